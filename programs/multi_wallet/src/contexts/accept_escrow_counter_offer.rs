@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::{state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, EscrowFees, MultisigError};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptEscrowCounterOffer<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        bump = escrow.vault_bump.unwrap()
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program
+    )]
+    pub proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        associated_token::mint = new_mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = new_token_program
+    )]
+    pub new_escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = new_mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = new_token_program
+    )]
+    pub new_proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub new_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// Token program of the counter-offer mint, which can differ from that of the current mint.
+    pub new_token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AcceptEscrowCounterOffer<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multi_wallet,
+            escrow,
+            proposer,
+            ..
+        } = self;
+
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        require!(
+            escrow.counter_offer.is_some(),
+            MultisigError::MissingCounterOffer
+        );
+        require!(
+            escrow.proposer == Some(proposer.key()),
+            MultisigError::InvalidEscrowProposer
+        );
+        require!(!escrow.is_expired()?, MultisigError::EscrowExpired);
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let counter_offer = escrow.counter_offer.take().unwrap();
+        let current_amount = escrow.recipient.amount;
+        let escrow_vault = Some(ctx.accounts.escrow_vault.to_account_info());
+        let proposer = Some(ctx.accounts.proposer.to_account_info());
+
+        if counter_offer.mint == escrow.recipient.mint {
+            if counter_offer.amount > current_amount {
+                // Top up the difference from the proposer.
+                escrow.transfer_amount(
+                    counter_offer.amount - current_amount,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.proposer_token_account,
+                    &ctx.accounts.escrow_token_vault,
+                    &proposer,
+                    &escrow_vault,
+                    &None,
+                    &ctx.accounts.proposer.to_account_info(),
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.system_program.to_account_info(),
+                )?;
+            } else {
+                // Refund the difference to the proposer.
+                escrow.transfer_amount(
+                    current_amount - counter_offer.amount,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.escrow_token_vault,
+                    &ctx.accounts.proposer_token_account,
+                    &escrow_vault,
+                    &proposer,
+                    &escrow_vault,
+                    &ctx.accounts.proposer.to_account_info(),
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.system_program.to_account_info(),
+                )?;
+            }
+            escrow.recipient.amount = counter_offer.amount;
+        } else {
+            // Refund the whole deposit in the previous asset, then deposit the new one.
+            escrow.escrow_transfer(
+                &ctx.accounts.mint,
+                &ctx.accounts.escrow_token_vault,
+                &ctx.accounts.proposer_token_account,
+                &escrow_vault,
+                &proposer,
+                &escrow_vault,
+                &ctx.accounts.proposer.to_account_info(),
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+
+            escrow.recipient.amount = counter_offer.amount;
+            escrow.recipient.mint = counter_offer.mint;
+
+            escrow.escrow_transfer(
+                &ctx.accounts.new_mint,
+                &ctx.accounts.new_proposer_token_account,
+                &ctx.accounts.new_escrow_token_vault,
+                &proposer,
+                &escrow_vault,
+                &None,
+                &ctx.accounts.proposer.to_account_info(),
+                &ctx.accounts.new_token_program,
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+        // Seat sales keep the threshold of the multi-wallet.
+        if escrow.seat.is_none() {
+            escrow.threshold = Some(counter_offer.threshold);
+        }
+        // Votes were cast on the previous terms.
+        escrow.votes.clear();
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: true,
+            is_rejected: false,
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
+        });
+
+        Ok(())
+    }
+}
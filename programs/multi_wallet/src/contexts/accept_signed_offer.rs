@@ -0,0 +1,261 @@
+use crate::{
+    create_program_account,
+    state::{Escrow, MultiWallet, SignedOffer, SignedOfferNonce, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_SIGNED_OFFER, SEED_VAULT},
+    verify_ed25519_signature, EscrowEvent, EscrowOutcome, EscrowReceipt, FeeAccounts, MultisigError, Permission, Recipient,
+    SEED_PROTOCOL_CONFIG,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(offer: SignedOffer)]
+pub struct AcceptSignedOffer<'info> {
+    #[account(
+        mut,
+        address = offer.multi_wallet,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    /// CHECK: escrow vault PDA of the offer nonce, delegate of the buyer's token account.
+    #[account(
+        seeds = [SEED_ESCROW, multi_wallet.create_key.as_ref(), offer.nonce.to_le_bytes().as_ref(), SEED_VAULT],
+        bump
+    )]
+    pub offer_delegate: UncheckedAccount<'info>,
+    /// CHECK: marks the offer nonce as used, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_SIGNED_OFFER, multi_wallet.create_key.as_ref(), offer.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub offer_nonce: UncheckedAccount<'info>,
+    /// CHECK: checked against the offer and its ed25519 signature
+    #[account(address = offer.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
+    )]
+    pub buyer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: may be uninitialized, in which case no protocol fee is charged.
+    #[account(
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+    /// CHECK: checked against the protocol config
+    #[account(mut)]
+    pub protocol_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = protocol_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub protocol_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: checked against the offer marketplace fee
+    #[account(mut)]
+    pub marketplace_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = marketplace_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub marketplace_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(address = offer.mint)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// CHECK: instructions sysvar
+    #[account(address = tx_instructions::ID)]
+    pub instruction_sysvar: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, multi_wallet.create_key.as_ref(), offer.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AcceptSignedOffer<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, offer: &SignedOffer) -> Result<()> {
+        let Self {
+            multi_wallet,
+            instruction_sysvar,
+            ..
+        } = self;
+
+        MultiWallet::durable_nonce_check(instruction_sysvar)?;
+        MultiWallet::validate_client_escrow_identifier(offer.nonce)?;
+        SignedOfferNonce::check_unused(&self.offer_nonce)?;
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+        require!(
+            multi_wallet.threshold
+                <= unique_signers
+                    .iter()
+                    .filter(|x| x.permissions.is_some()
+                        && x.permissions.unwrap().has(Permission::VoteEscrow))
+                    .count()
+                    .try_into()
+                    .unwrap(),
+            MultisigError::NotEnoughSigners
+        );
+
+        require!(
+            unique_signers
+                .iter()
+                .filter(|x| x.permissions.is_some()
+                    && x.permissions.unwrap().has(Permission::ExecuteEscrow))
+                .count()
+                >= 1,
+            MultisigError::InsufficientSignerWithExecutePermission
+        );
+
+        require!(
+            multi_wallet.listing_state().allows_bid(),
+            MultisigError::MultisigIsCurrentlyLocked
+        );
+        require!(
+            offer.expires_at > Clock::get()?.unix_timestamp,
+            MultisigError::EscrowExpired
+        );
+        Escrow::validate_marketplace_fee(&offer.marketplace_fee)?;
+        MultiWallet::check_state_validity(&offer.threshold, &offer.new_members)?;
+        verify_ed25519_signature(instruction_sysvar, &offer.buyer, &offer.message()?)?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx, &offer))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, offer: SignedOffer) -> Result<()> {
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let seller_members = multi_wallet.members.clone();
+
+        // The offer is settled like an escrow whose vault is the delegate of the buyer's token account.
+        let escrow = Escrow {
+            create_key: multi_wallet.create_key,
+            identifier: offer.nonce,
+            proposer: Some(offer.buyer),
+            vault_bump: Some(ctx.bumps.offer_delegate),
+            recipient: Recipient {
+                pubkey: Some(ctx.accounts.recipient.key()),
+                amount: offer.amount,
+                mint: Some(offer.mint),
+            },
+            new_members: Some(offer.new_members),
+            threshold: Some(offer.threshold),
+            expires_at: Some(offer.expires_at),
+            marketplace_fee: offer.marketplace_fee,
+            created_at: Clock::get()?.unix_timestamp,
+            rent_payer: ctx.accounts.payer.key(),
+            ..Default::default()
+        };
+
+        multi_wallet.set_members(
+            escrow.new_members.as_ref().unwrap().clone(),
+            offer.buyer,
+            Clock::get()?.unix_timestamp,
+        );
+        multi_wallet.set_threshold(offer.threshold);
+
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            multi_wallet.members.len(),
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        let nonce = offer.nonce.to_le_bytes();
+        create_program_account(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.offer_nonce.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            SignedOfferNonce::SIZE,
+            &[SEED_SIGNED_OFFER, multi_wallet.create_key.as_ref(), nonce.as_ref(), &[ctx.bumps.offer_nonce]],
+        )?;
+        SignedOfferNonce {
+            create_key: multi_wallet.create_key,
+            nonce: offer.nonce,
+            bump: ctx.bumps.offer_nonce,
+        }
+        .try_serialize(&mut &mut ctx.accounts.offer_nonce.try_borrow_mut_data()?[..])?;
+
+        let fees = escrow.escrow_transfer_with_fees(
+            FeeAccounts {
+                protocol_config: &ctx.accounts.protocol_config,
+                protocol_fee_recipient: &ctx.accounts.protocol_fee_recipient,
+                protocol_fee_token_account: &ctx.accounts.protocol_fee_token_account,
+                marketplace_fee_recipient: &ctx.accounts.marketplace_fee_recipient,
+                marketplace_fee_token_account: &ctx.accounts.marketplace_fee_token_account,
+            },
+            &ctx.accounts.mint,
+            &ctx.accounts.buyer_token_account,
+            &ctx.accounts.recipient_token_account,
+            &None,
+            &None,
+            &Some(ctx.accounts.offer_delegate.to_account_info()),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
+
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            Some(&ctx.accounts.payer.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            &escrow,
+            Some(&seller_members),
+            Some(&multi_wallet.members),
+            EscrowOutcome::Executed,
+        )?;
+
+        emit_cpi!(EscrowEvent {
+            create_key: multi_wallet.create_key,
+            identifier: escrow.identifier,
+            is_pending: false,
+            is_rejected: false,
+            recipient: escrow.recipient,
+            approver: Some(ctx.accounts.recipient.key()),
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    state::{Escrow, SEED_ESCROW},
-    EscrowEvent, MultiWallet, MultisigError, SEED_MULTISIG, SEED_VAULT,
+    state::{Escrow, ESCROW_VERSION, SEED_ESCROW},
+    EscrowEvent, EscrowFees, EscrowOutcome, EscrowReceipt, LegAccounts, MultiWallet, MultisigError, MULTI_WALLET_VERSION, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_VAULT,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -14,14 +14,16 @@ pub struct CancelEscrowAsNonOwner<'info> {
     #[account(
         mut,
         seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         mut,
-        close = proposer,
+        close = rent_payer,
         seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
         bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
     )]
     pub escrow: Box<Account<'info, Escrow>>,
     #[account(
@@ -44,10 +46,24 @@ pub struct CancelEscrowAsNonOwner<'info> {
         associated_token::token_program = token_program
     )]
     pub proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
     pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
     #[account(mut)]
     pub proposer: Signer<'info>,
+    /// CHECK: receives the escrow rent, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
     pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the rent freed by shrinking the multi-wallet, checked by `shrink_if_needed`.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
@@ -65,6 +81,8 @@ impl<'info> CancelEscrowAsNonOwner<'info> {
             escrow.proposer.unwrap() == proposer.key(),
             MultisigError::InvalidEscrowProposer
         );
+        // Auction bids stay committed until they are outbid or the auction is settled.
+        require!(escrow.auction.is_none(), MultisigError::InvalidAuction);
         Ok(())
     }
 
@@ -86,6 +104,36 @@ impl<'info> CancelEscrowAsNonOwner<'info> {
             &ctx.accounts.token_program,
             &ctx.accounts.system_program.to_account_info(),
         )?;
+        escrow.transfer_additional_legs(
+            &LegAccounts {
+                account_infos: ctx.remaining_accounts,
+                rent_payer: Some(&ctx.accounts.proposer.to_account_info()),
+                vault_rent_refund: Some(&ctx.accounts.rent_payer.to_account_info()),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            },
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.proposer.to_account_info(),
+            true,
+        )?;
+
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            Some(&ctx.accounts.proposer.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&multi_wallet.members),
+            escrow.new_members.as_ref(),
+            EscrowOutcome::Cancelled,
+        )?;
+
+        MultiWallet::shrink_if_needed(
+            multi_wallet,
+            ctx.accounts
+                .rent_collector
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
 
         emit_cpi!(EscrowEvent {
             create_key: escrow.create_key,
@@ -96,7 +144,10 @@ impl<'info> CancelEscrowAsNonOwner<'info> {
             proposer: escrow.proposer,
             approver: None,
             new_members: escrow.new_members.clone(),
-            threshold: escrow.threshold
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
         });
 
         Ok(())
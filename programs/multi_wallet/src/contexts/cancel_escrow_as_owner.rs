@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
-use crate::{state::{Escrow, MultiWallet, SEED_ESCROW}, EscrowEvent, MultisigError, Permission, SEED_MULTISIG, SEED_VAULT};
+use crate::{state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW}, EscrowEvent, EscrowFees, EscrowOutcome, EscrowReceipt, LegAccounts, MultisigError, Permission, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_VAULT};
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 
 #[event_cpi]
@@ -9,14 +9,16 @@ pub struct CancelEscrowAsOwner<'info> {
     #[account(
         mut, 
         seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         mut,
-        close = proposer,
+        close = rent_payer,
         seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
         bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
     )]
     pub escrow: Box<Account<'info, Escrow>>,
      #[account(
@@ -42,11 +44,28 @@ pub struct CancelEscrowAsOwner<'info> {
     /// CHECK:
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
+    /// CHECK: receives the escrow rent, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
     /// CHECK: instructions sysvar
     #[account(address = tx_instructions::ID)]
     pub instruction_sysvar: UncheckedAccount<'info>,
+    #[account(mut)]
     pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
     pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// Pays for the receipt, if one is created.
+    #[account(mut)]
+    pub receipt_payer: Option<Signer<'info>>,
+    /// CHECK: receives the rent freed by shrinking the multi-wallet, checked by `shrink_if_needed`.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
@@ -56,6 +75,7 @@ impl<'info> CancelEscrowAsOwner<'info> {
     fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>) -> Result<()> {
         let Self {
             multi_wallet,
+            escrow,
             instruction_sysvar,
             ..
         } = self;
@@ -64,7 +84,7 @@ impl<'info> CancelEscrowAsOwner<'info> {
         let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
 
         require!(
-            multi_wallet.threshold <= unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::VoteEscrow)).count().try_into().unwrap(),
+            usize::from(multi_wallet.threshold) <= multi_wallet.count_escrow_votes(&unique_signers, &escrow.votes, false),
             MultisigError::NotEnoughSigners
         );
 
@@ -92,21 +112,59 @@ impl<'info> CancelEscrowAsOwner<'info> {
                     &ctx.accounts
                         .escrow_vault
                         .as_ref()
-                        .map_or(None, |x| Some(x.to_account_info())),
+                        .map(|x| x.to_account_info()),
                     &Some(ctx.accounts.proposer.to_account_info()),
                     &ctx.accounts
                         .escrow_vault
                         .as_ref()
-                        .map_or(None, |x| Some(x.to_account_info())),
+                        .map(|x| x.to_account_info()),
                     &ctx.accounts.proposer.to_account_info(),
                     &ctx.accounts.token_program,
                     &ctx.accounts.system_program.to_account_info(),
                 )?;
+                escrow.transfer_additional_legs(
+                    &LegAccounts {
+                        account_infos: ctx.remaining_accounts,
+                        rent_payer: None,
+                        vault_rent_refund: Some(&ctx.accounts.rent_payer.to_account_info()),
+                        system_program: &ctx.accounts.system_program.to_account_info(),
+                        associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+                    },
+                    &ctx.accounts
+                        .escrow_vault
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?
+                        .to_account_info(),
+                    &ctx.accounts.proposer.to_account_info(),
+                    true,
+                )?;
             } else {
                 return err!(MultisigError::InvalidEscrowProposer);
             }
         }
 
+        let outcome = match escrow.proposer {
+            Some(_) => EscrowOutcome::Rejected,
+            None => EscrowOutcome::Cancelled,
+        };
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            ctx.accounts.receipt_payer.as_ref().map(|x| x.as_ref()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&multi_wallet.members),
+            escrow.new_members.as_ref(),
+            outcome,
+        )?;
+
+        MultiWallet::shrink_if_needed(
+            multi_wallet,
+            ctx.accounts
+                .rent_collector
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
         emit_cpi!(EscrowEvent {
             create_key: escrow.create_key,
             identifier: escrow.identifier,
@@ -116,7 +174,10 @@ impl<'info> CancelEscrowAsOwner<'info> {
             approver: None,
             proposer: escrow.proposer,
             new_members: escrow.new_members.clone(),
-            threshold: escrow.threshold
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
         });
         Ok(())
     }
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::{state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW}, EscrowEvent, EscrowFees, EscrowOutcome, EscrowReceipt, LegAccounts, MultisigError, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_VAULT};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelExpiredEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        bump = escrow.vault_bump.unwrap()
+    )]
+    pub escrow_vault: Option<SystemAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program
+    )]
+    pub proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: must match the escrow proposer for non-owner escrows, unused for owner listings.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// CHECK: receives the escrow rent, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// Pays for the receipt, if one is created.
+    #[account(mut)]
+    pub receipt_payer: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> CancelExpiredEscrow<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            escrow, proposer, ..
+        } = self;
+
+        require!(escrow.is_expired()?, MultisigError::EscrowNotExpired);
+        if let Some(escrow_proposer) = escrow.proposer {
+            require!(
+                escrow_proposer == proposer.key(),
+                MultisigError::InvalidEscrowProposer
+            );
+        }
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        multi_wallet.remove_offer(escrow.key());
+
+        if escrow.proposer.is_some() {
+            escrow.escrow_transfer(
+                &ctx.accounts.mint,
+                &ctx.accounts.escrow_token_vault,
+                &ctx.accounts.proposer_token_account,
+                &ctx.accounts
+                    .escrow_vault
+                    .as_ref()
+                    .map(|x| x.to_account_info()),
+                &Some(ctx.accounts.proposer.to_account_info()),
+                &ctx.accounts
+                    .escrow_vault
+                    .as_ref()
+                    .map(|x| x.to_account_info()),
+                &ctx.accounts.proposer.to_account_info(),
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            escrow.transfer_additional_legs(
+                &LegAccounts {
+                    account_infos: ctx.remaining_accounts,
+                    rent_payer: None,
+                    vault_rent_refund: Some(&ctx.accounts.rent_payer.to_account_info()),
+                    system_program: &ctx.accounts.system_program.to_account_info(),
+                    associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+                },
+                &ctx.accounts
+                    .escrow_vault
+                    .as_ref()
+                    .ok_or(MultisigError::MissingAccount)?
+                    .to_account_info(),
+                &ctx.accounts.proposer.to_account_info(),
+                true,
+            )?;
+        }
+
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            ctx.accounts.receipt_payer.as_ref().map(|x| x.as_ref()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&multi_wallet.members),
+            escrow.new_members.as_ref(),
+            EscrowOutcome::Expired,
+        )?;
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: false,
+            is_rejected: true,
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
+        });
+        Ok(())
+    }
+}
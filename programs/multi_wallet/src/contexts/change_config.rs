@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::{MultiWallet, Permission, SEED_MULTISIG}, ConfigAction, ConfigEvent, MultisigError};
+use crate::{state::{MultiWallet, Permission, WalletMetadata, MULTI_WALLET_VERSION, SEED_MULTISIG, SEED_WALLET_METADATA}, ConfigAction, ConfigEvent, MultisigError};
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 #[event_cpi]
 #[derive(Accounts)]
@@ -7,11 +7,22 @@ pub struct ChangeConfig<'info> {
     #[account(
         mut, 
         seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Account<'info, MultiWallet>,
     #[account(mut)]
     pub payer: Option<Signer<'info>>,
+    /// CHECK: created or overwritten by `ConfigAction::UpdateMetadata`.
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.key().as_ref(), SEED_WALLET_METADATA],
+        bump
+    )]
+    pub wallet_metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the rent freed by shrinking the multi-wallet, checked by `shrink_if_needed`.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
     pub system_program: Option<Program<'info, System>>,
     /// CHECK: instructions sysvar
     #[account(address = tx_instructions::ID)]
@@ -44,7 +55,7 @@ impl<'info> ChangeConfig<'info> {
         );
       
         require!(
-            multi_wallet.pending_offers.len() == 0,
+            multi_wallet.listing_state().allows_config_change(),
             MultisigError::MultisigIsCurrentlyLocked
         );
       
@@ -53,14 +64,27 @@ impl<'info> ChangeConfig<'info> {
 
     #[access_control(ctx.accounts.validate(&ctx))]
     pub fn process(ctx: Context<'_, '_, '_, 'info, Self>,  config_actions: Vec<ConfigAction>,) -> Result<()> {   
+        // New members are recorded as added by the signer initiating the change.
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let initiator = ctx
+            .accounts
+            .multi_wallet
+            .get_unique_signers(account_infos)?
+            .iter()
+            .find(|x| x.permissions.is_some_and(|x| x.has(Permission::InitiateTransaction)))
+            .map(|x| x.pubkey)
+            .ok_or(MultisigError::InsufficientSignerWithInitiatePermission)?;
+        let now = Clock::get()?.unix_timestamp;
+
         let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let mut wallet_metadata_update = None;
         for action in config_actions {
             match action {
                 ConfigAction::SetMembers(set_members) => {
-                    multi_wallet.set_members(set_members);
+                    multi_wallet.set_members(set_members, initiator, now);
                 }
                 ConfigAction::AddMembers(add_members) => {
-                    multi_wallet.add_members(add_members);
+                    multi_wallet.add_members(add_members, initiator, now);
                 }
                 ConfigAction::RemoveMembers(remove_members) => {
                     multi_wallet.remove_members(remove_members);
@@ -71,6 +95,22 @@ impl<'info> ChangeConfig<'info> {
                 ConfigAction::SetMetadata(metadata) => {
                     multi_wallet.metadata = metadata;
                 }
+                ConfigAction::UpdateMetadata(update) => {
+                    let wallet_metadata = ctx
+                        .accounts
+                        .wallet_metadata
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+                    WalletMetadata::write(
+                        wallet_metadata,
+                        ctx.accounts.payer.as_ref().map(|x| x.as_ref()),
+                        ctx.accounts.system_program.as_ref().map(|x| x.as_ref()),
+                        multi_wallet.key(),
+                        update.clone(),
+                    )?;
+                    multi_wallet.metadata = Some(wallet_metadata.key());
+                    wallet_metadata_update = Some(update);
+                }
             }
         }
 
@@ -90,11 +130,20 @@ impl<'info> ChangeConfig<'info> {
 
         MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
 
+        MultiWallet::shrink_if_needed(
+            multi_wallet,
+            ctx.accounts
+                .rent_collector
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
         emit_cpi!(ConfigEvent {
             create_key: multi_wallet.create_key,
             members: multi_wallet.members.clone(),
             threshold: multi_wallet.threshold,
             metadata: multi_wallet.metadata,
+            wallet_metadata: wallet_metadata_update,
         });
 
         Ok(())
@@ -0,0 +1,55 @@
+use crate::{
+    state::{EscrowReceipt, MultiWallet, MULTI_WALLET_VERSION, SEED_ESCROW_RECEIPT, SEED_MULTISIG},
+    MultisigError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseEscrowReceipt<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, receipt.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        close = rent_collector,
+        seeds = [SEED_ESCROW_RECEIPT, receipt.create_key.as_ref(), receipt.identifier.to_le_bytes().as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Box<Account<'info, EscrowReceipt>>,
+    /// Member of the multi-wallet receiving the rent of the receipt.
+    #[account(mut)]
+    pub rent_collector: Signer<'info>,
+}
+
+impl<'info> CloseEscrowReceipt<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let Self {
+            multi_wallet,
+            rent_collector,
+            ..
+        } = self;
+
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+        require!(
+            usize::from(multi_wallet.threshold) <= unique_signers.len(),
+            MultisigError::NotEnoughSigners
+        );
+        require!(
+            unique_signers.iter().any(|x| x.pubkey.eq(rent_collector.key)),
+            MultisigError::InvalidAccount
+        );
+
+        Ok(())
+    }
+
+    /// Close an escrow receipt of the multi-wallet.
+    #[access_control(ctx.accounts.validate(&ctx))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        Ok(())
+    }
+}
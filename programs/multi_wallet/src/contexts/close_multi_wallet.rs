@@ -0,0 +1,221 @@
+use anchor_lang::{
+    prelude::*,
+    Discriminator,
+    system_program::{transfer, Transfer},
+};
+use anchor_spl::{
+    token_2022_extensions::transfer_fee::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint},
+    token_interface::{close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked},
+};
+use crate::{get_token_account_balances, id, state::{MultiWallet, Permission, WalletMetadata, MULTI_WALLET_VERSION, SEED_MULTISIG, SEED_WALLET_METADATA}, ConfigEvent, MultisigError, SEED_VAULT};
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMultiWallet<'info> {
+    #[account(
+        mut,
+        close = rent_collector,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Account<'info, MultiWallet>,
+    /// CHECK: wallet metadata PDA of the multi-wallet, closed by the instruction if it exists.
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.key().as_ref(), SEED_WALLET_METADATA],
+        bump
+    )]
+    pub wallet_metadata: UncheckedAccount<'info>,
+    /// Member of the multi-wallet receiving the rent of the multi-wallet.
+    #[account(mut)]
+    pub rent_collector: Signer<'info>,
+    /// CHECK: receives the lamports of the swept vaults and of their closed token accounts.
+    #[account(mut)]
+    pub sweep_recipient: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: instructions sysvar
+    #[account(address = tx_instructions::ID)]
+    pub instruction_sysvar: UncheckedAccount<'info>
+}
+
+impl<'info> CloseMultiWallet<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, vault_indices: &[u16], token_vault_indices: &[u16]) -> Result<()> {
+        let Self {
+            multi_wallet,
+            rent_collector,
+            sweep_recipient,
+            instruction_sysvar,
+            ..
+        } = self;
+        MultiWallet::durable_nonce_check(instruction_sysvar)?;
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+        require!(
+            unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::InitiateTransaction)).count() >= 1,
+            MultisigError::InsufficientSignerWithInitiatePermission
+        );
+        require!(
+            multi_wallet.threshold <= unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::VoteTransaction)).count().try_into().unwrap(),
+            MultisigError::NotEnoughSigners
+        );
+        require!(
+           unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::ExecuteTransaction)).count() >= 1,
+            MultisigError::InsufficientSignerWithExecutePermission
+        );
+        require!(
+            unique_signers.iter().any(|x| x.pubkey.eq(rent_collector.key)),
+            MultisigError::InvalidAccount
+        );
+
+        require!(multi_wallet.pending_offers.is_empty(), MultisigError::PendingOffersExist);
+        require!(multi_wallet.open_buffers == 0, MultisigError::OpenBuffersExist);
+        require!(
+            (vault_indices.is_empty() && token_vault_indices.is_empty()) || sweep_recipient.is_some(),
+            MultisigError::MissingAccount
+        );
+
+        Ok(())
+    }
+
+    /// Closes the multi-wallet, sweeping the lamports of the vaults at `vault_indices` and the token
+    /// accounts owned by the vaults at `token_vault_indices`, which must also be listed in `vault_indices`. The vaults are passed as the first remaining
+    /// accounts, in the same order, followed by `[token account, mint, destination token account, token program]`
+    /// for each token account to sweep. Token accounts are emptied into their destination and closed.
+    #[access_control(ctx.accounts.validate(&ctx, &vault_indices, &token_vault_indices))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, vault_indices: Vec<u16>, token_vault_indices: Vec<u16>) -> Result<()> {
+        let multi_wallet = &ctx.accounts.multi_wallet;
+        let multi_wallet_key = multi_wallet.key();
+
+        require!(
+            ctx.remaining_accounts.len() >= vault_indices.len() + token_vault_indices.len() * 4,
+            MultisigError::InvalidNumberOfAccounts
+        );
+        let (vaults, token_accounts) = ctx.remaining_accounts.split_at(vault_indices.len());
+
+        for (vault_index, group) in token_vault_indices.iter().zip(token_accounts.chunks_exact(4)) {
+            let [token_account, mint, destination, token_program] = group else {
+                return err!(MultisigError::InvalidNumberOfAccounts);
+            };
+            let vault_index_ref = vault_index.to_le_bytes();
+            let (vault_pubkey, vault_bump) = Pubkey::find_program_address(
+                &[SEED_MULTISIG, multi_wallet_key.as_ref(), SEED_VAULT, vault_index_ref.as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                token_program.key() == anchor_spl::token::ID || token_program.key() == anchor_spl::token_2022::ID,
+                MultisigError::InvalidAccount
+            );
+            require_keys_eq!(*token_account.owner, token_program.key(), MultisigError::InvalidAccount);
+            let token_account_data = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?;
+            require_keys_eq!(token_account_data.owner, vault_pubkey, MultisigError::InvalidAccount);
+            require_keys_eq!(token_account_data.mint, mint.key(), MultisigError::InvalidAccount);
+            let decimals = Mint::try_deserialize(&mut &mint.try_borrow_data()?[..])?.decimals;
+
+            // The vault of a swept token account is swept as well, so it is one of the vaults passed.
+            let authority = vault_indices
+                .iter()
+                .position(|x| x == vault_index)
+                .map(|i| &vaults[i])
+                .ok_or(MultisigError::MissingAccount)?;
+            let vault_signer: &[&[&[u8]]] = &[&[SEED_MULTISIG, multi_wallet_key.as_ref(), SEED_VAULT, vault_index_ref.as_ref(), &[vault_bump]]];
+
+            if token_account_data.amount > 0 {
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: token_account.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to: destination.to_account_info(),
+                            authority: authority.to_account_info(),
+                        },
+                        vault_signer,
+                    ),
+                    token_account_data.amount,
+                    decimals,
+                )?;
+            }
+            // Withheld transfer fees block closing the account, so they are harvested to the mint first.
+            let (_, withheld_amount) = get_token_account_balances(token_account)?;
+            if withheld_amount > 0 {
+                harvest_withheld_tokens_to_mint(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        HarvestWithheldTokensToMint {
+                            token_program_id: token_program.to_account_info(),
+                            mint: mint.to_account_info(),
+                        },
+                    ),
+                    vec![token_account.to_account_info()],
+                )?;
+            }
+            close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: token_account.to_account_info(),
+                    destination: ctx.accounts.sweep_recipient.as_ref().unwrap().to_account_info(),
+                    authority: authority.to_account_info(),
+                },
+                vault_signer,
+            ))?;
+        }
+
+        for (vault_index, vault) in vault_indices.iter().zip(vaults) {
+            let vault_index_ref = vault_index.to_le_bytes();
+            let (vault_pubkey, vault_bump) = Pubkey::find_program_address(
+                &[SEED_MULTISIG, multi_wallet_key.as_ref(), SEED_VAULT, vault_index_ref.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(vault.key(), vault_pubkey, MultisigError::InvalidAccount);
+            // A vault holding data cannot be emptied by the system program and would outlive the multi-wallet.
+            require!(vault.data_is_empty(), MultisigError::VaultHoldsData);
+
+            let lamports = vault.lamports();
+            if lamports == 0 {
+                continue;
+            }
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: ctx.accounts.sweep_recipient.as_ref().unwrap().to_account_info(),
+                    },
+                    &[&[SEED_MULTISIG, multi_wallet_key.as_ref(), SEED_VAULT, vault_index_ref.as_ref(), &[vault_bump]]],
+                ),
+                lamports,
+            )?;
+        }
+
+        // Close the wallet metadata account the way Anchor's `close` constraint does, if it was created.
+        let wallet_metadata = &ctx.accounts.wallet_metadata;
+        if wallet_metadata.owner.eq(&id()) {
+            require!(
+                wallet_metadata.try_borrow_data()?.starts_with(&WalletMetadata::DISCRIMINATOR),
+                MultisigError::InvalidAccount
+            );
+            let rent_collector = &ctx.accounts.rent_collector;
+            **rent_collector.lamports.borrow_mut() = rent_collector
+                .lamports()
+                .checked_add(wallet_metadata.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            **wallet_metadata.lamports.borrow_mut() = 0;
+            wallet_metadata.assign(&System::id());
+            wallet_metadata.realloc(0, false)?;
+        }
+
+        // The final config of a closed multi-wallet has no members and no threshold.
+        emit_cpi!(ConfigEvent {
+            create_key: multi_wallet.create_key,
+            members: Vec::new(),
+            threshold: 0,
+            metadata: None,
+            wallet_metadata: None,
+        });
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::{ConfigEvent, MultiWallet, SEED_MULTISIG}, Member};
+use crate::{state::{ConfigEvent, MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, Member};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -22,11 +22,21 @@ impl<'info> CreateMultiWallet<'info> {
     pub fn process(ctx: Context<Self>, create_key: Member, metadata: Option<Pubkey>) -> Result<()> {
         let multi_wallet = &mut ctx.accounts.multi_wallet;
         multi_wallet.create_key = create_key.pubkey.key();
-        multi_wallet.members = [create_key].to_vec();
+        multi_wallet.members = [Member {
+            added_at: Clock::get()?.unix_timestamp,
+            added_by: Some(ctx.accounts.payer.key()),
+            ..create_key
+        }]
+        .to_vec();
         multi_wallet.bump = ctx.bumps.multi_wallet;
         multi_wallet.metadata = metadata;
         multi_wallet.threshold = 1;
         multi_wallet.pending_offers = Vec::new();
+        multi_wallet.transaction_counter = 0;
+        multi_wallet.listing = None;
+        multi_wallet.escrow_counter = 0;
+        multi_wallet.open_buffers = 0;
+        multi_wallet.version = MULTI_WALLET_VERSION;
         MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
 
         emit_cpi!(ConfigEvent {
@@ -34,6 +44,7 @@ impl<'info> CreateMultiWallet<'info> {
             members: multi_wallet.members.clone(),
             threshold: multi_wallet.threshold,
             metadata: multi_wallet.metadata,
+            wallet_metadata: None,
         });
         Ok(())
     }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG}, EscrowVoteEvent, MultisigError, Permission};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EscrowCastVote<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    pub member: Signer<'info>,
+}
+
+impl<'info> EscrowCastVote<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multi_wallet,
+            escrow,
+            member,
+            ..
+        } = self;
+
+        require!(
+            multi_wallet
+                .find_member(member.key)
+                .is_some_and(|x| x.permissions.is_some_and(|x| x.has(Permission::VoteEscrow))),
+            MultisigError::InsufficientSignersWithVotePermission
+        );
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        require!(!escrow.is_expired()?, MultisigError::EscrowExpired);
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, approve: bool) -> Result<()> {
+        let vote_capacity = ctx.accounts.escrow.vote_capacity(ctx.accounts.escrow.to_account_info().data_len());
+        let escrow = &mut ctx.accounts.escrow;
+        let member = ctx.accounts.member.key();
+        escrow.record_vote(member, approve, vote_capacity, &ctx.accounts.multi_wallet)?;
+
+        emit_cpi!(EscrowVoteEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            member,
+            approve,
+        });
+        Ok(())
+    }
+}
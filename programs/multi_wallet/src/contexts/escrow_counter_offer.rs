@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{state::{CounterOffer, Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW}, EscrowEvent, EscrowFees, MultisigError, Permission, Recipient, SEED_MULTISIG};
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EscrowCounterOffer<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// CHECK: instructions sysvar
+    #[account(address = tx_instructions::ID)]
+    pub instruction_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> EscrowCounterOffer<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, mint: &Option<Pubkey>, threshold: &u8) -> Result<()> {
+        let Self {
+            multi_wallet,
+            escrow,
+            instruction_sysvar,
+            ..
+        } = self;
+        MultiWallet::durable_nonce_check(instruction_sysvar)?;
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+        require!(
+            multi_wallet.threshold <= unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::VoteEscrow)).count().try_into().unwrap(),
+            MultisigError::NotEnoughSigners
+        );
+
+        require!(
+            unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::ExecuteEscrow)).count() >= 1,
+            MultisigError::InsufficientSignerWithExecutePermission
+        );
+
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        require!(
+            escrow.proposer.is_some() && escrow.auction.is_none(),
+            MultisigError::UnauthorisedToAcceptEscrowOffer
+        );
+        require!(!escrow.is_expired()?, MultisigError::EscrowExpired);
+        require!(
+            !escrow.additional_legs.iter().any(|x| x.mint == *mint),
+            MultisigError::InvalidPaymentLegs
+        );
+        if escrow.seat.is_none() {
+            MultiWallet::check_state_validity(threshold, escrow.new_members.as_ref().unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx, &mint, &threshold))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, amount: u64, mint: Option<Pubkey>, threshold: u8) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.counter_offer = Some(CounterOffer {
+            amount,
+            mint,
+            threshold,
+        });
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: true,
+            is_rejected: false,
+            recipient: Recipient {
+                pubkey: escrow.recipient.pubkey,
+                amount,
+                mint,
+            },
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: Some(threshold),
+            fees: EscrowFees::default()
+        });
+        Ok(())
+    }
+}
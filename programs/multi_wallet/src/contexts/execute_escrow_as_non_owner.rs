@@ -1,6 +1,6 @@
 use crate::{
-    state::{Escrow, MultiWallet, SEED_ESCROW},
-    EscrowEvent, Member, MultisigError, SEED_MULTISIG,
+    state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW},
+    EscrowEvent, EscrowOutcome, EscrowReceipt, FeeAccounts, Member, MultisigError, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_PROTOCOL_CONFIG,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -14,14 +14,16 @@ pub struct ExecuteEscrowAsNonOwner<'info> {
     #[account(
         mut,
         seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         mut,
-        close = payer,
+        close = rent_payer,
         seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
-        bump = escrow.bump
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
     )]
     pub escrow: Box<Account<'info, Escrow>>,
     #[account(
@@ -39,13 +41,54 @@ pub struct ExecuteEscrowAsNonOwner<'info> {
         associated_token::token_program = token_program
     )]
     pub payer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: may be uninitialized, in which case no protocol fee is charged.
+    #[account(
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+    /// CHECK: checked against the protocol config
+    #[account(mut)]
+    pub protocol_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = protocol_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub protocol_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: checked against the escrow marketplace fee
+    #[account(mut)]
+    pub marketplace_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = marketplace_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub marketplace_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
     /// CHECK:
     #[account(mut)]
     pub recipient: UncheckedAccount<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// CHECK: receives the escrow rent, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
     pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the rent freed by shrinking the multi-wallet, checked by `shrink_if_needed`.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
@@ -56,17 +99,24 @@ impl<'info> ExecuteEscrowAsNonOwner<'info> {
             multi_wallet,
             escrow,
             recipient,
+            payer,
             ..
         } = self;
 
-        require!(
-            multi_wallet.pending_offers.contains(&escrow.key()),
-            MultisigError::EscrowDoesNotExist
-        );
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        require!(!escrow.is_expired()?, MultisigError::EscrowExpired);
+        if let Some(auction) = escrow.auction {
+            require!(!auction.is_english(), MultisigError::InvalidAuction);
+            require!(!auction.has_ended()?, MultisigError::AuctionEnded);
+        }
         require!(
             escrow.proposer.is_none(),
             MultisigError::UnauthorisedToAcceptEscrowOffer
         );
+        require!(
+            escrow.is_allowed_buyer(payer.key),
+            MultisigError::BuyerNotAllowed
+        );
         require!(
             escrow.recipient.pubkey.is_some()
                 && recipient.key() == escrow.recipient.pubkey.unwrap(),
@@ -82,12 +132,33 @@ impl<'info> ExecuteEscrowAsNonOwner<'info> {
         threshold: u8,
     ) -> Result<()> {
         let multi_wallet = &mut ctx.accounts.multi_wallet;
-        let escrow = &ctx.accounts.escrow;
+        let escrow = &mut ctx.accounts.escrow;
+        let seller_members = multi_wallet.members.clone();
+
+        // Dutch auctions are sold at their current price.
+        if let Some(auction) = escrow.auction {
+            escrow.recipient.amount = auction.current_price()?;
+        }
 
-        multi_wallet.set_members(new_members.clone());
+        // Grow the account before the buyer's members are written, as they may outnumber the seller's.
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            new_members.len(),
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+        multi_wallet.set_members(new_members.clone(), ctx.accounts.payer.key(), Clock::get()?.unix_timestamp);
         multi_wallet.set_threshold(threshold);
 
-        escrow.escrow_transfer(
+        let fees = escrow.escrow_transfer_with_fees(
+            FeeAccounts {
+                protocol_config: &ctx.accounts.protocol_config,
+                protocol_fee_recipient: &ctx.accounts.protocol_fee_recipient,
+                protocol_fee_token_account: &ctx.accounts.protocol_fee_token_account,
+                marketplace_fee_recipient: &ctx.accounts.marketplace_fee_recipient,
+                marketplace_fee_token_account: &ctx.accounts.marketplace_fee_token_account,
+            },
             &ctx.accounts.mint,
             &ctx.accounts.payer_token_account,
             &ctx.accounts.recipient_token_account,
@@ -100,7 +171,25 @@ impl<'info> ExecuteEscrowAsNonOwner<'info> {
         )?;
 
         MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
-        multi_wallet.clear_pending_offers();
+        multi_wallet.settle_offer(escrow.key());
+
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            Some(&ctx.accounts.payer.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&seller_members),
+            Some(&multi_wallet.members),
+            EscrowOutcome::Executed,
+        )?;
+
+        MultiWallet::shrink_if_needed(
+            multi_wallet,
+            ctx.accounts
+                .rent_collector
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
 
         emit_cpi!(EscrowEvent {
             create_key: multi_wallet.create_key,
@@ -111,7 +200,10 @@ impl<'info> ExecuteEscrowAsNonOwner<'info> {
             approver: Some(ctx.accounts.payer.key()),
             proposer: escrow.proposer,
             new_members: Some(new_members.clone()),
-            threshold: Some(threshold)
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: Some(threshold),
+            fees
         });
 
         Ok(())
@@ -1,6 +1,6 @@
 use crate::{
-    state::{Escrow, MultiWallet, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT},
-    EscrowEvent, MultisigError, Permission, Recipient,
+    state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_VAULT},
+    EscrowEvent, EscrowOutcome, EscrowReceipt, FeeAccounts, LegAccounts, MarketplaceFee, MultisigError, Permission, Recipient, SEED_PROTOCOL_CONFIG,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
@@ -15,14 +15,16 @@ pub struct ExecuteEscrowAsOwner<'info> {
     #[account(
         mut,
         seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         mut,
-        close = payer,
+        close = rent_payer,
         seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
-        bump = escrow.bump
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
     )]
     pub escrow: Box<Account<'info, Escrow>>,
     #[account(
@@ -46,6 +48,35 @@ pub struct ExecuteEscrowAsOwner<'info> {
         associated_token::token_program = token_program
     )]
     pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: may be uninitialized, in which case no protocol fee is charged.
+    #[account(
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+    /// CHECK: checked against the protocol config
+    #[account(mut)]
+    pub protocol_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = protocol_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub protocol_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: checked against the escrow marketplace fee
+    #[account(mut)]
+    pub marketplace_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = marketplace_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub marketplace_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
     pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
     /// CHECK: instructions sysvar
     #[account(address = tx_instructions::ID)]
@@ -56,12 +87,25 @@ pub struct ExecuteEscrowAsOwner<'info> {
     #[account(mut)]
     pub recipient: UncheckedAccount<'info>,
     pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    /// CHECK: receives the escrow rent and that of the emptied escrow leg token accounts, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// CHECK: receives the rent freed by shrinking the multi-wallet, checked by `shrink_if_needed`.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> ExecuteEscrowAsOwner<'info> {
-    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, marketplace_fee: &Option<MarketplaceFee>) -> Result<()> {
         let Self {
             multi_wallet,
             escrow,
@@ -74,14 +118,8 @@ impl<'info> ExecuteEscrowAsOwner<'info> {
         let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
 
         require!(
-            multi_wallet.threshold
-                <= unique_signers
-                    .iter()
-                    .filter(|x| x.permissions.is_some()
-                        && x.permissions.unwrap().has(Permission::VoteEscrow))
-                    .count()
-                    .try_into()
-                    .unwrap(),
+            usize::from(multi_wallet.threshold)
+                <= multi_wallet.count_escrow_votes(&unique_signers, &escrow.votes, true),
             MultisigError::NotEnoughSigners
         );
 
@@ -95,26 +133,75 @@ impl<'info> ExecuteEscrowAsOwner<'info> {
             MultisigError::InsufficientSignerWithExecutePermission
         );
 
-        require!(
-            multi_wallet.pending_offers.contains(&escrow.key()),
-            MultisigError::EscrowDoesNotExist
-        );
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        require!(!escrow.is_expired()?, MultisigError::EscrowExpired);
         require!(
             escrow.proposer.is_some(),
             MultisigError::UnauthorisedToAcceptEscrowOffer
         );
+        // The marketplace fee of a bid is chosen by the buyer and paid out of the proceeds,
+        // so the owners state the fee they agree to when executing it.
+        require!(
+            *marketplace_fee == escrow.marketplace_fee,
+            MultisigError::MarketplaceFeeNotApproved
+        );
+        // A seat is only sold with the consent of its member, who receives the payment.
+        if let Some(seat) = escrow.seat {
+            require!(
+                account_infos.iter().any(|x| x.is_signer && x.key() == seat)
+                    && self.recipient.key() == seat,
+                MultisigError::InvalidSeat
+            );
+        }
+
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(&ctx))]
-    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+    #[access_control(ctx.accounts.validate(&ctx, &marketplace_fee))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, marketplace_fee: Option<MarketplaceFee>) -> Result<()> {
         let escrow = &ctx.accounts.escrow;
         let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let seller_members = multi_wallet.members.clone();
+
+        let multi_wallet_key = multi_wallet.key();
+        for expectation in &escrow.asset_manifest {
+            expectation.verify(&multi_wallet_key, ctx.remaining_accounts)?;
+        }
 
-        multi_wallet.set_members(escrow.new_members.as_ref().unwrap().clone());
-        multi_wallet.set_threshold(escrow.threshold.unwrap());
+        // Grow the account before the buyer's members are written, as they may outnumber the seller's.
+        let members_length = match escrow.seat {
+            Some(_) => multi_wallet.members.len(),
+            None => escrow.new_members.as_ref().unwrap().len(),
+        };
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            members_length,
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
 
-        escrow.escrow_transfer(
+        match escrow.seat {
+            Some(seat) => multi_wallet.transfer_seat(seat, escrow.proposer.unwrap(), Clock::get()?.unix_timestamp)?,
+            None => {
+                multi_wallet.set_members(
+                    escrow.new_members.as_ref().unwrap().clone(),
+                    escrow.proposer.unwrap(),
+                    Clock::get()?.unix_timestamp,
+                );
+                multi_wallet.set_threshold(escrow.threshold.unwrap());
+            }
+        }
+
+        let fee_accounts = FeeAccounts {
+            protocol_config: &ctx.accounts.protocol_config,
+            protocol_fee_recipient: &ctx.accounts.protocol_fee_recipient,
+            protocol_fee_token_account: &ctx.accounts.protocol_fee_token_account,
+            marketplace_fee_recipient: &ctx.accounts.marketplace_fee_recipient,
+            marketplace_fee_token_account: &ctx.accounts.marketplace_fee_token_account,
+        };
+        let fees = escrow.escrow_transfer_with_fees(
+            fee_accounts,
             &ctx.accounts.mint,
             &ctx.accounts.escrow_token_vault,
             &ctx.accounts.recipient_token_account,
@@ -125,9 +212,39 @@ impl<'info> ExecuteEscrowAsOwner<'info> {
             &ctx.accounts.token_program,
             &ctx.accounts.system_program.to_account_info(),
         )?;
+        escrow.transfer_additional_legs_with_fees(
+            fee_accounts,
+            &LegAccounts {
+                account_infos: ctx.remaining_accounts,
+                rent_payer: Some(&ctx.accounts.payer.to_account_info()),
+                vault_rent_refund: Some(&ctx.accounts.rent_payer.to_account_info()),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            },
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+        )?;
 
         MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
-        multi_wallet.clear_pending_offers();
+        multi_wallet.settle_offer(escrow.key());
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            Some(&ctx.accounts.payer.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&seller_members),
+            Some(&multi_wallet.members),
+            EscrowOutcome::Executed,
+        )?;
+
+        MultiWallet::shrink_if_needed(
+            multi_wallet,
+            ctx.accounts
+                .rent_collector
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
         emit_cpi!(EscrowEvent {
             create_key: multi_wallet.create_key,
             identifier: escrow.identifier,
@@ -141,7 +258,10 @@ impl<'info> ExecuteEscrowAsOwner<'info> {
             approver: Some(ctx.accounts.recipient.key()),
             proposer: escrow.proposer,
             new_members: escrow.new_members.clone(),
-            threshold: escrow.threshold
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees
         });
 
         Ok(())
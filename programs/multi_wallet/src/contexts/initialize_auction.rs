@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Auction, AuctionKind, Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, EscrowFees, MarketplaceFee, MultisigError, Permission, Recipient};
+use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAuction<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::size(0, 0, 0, 0, multi_wallet.escrow_voter_count()),
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), multi_wallet.escrow_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), multi_wallet.escrow_counter.to_le_bytes().as_ref(), SEED_VAULT],
+        bump
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+    /// CHECK: instructions sysvar
+    #[account(address = tx_instructions::ID)]
+    pub instruction_sysvar: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeAuction<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, marketplace_fee: &Option<MarketplaceFee>) -> Result<()> {
+        let Self {
+            multi_wallet,
+            instruction_sysvar,
+            ..
+        } = self;
+        MultiWallet::durable_nonce_check(instruction_sysvar)?;
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+        require!(multi_wallet.threshold > 1, MultisigError::MissingOwner);
+        Escrow::validate_marketplace_fee(marketplace_fee)?;
+
+        require!(
+            unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::InitiateEscrow)).count() >= 1,
+            MultisigError::InsufficientSignerWithInitiatePermission
+        );
+
+        require!(
+            multi_wallet.threshold <= unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::VoteEscrow)).count().try_into().unwrap(),
+            MultisigError::NotEnoughSigners
+        );
+
+        require!(
+            unique_signers.iter().filter(|x| x.permissions.is_some() && x.permissions.unwrap().has(Permission::ExecuteEscrow)).count() >= 1,
+            MultisigError::InsufficientSignerWithExecutePermission
+        );
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx, &marketplace_fee))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, recipient: Pubkey, mint: Option<Pubkey>, kind: AuctionKind, ends_at: i64, marketplace_fee: Option<MarketplaceFee>) -> Result<()> {
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let escrow = &mut ctx.accounts.escrow;
+        multi_wallet.add_listing(escrow.key())?;
+
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            multi_wallet.members.len(),
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        let auction = Auction::new(kind, ends_at)?;
+        // English auctions start without a bid, Dutch auctions start at their highest price.
+        let amount = match kind {
+            AuctionKind::English { .. } => 0,
+            AuctionKind::Dutch { start_price, .. } => start_price,
+        };
+
+        escrow.bump = ctx.bumps.escrow;
+        escrow.vault_bump = Some(ctx.bumps.escrow_vault);
+        escrow.identifier = multi_wallet.allocate_escrow_identifier()?;
+        escrow.create_key = multi_wallet.create_key;
+        escrow.new_members = None;
+        escrow.threshold = None;
+        escrow.recipient = Recipient {
+            pubkey: Some(recipient),
+            amount,
+            mint,
+        };
+        escrow.proposer = None;
+        // A Dutch auction that ends unsold can only be cancelled, so it expires when it ends.
+        // English auctions are closed by `settle_auction` instead.
+        escrow.expires_at = match kind {
+            AuctionKind::English { .. } => None,
+            AuctionKind::Dutch { .. } => Some(ends_at),
+        };
+        escrow.auction = Some(auction);
+        escrow.marketplace_fee = marketplace_fee;
+        escrow.counter_offer = None;
+        escrow.asset_manifest = Vec::new();
+        escrow.additional_legs = Vec::new();
+        escrow.votes = Vec::new();
+        escrow.created_at = Clock::get()?.unix_timestamp;
+        escrow.allowed_buyers = Vec::new();
+        escrow.seat = None;
+        escrow.rent_payer = ctx.accounts.payer.key();
+        escrow.version = ESCROW_VERSION;
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: true,
+            is_rejected: false,
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
+        });
+        Ok(())
+    }
+}
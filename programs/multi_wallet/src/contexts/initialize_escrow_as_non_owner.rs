@@ -1,28 +1,51 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
-use crate::{state::{Escrow, Member, MultiWallet, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, MultisigError, Permission, Recipient};
+use crate::{state::{Escrow, Member, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, EscrowFees, MarketplaceFee, LegAccounts, MultisigError, PaymentLeg, Permission, Recipient, VaultAssetExpectation};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeEscrowAsNonOwnerArgs {
+    /// The members of the multi-wallet after the escrow is executed.
+    pub new_members: Vec<Member>,
+    /// The amount locked in the escrow vault.
+    pub amount: u64,
+    /// The threshold of the multi-wallet after the escrow is executed.
+    pub threshold: u8,
+    /// Optional unix timestamp after which the escrow can no longer be executed.
+    pub expires_at: Option<i64>,
+    /// Optional fee paid to the marketplace the escrow was created through.
+    pub marketplace_fee: Option<MarketplaceFee>,
+    /// Vault balances that must still be present when the owners execute the escrow.
+    pub asset_manifest: Vec<VaultAssetExpectation>,
+    /// Assets deposited in addition to `amount`. Their mints, token programs and token accounts
+    /// are passed as remaining accounts.
+    pub additional_legs: Vec<PaymentLeg>,
+    /// Member whose seat is bought instead of the whole multi-wallet. `new_members` must then be
+    /// empty and `threshold` is ignored.
+    pub seat: Option<Pubkey>,
+}
 
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(identifier: u64, new_owners: Vec<Member>)]
+#[instruction(args: InitializeEscrowAsNonOwnerArgs)]
 pub struct InitializeEscrowAsNonOwner<'info> {
     #[account(
         mut,
         seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         init, 
         payer = proposer,
-        space = Escrow::size(new_owners.len()),
-        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), identifier.to_le_bytes().as_ref()],
+        space = Escrow::size(args.new_members.len(), args.asset_manifest.len(), args.additional_legs.len(), 0, multi_wallet.escrow_voter_count()),
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), multi_wallet.escrow_counter.to_le_bytes().as_ref()],
         bump
     )]
     pub escrow: Box<Account<'info, Escrow>>,
     #[account(
         mut,
-        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), multi_wallet.escrow_counter.to_le_bytes().as_ref(), SEED_VAULT],
         bump
     )]
     pub escrow_vault: SystemAccount<'info>,
@@ -51,32 +74,93 @@ pub struct InitializeEscrowAsNonOwner<'info> {
 }
 
 
-impl<'info> InitializeEscrowAsNonOwner<'info> {
-    fn validate(&self, threshold: &u8, new_members: &Vec<Member>) -> Result<()> {
-        let Self {
-            multi_wallet,
-            member,
-            ..
-        } = self;
+impl InitializeEscrowAsNonOwnerArgs {
+    /// Checks the terms of a non-owner escrow on `multi_wallet`, proposed by `proposer` through `member`.
+    pub fn validate(
+        &self,
+        multi_wallet: &MultiWallet,
+        member: &Pubkey,
+        proposer: &Pubkey,
+        mint: Option<Pubkey>,
+    ) -> Result<()> {
         require!(
             multi_wallet
-                .members
-                .iter()
-                .filter(|x| x.pubkey.eq(member.key) && x.permissions.is_some() && x.permissions.unwrap().has(Permission::InitiateEscrow)).count() == 1,
+                .find_member(member)
+                .is_some_and(|x| x.permissions.is_some_and(|x| x.has(Permission::InitiateEscrow))),
             MultisigError::InsufficientSignerWithInitiatePermission
         );
         require!(multi_wallet.threshold > 1, MultisigError::MissingOwner);
-        MultiWallet::check_state_validity(threshold, new_members)?;
+        match self.seat {
+            Some(seat) => {
+                require!(
+                    self.new_members.is_empty()
+                        && multi_wallet.find_member(&seat).is_some()
+                        && multi_wallet.find_member(proposer).is_none(),
+                    MultisigError::InvalidSeat
+                );
+            }
+            None => MultiWallet::check_state_validity(&self.threshold, &self.new_members)?,
+        }
+        Escrow::validate_expiry(&self.expires_at)?;
+        Escrow::validate_marketplace_fee(&self.marketplace_fee)?;
+        VaultAssetExpectation::validate_manifest(&self.asset_manifest)?;
+        Escrow::validate_additional_legs(&mint, &self.additional_legs)?;
 
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(&threshold, &new_members))]
-    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, identifier: u64, new_members: Vec<Member>, amount: u64, threshold: u8,) -> Result<()> {
+    /// Builds the pending escrow described by the arguments.
+    pub fn into_escrow(
+        self,
+        create_key: Pubkey,
+        identifier: u64,
+        bump: u8,
+        vault_bump: u8,
+        proposer: Pubkey,
+        mint: Option<Pubkey>,
+    ) -> Result<Escrow> {
+        Ok(Escrow {
+            create_key,
+            identifier,
+            bump,
+            vault_bump: Some(vault_bump),
+            new_members: self.seat.is_none().then_some(self.new_members),
+            recipient: Recipient {
+                pubkey: None,
+                mint,
+                amount: self.amount,
+            },
+            proposer: Some(proposer),
+            threshold: self.seat.is_none().then_some(self.threshold),
+            expires_at: self.expires_at,
+            marketplace_fee: self.marketplace_fee,
+            asset_manifest: self.asset_manifest,
+            additional_legs: self.additional_legs,
+            created_at: Clock::get()?.unix_timestamp,
+            rent_payer: proposer,
+            version: ESCROW_VERSION,
+            seat: self.seat,
+            ..Default::default()
+        })
+    }
+}
+
+impl<'info> InitializeEscrowAsNonOwner<'info> {
+    fn validate(&self, args: &InitializeEscrowAsNonOwnerArgs) -> Result<()> {
+        args.validate(
+            &self.multi_wallet,
+            self.member.key,
+            self.proposer.key,
+            self.mint.as_ref().map(|x| x.key()),
+        )
+    }
+
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, args: InitializeEscrowAsNonOwnerArgs) -> Result<()> {
         let multi_wallet = &mut ctx.accounts.multi_wallet;
         let escrow = &mut ctx.accounts.escrow;
 
-        multi_wallet.add_offer(escrow.key());
+        multi_wallet.add_bid(escrow.key())?;
 
         MultiWallet::realloc_if_needed(
             multi_wallet.to_account_info(),
@@ -86,19 +170,15 @@ impl<'info> InitializeEscrowAsNonOwner<'info> {
             Some(ctx.accounts.system_program.to_account_info()),
         )?;
 
-        let mint = ctx.accounts.mint.as_ref().map_or(None, |x| Some(x.key()));
-        escrow.bump = ctx.bumps.escrow;
-        escrow.vault_bump = Some(ctx.bumps.escrow_vault);
-        escrow.identifier = identifier;
-        escrow.create_key = multi_wallet.create_key;
-        escrow.new_members = Some(new_members);
-        escrow.recipient = Recipient {
-            pubkey: None,
-            mint,
-            amount,
-        };
-        escrow.proposer = Some(ctx.accounts.proposer.key());
-        escrow.threshold = Some(threshold);
+        let identifier = multi_wallet.allocate_escrow_identifier()?;
+        escrow.set_inner(args.into_escrow(
+            multi_wallet.create_key,
+            identifier,
+            ctx.bumps.escrow,
+            ctx.bumps.escrow_vault,
+            ctx.accounts.proposer.key(),
+            ctx.accounts.mint.as_ref().map(|x| x.key()),
+        )?);
 
         escrow.escrow_transfer(
             &ctx.accounts.mint,
@@ -111,6 +191,18 @@ impl<'info> InitializeEscrowAsNonOwner<'info> {
             &ctx.accounts.token_program,
             &ctx.accounts.system_program.to_account_info(),
         )?;
+        escrow.transfer_additional_legs(
+            &LegAccounts {
+                account_infos: ctx.remaining_accounts,
+                rent_payer: Some(&ctx.accounts.proposer.to_account_info()),
+                vault_rent_refund: None,
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            },
+            &ctx.accounts.proposer.to_account_info(),
+            &ctx.accounts.escrow_vault.to_account_info(),
+            false,
+        )?;
 
         emit_cpi!(EscrowEvent {
             create_key: escrow.create_key,
@@ -121,7 +213,10 @@ impl<'info> InitializeEscrowAsNonOwner<'info> {
             approver: None,
             proposer: escrow.proposer,
             new_members: escrow.new_members.clone(),
-            threshold: escrow.threshold
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
         });
 
         Ok(())
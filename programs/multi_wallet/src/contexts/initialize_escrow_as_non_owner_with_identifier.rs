@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::{create_program_account, state::{Escrow, MultiWallet, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, EscrowFees, InitializeEscrowAsNonOwnerArgs, LegAccounts, MultisigError};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(identifier: u64)]
+pub struct InitializeEscrowAsNonOwnerWithIdentifier<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    /// CHECK: created by the instruction, must not be in use.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        bump
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program
+    )]
+    pub proposer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub member: Signer<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+
+impl<'info> InitializeEscrowAsNonOwnerWithIdentifier<'info> {
+    fn validate(&self, identifier: u64, args: &InitializeEscrowAsNonOwnerArgs) -> Result<()> {
+        MultiWallet::validate_client_escrow_identifier(identifier)?;
+        require!(
+            self.escrow.data_is_empty() && self.escrow.owner.eq(&System::id()),
+            MultisigError::EscrowIdentifierInUse
+        );
+        args.validate(
+            &self.multi_wallet,
+            self.member.key,
+            self.proposer.key,
+            self.mint.as_ref().map(|x| x.key()),
+        )
+    }
+
+    #[access_control(ctx.accounts.validate(identifier, &args))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, identifier: u64, args: InitializeEscrowAsNonOwnerArgs) -> Result<()> {
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let escrow_account = &ctx.accounts.escrow;
+
+        multi_wallet.add_bid(escrow_account.key())?;
+
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            multi_wallet.members.len(),
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.proposer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        create_program_account(
+            &ctx.accounts.proposer.to_account_info(),
+            &escrow_account.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            Escrow::size(args.new_members.len(), args.asset_manifest.len(), args.additional_legs.len(), 0, multi_wallet.escrow_voter_count()),
+            &[SEED_ESCROW, multi_wallet.create_key.as_ref(), identifier.to_le_bytes().as_ref(), &[ctx.bumps.escrow]],
+        )?;
+        let escrow = args.into_escrow(
+            multi_wallet.create_key,
+            identifier,
+            ctx.bumps.escrow,
+            ctx.bumps.escrow_vault,
+            ctx.accounts.proposer.key(),
+            ctx.accounts.mint.as_ref().map(|x| x.key()),
+        )?;
+        escrow.try_serialize(&mut &mut escrow_account.try_borrow_mut_data()?[..])?;
+
+        escrow.escrow_transfer(
+            &ctx.accounts.mint,
+            &ctx.accounts.proposer_token_account,
+            &ctx.accounts.escrow_token_vault,
+            &Some(ctx.accounts.proposer.to_account_info()),
+            &Some(ctx.accounts.escrow_vault.to_account_info()),
+            &None,
+            &ctx.accounts.proposer.to_account_info(),
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        escrow.transfer_additional_legs(
+            &LegAccounts {
+                account_infos: ctx.remaining_accounts,
+                rent_payer: Some(&ctx.accounts.proposer.to_account_info()),
+                vault_rent_refund: None,
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            },
+            &ctx.accounts.proposer.to_account_info(),
+            &ctx.accounts.escrow_vault.to_account_info(),
+            false,
+        )?;
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: true,
+            is_rejected: false,
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
+        });
+
+        Ok(())
+    }
+}
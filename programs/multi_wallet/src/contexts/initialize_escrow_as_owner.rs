@@ -1,22 +1,39 @@
 use anchor_lang::prelude::*;
-use crate::{state::{Escrow, MultiWallet, SEED_ESCROW, SEED_MULTISIG}, EscrowEvent, MultisigError, Permission, Recipient};
+use crate::{state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG}, EscrowEvent, EscrowFees, MarketplaceFee, MultisigError, Permission, Recipient};
 use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeEscrowAsOwnerArgs {
+    /// The account receiving the payment.
+    pub recipient: Pubkey,
+    /// The amount asked for the multi-wallet.
+    pub amount: u64,
+    /// Token mint the amount is denominated in (if any).
+    pub mint: Option<Pubkey>,
+    /// Optional unix timestamp after which the escrow can no longer be executed.
+    pub expires_at: Option<i64>,
+    /// Optional fee paid to the marketplace the escrow was created through.
+    pub marketplace_fee: Option<MarketplaceFee>,
+    /// Buyers allowed to execute the listing. Anyone can buy it if empty.
+    pub allowed_buyers: Vec<Pubkey>,
+}
+
 #[event_cpi]
 #[derive(Accounts)]
-#[instruction(identifier: u64)]
+#[instruction(args: InitializeEscrowAsOwnerArgs)]
 pub struct InitializeEscrowAsOwner<'info> {
     #[account(
         mut, 
         seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     #[account(
         init, 
         payer = payer,
-        space = Escrow::size(0),
-        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), identifier.to_le_bytes().as_ref()],
+        space = Escrow::size(0, 0, 0, args.allowed_buyers.len(), multi_wallet.escrow_voter_count()),
+        seeds = [SEED_ESCROW, multi_wallet.create_key.key().as_ref(), multi_wallet.escrow_counter.to_le_bytes().as_ref()],
         bump
     )]
     pub escrow: Box<Account<'info, Escrow>>,
@@ -29,7 +46,7 @@ pub struct InitializeEscrowAsOwner<'info> {
 }
 
 impl<'info> InitializeEscrowAsOwner<'info> {
-    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>, args: &InitializeEscrowAsOwnerArgs) -> Result<()> {
         let Self {
             multi_wallet,
             instruction_sysvar,
@@ -40,6 +57,9 @@ impl<'info> InitializeEscrowAsOwner<'info> {
         let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
 
         require!(multi_wallet.threshold > 1, MultisigError::MissingOwner);
+        Escrow::validate_expiry(&args.expires_at)?;
+        Escrow::validate_marketplace_fee(&args.marketplace_fee)?;
+        Escrow::validate_allowed_buyers(&args.allowed_buyers)?;
 
 
         require!(
@@ -61,11 +81,11 @@ impl<'info> InitializeEscrowAsOwner<'info> {
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(&ctx))]
-    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, identifier: u64, recipient: Pubkey, amount: u64, mint: Option<Pubkey>) -> Result<()> {
+    #[access_control(ctx.accounts.validate(&ctx, &args))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, args: InitializeEscrowAsOwnerArgs) -> Result<()> {
         let multi_wallet = &mut ctx.accounts.multi_wallet;
         let escrow = &mut ctx.accounts.escrow;
-        multi_wallet.add_offer(escrow.key());
+        multi_wallet.add_listing(escrow.key())?;
 
         MultiWallet::realloc_if_needed(
             multi_wallet.to_account_info(),
@@ -77,16 +97,28 @@ impl<'info> InitializeEscrowAsOwner<'info> {
 
         escrow.bump = ctx.bumps.escrow;
         escrow.vault_bump = None;
-        escrow.identifier = identifier;
+        escrow.identifier = multi_wallet.allocate_escrow_identifier()?;
         escrow.create_key = multi_wallet.create_key;
         escrow.new_members = None;
         escrow.threshold = None;
         escrow.recipient = Recipient {
-            pubkey: Some(recipient),
-            amount,
-            mint,
+            pubkey: Some(args.recipient),
+            amount: args.amount,
+            mint: args.mint,
         };
         escrow.proposer = None;
+        escrow.expires_at = args.expires_at;
+        escrow.auction = None;
+        escrow.marketplace_fee = args.marketplace_fee;
+        escrow.counter_offer = None;
+        escrow.asset_manifest = Vec::new();
+        escrow.additional_legs = Vec::new();
+        escrow.votes = Vec::new();
+        escrow.created_at = Clock::get()?.unix_timestamp;
+        escrow.allowed_buyers = args.allowed_buyers;
+        escrow.seat = None;
+        escrow.rent_payer = ctx.accounts.payer.key();
+        escrow.version = ESCROW_VERSION;
 
         emit_cpi!(EscrowEvent {
             create_key: escrow.create_key,
@@ -97,7 +129,10 @@ impl<'info> InitializeEscrowAsOwner<'info> {
             approver: None,
             proposer: escrow.proposer,
             new_members: escrow.new_members.clone(),
-            threshold: escrow.threshold
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
         });
         Ok(())
     }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{program::MultiWallet as MultiWalletProgram, state::{ProtocolConfig, SEED_PROTOCOL_CONFIG}};
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::size(),
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, MultiWalletProgram>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()))]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeProtocolConfig<'_> {
+    fn validate(&self, fee_bps: &u16) -> Result<()> {
+        ProtocolConfig::validate_fee_bps(*fee_bps)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&fee_bps))]
+    pub fn process(ctx: Context<Self>, fee_recipient: Pubkey, fee_bps: u16) -> Result<()> {
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.authority = ctx.accounts.authority.key();
+        protocol_config.fee_recipient = fee_recipient;
+        protocol_config.fee_bps = fee_bps;
+        protocol_config.bump = ctx.bumps.protocol_config;
+        Ok(())
+    }
+}
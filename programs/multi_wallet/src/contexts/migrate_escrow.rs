@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{id, state::{Escrow, MultiWallet, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG}, MultisigError, VaultSigner};
+
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    /// CHECK: read in its old layout and checked against its seeds by the instruction.
+    #[account(mut, owner = id())]
+    pub escrow: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateEscrow<'info> {
+    /// Upgrade an escrow to the current account layout, reserving room for the votes of the escrow voters
+    /// of its multi-wallet. Like `migrate_multi_wallet`, a large escrow is grown over several calls and only
+    /// rewritten by the call that brings it to its full size.
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let multi_wallet = &ctx.accounts.multi_wallet;
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let default_vault = VaultSigner::derive(multi_wallet.key(), 0, &id());
+        let escrow = Escrow::upgrade(
+            &escrow_info.try_borrow_data()?,
+            default_vault.pubkey,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let (escrow_key, bump) = Pubkey::find_program_address(
+            &[SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+            &id(),
+        );
+        require!(
+            escrow_info.key() == escrow_key
+                && escrow.bump == bump
+                && escrow.create_key == multi_wallet.create_key,
+            MultisigError::InvalidAccount
+        );
+
+        let target_size = escrow.space(
+            escrow.new_members.as_ref().map_or(0, Vec::len),
+            multi_wallet.escrow_voter_count(),
+        );
+        let step_size = MultiWallet::growth_step(escrow_info.data_len(), target_size);
+        MultiWallet::realloc_to_size(
+            escrow_info.clone(),
+            step_size,
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+        if step_size < target_size {
+            // The old layout is left untouched until the account is large enough.
+            return Ok(());
+        }
+        escrow.try_serialize(&mut &mut escrow_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{id, state::{MultiWallet, SEED_MULTISIG}, MultisigError};
+
+#[derive(Accounts)]
+pub struct MigrateMultiWallet<'info> {
+    /// CHECK: read in its old layout and checked against its seeds by the instruction.
+    #[account(mut, owner = id())]
+    pub multi_wallet: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateMultiWallet<'info> {
+    /// Upgrade a multi-wallet to the current account layout, growing it if needed. An account can only grow
+    /// by `MAX_PERMITTED_DATA_INCREASE` bytes per instruction, so a large multi-wallet is grown over several
+    /// calls and only rewritten by the call that brings it to its full size.
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let multi_wallet_info = ctx.accounts.multi_wallet.to_account_info();
+        let multi_wallet = MultiWallet::upgrade(&multi_wallet_info.try_borrow_data()?)?;
+
+        let (multi_wallet_key, bump) = Pubkey::find_program_address(
+            &[SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+            &id(),
+        );
+        require!(
+            multi_wallet_info.key() == multi_wallet_key && multi_wallet.bump == bump,
+            MultisigError::InvalidAccount
+        );
+
+        let target_size = MultiWallet::size(multi_wallet.members.len(), multi_wallet.pending_offers.len());
+        let step_size = MultiWallet::growth_step(multi_wallet_info.data_len(), target_size);
+        MultiWallet::realloc_to_size(
+            multi_wallet_info.clone(),
+            step_size,
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+        if step_size < target_size {
+            // The old layout is left untouched until the account is large enough.
+            return Ok(());
+        }
+        multi_wallet.try_serialize(&mut &mut multi_wallet_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
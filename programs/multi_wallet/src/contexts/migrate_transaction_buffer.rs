@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::{id, state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, MultisigError, TransactionBuffer, SEED_TRANSACTION_BUFFER};
+
+#[derive(Accounts)]
+pub struct MigrateTransactionBuffer<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    /// CHECK: read in its old layout and checked against its seeds by the instruction.
+    #[account(mut, owner = id())]
+    pub transaction_buffer: UncheckedAccount<'info>,
+    /// CHECK: checked against the rent payer recorded in the transaction buffer.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateTransactionBuffer<'info> {
+    /// Upgrade a transaction buffer to the current account layout and give it a transaction nonce. A buffer
+    /// too large to fit the current layout could never be executed, so it is closed and its rent refunded.
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let transaction_buffer_info = ctx.accounts.transaction_buffer.to_account_info();
+        let mut transaction_buffer = TransactionBuffer::upgrade(&transaction_buffer_info.try_borrow_data()?)?;
+
+        let (transaction_buffer_key, bump) = Pubkey::find_program_address(
+            &[
+                SEED_MULTISIG,
+                multi_wallet.key().as_ref(),
+                SEED_TRANSACTION_BUFFER,
+                transaction_buffer.creator.as_ref(),
+                &transaction_buffer.buffer_index.to_le_bytes(),
+            ],
+            &id(),
+        );
+        require!(
+            transaction_buffer_info.key() == transaction_buffer_key
+                && transaction_buffer.bump == bump
+                && transaction_buffer.multi_wallet == multi_wallet.key(),
+            MultisigError::InvalidAccount
+        );
+        let rent_payer = &ctx.accounts.rent_payer;
+        require_keys_eq!(
+            rent_payer.key(),
+            transaction_buffer.rent_payer,
+            MultisigError::InvalidAccount
+        );
+
+        let Ok(target_size) = TransactionBuffer::size(transaction_buffer.final_buffer_size, 0) else {
+            // Close the buffer the way Anchor's `close` constraint does.
+            **rent_payer.lamports.borrow_mut() = rent_payer
+                .lamports()
+                .checked_add(transaction_buffer_info.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            **transaction_buffer_info.lamports.borrow_mut() = 0;
+            transaction_buffer_info.assign(&System::id());
+            transaction_buffer_info.realloc(0, false)?;
+            return Ok(());
+        };
+
+        MultiWallet::realloc_to_size(
+            transaction_buffer_info.clone(),
+            target_size,
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+        transaction_buffer.transaction_nonce = multi_wallet.next_transaction_nonce()?;
+        // Buffers in the old layout predate the open buffer count of the multi-wallet.
+        multi_wallet.open_buffer()?;
+        transaction_buffer.try_serialize(&mut &mut transaction_buffer_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
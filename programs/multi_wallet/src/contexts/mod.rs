@@ -16,9 +16,18 @@ pub use transaction_buffer_close::*;
 pub mod vault_transaction_execute;
 pub use vault_transaction_execute::*;
 
+pub mod vault_transaction_execute_inline;
+pub use vault_transaction_execute_inline::*;
+
+pub mod vault_transaction_validate;
+pub use vault_transaction_validate::*;
+
 pub mod initialize_escrow_as_non_owner;
 pub use initialize_escrow_as_non_owner::*;
 
+pub mod initialize_escrow_as_non_owner_with_identifier;
+pub use initialize_escrow_as_non_owner_with_identifier::*;
+
 pub mod initialize_escrow_as_owner;
 pub use initialize_escrow_as_owner::*;
 
@@ -33,3 +42,48 @@ pub use cancel_escrow_as_owner::*;
 
 pub mod cancel_escrow_as_non_owner;
 pub use cancel_escrow_as_non_owner::*;
+
+pub mod cancel_expired_escrow;
+pub use cancel_expired_escrow::*;
+
+pub mod escrow_cast_vote;
+pub use escrow_cast_vote::*;
+
+pub mod escrow_counter_offer;
+pub use escrow_counter_offer::*;
+
+pub mod accept_escrow_counter_offer;
+pub use accept_escrow_counter_offer::*;
+
+pub mod initialize_auction;
+pub use initialize_auction::*;
+
+pub mod place_auction_bid;
+pub use place_auction_bid::*;
+
+pub mod settle_auction;
+pub use settle_auction::*;
+
+pub mod initialize_protocol_config;
+pub use initialize_protocol_config::*;
+
+pub mod update_protocol_config;
+pub use update_protocol_config::*;
+
+pub mod accept_signed_offer;
+pub use accept_signed_offer::*;
+
+pub mod close_escrow_receipt;
+pub use close_escrow_receipt::*;
+
+pub mod close_multi_wallet;
+pub use close_multi_wallet::*;
+
+pub mod migrate_multi_wallet;
+pub use migrate_multi_wallet::*;
+
+pub mod migrate_transaction_buffer;
+pub use migrate_transaction_buffer::*;
+
+pub mod migrate_escrow;
+pub use migrate_escrow::*;
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
+use crate::{state::{Escrow, Member, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_MULTISIG, SEED_VAULT}, EscrowEvent, EscrowFees, MultisigError};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64, new_members: Vec<Member>)]
+pub struct PlaceAuctionBid<'info> {
+    #[account(
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        realloc = escrow.space(new_members.len(), escrow.vote_capacity(escrow.to_account_info().data_len())),
+        realloc::payer = bidder,
+        realloc::zero = false,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        bump = escrow.vault_bump.unwrap()
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bidder,
+        associated_token::token_program = token_program
+    )]
+    pub bidder_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: checked against the escrow proposer, receives the refund of the outbid bid.
+    #[account(mut)]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+    /// Created if the outbid bidder closed it, so that the refund cannot be blocked.
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        associated_token::mint = mint,
+        associated_token::authority = previous_bidder,
+        associated_token::token_program = token_program
+    )]
+    pub previous_bidder_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> PlaceAuctionBid<'info> {
+    fn validate(&self, amount: &u64, new_members: &Vec<Member>, threshold: &u8) -> Result<()> {
+        let Self {
+            multi_wallet,
+            escrow,
+            previous_bidder,
+            ..
+        } = self;
+
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        let auction = escrow
+            .auction
+            .filter(|x| x.is_english())
+            .ok_or(MultisigError::InvalidAuction)?;
+        require!(!auction.has_ended()?, MultisigError::AuctionEnded);
+
+        let highest_bid = escrow.proposer.map(|_| escrow.recipient.amount);
+        require!(
+            *amount >= auction.min_bid(highest_bid)?,
+            MultisigError::AuctionBidTooLow
+        );
+        if let Some(proposer) = escrow.proposer {
+            require!(
+                previous_bidder
+                    .as_ref()
+                    .is_some_and(|x| x.key() == proposer),
+                MultisigError::InvalidEscrowProposer
+            );
+        }
+        MultiWallet::check_state_validity(threshold, new_members)?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&amount, &new_members, &threshold))]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, amount: u64, new_members: Vec<Member>, threshold: u8) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let previous_proposer = escrow.proposer;
+        let previous_amount = escrow.recipient.amount;
+
+        // Deposit the new bid before refunding the outbid one, so the escrow token vault
+        // is not emptied and closed in between.
+        escrow.transfer_amount(
+            amount,
+            &ctx.accounts.mint,
+            &ctx.accounts.bidder_token_account,
+            &ctx.accounts.escrow_token_vault,
+            &Some(ctx.accounts.bidder.to_account_info()),
+            &Some(ctx.accounts.escrow_vault.to_account_info()),
+            &None,
+            &ctx.accounts.bidder.to_account_info(),
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Refund the bid that is being outbid.
+        if let Some(previous_bidder) = &ctx.accounts.previous_bidder {
+            if previous_proposer.is_some() {
+                escrow.transfer_amount(
+                    previous_amount,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.escrow_token_vault,
+                    &ctx.accounts.previous_bidder_token_account,
+                    &Some(ctx.accounts.escrow_vault.to_account_info()),
+                    &Some(previous_bidder.to_account_info()),
+                    &Some(ctx.accounts.escrow_vault.to_account_info()),
+                    &previous_bidder.to_account_info(),
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.system_program.to_account_info(),
+                )?;
+            }
+        }
+
+        escrow.proposer = Some(ctx.accounts.bidder.key());
+        escrow.recipient.amount = amount;
+        escrow.new_members = Some(new_members);
+        escrow.threshold = Some(threshold);
+        // Votes were cast on the outbid terms.
+        escrow.votes.clear();
+
+        emit_cpi!(EscrowEvent {
+            create_key: escrow.create_key,
+            identifier: escrow.identifier,
+            is_pending: true,
+            is_rejected: false,
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees: EscrowFees::default()
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    state::{Escrow, MultiWallet, ESCROW_VERSION, MULTI_WALLET_VERSION, SEED_ESCROW, SEED_ESCROW_RECEIPT, SEED_MULTISIG, SEED_VAULT},
+    EscrowEvent, EscrowFees, EscrowOutcome, EscrowReceipt, FeeAccounts, MultisigError, SEED_PROTOCOL_CONFIG,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, escrow.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.version == ESCROW_VERSION @MultisigError::EscrowNotMigrated,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref(), SEED_VAULT],
+        bump = escrow.vault_bump.unwrap()
+    )]
+    pub escrow_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow_vault,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: may be uninitialized, in which case no protocol fee is charged.
+    #[account(
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+    /// CHECK: checked against the protocol config
+    #[account(mut)]
+    pub protocol_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = protocol_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub protocol_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: checked against the escrow marketplace fee
+    #[account(mut)]
+    pub marketplace_fee_recipient: Option<UncheckedAccount<'info>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = marketplace_fee_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub marketplace_fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: receives the escrow rent, checked against the escrow.
+    #[account(mut, constraint = rent_payer.key() == escrow.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// CHECK: optional receipt of the settlement, created by the instruction.
+    #[account(
+        mut,
+        seeds = [SEED_ESCROW_RECEIPT, escrow.create_key.as_ref(), escrow.identifier.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> SettleAuction<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multi_wallet,
+            escrow,
+            recipient,
+            ..
+        } = self;
+
+        multi_wallet.require_pending_offer(&escrow.key())?;
+        let auction = escrow
+            .auction
+            .filter(|x| x.is_english())
+            .ok_or(MultisigError::InvalidAuction)?;
+        require!(auction.has_ended()?, MultisigError::AuctionNotEnded);
+        require!(
+            escrow.recipient.pubkey.is_some()
+                && recipient.key() == escrow.recipient.pubkey.unwrap(),
+            MultisigError::InvalidEscrowRecipient
+        );
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let seller_members = multi_wallet.members.clone();
+
+        // Without a winning bid the listing simply lapses.
+        let mut fees = EscrowFees::default();
+        if escrow.proposer.is_some() {
+            multi_wallet.set_members(
+                escrow.new_members.as_ref().unwrap().clone(),
+                escrow.proposer.unwrap(),
+                Clock::get()?.unix_timestamp,
+            );
+            multi_wallet.set_threshold(escrow.threshold.unwrap());
+
+            fees = escrow.escrow_transfer_with_fees(
+                FeeAccounts {
+                    protocol_config: &ctx.accounts.protocol_config,
+                    protocol_fee_recipient: &ctx.accounts.protocol_fee_recipient,
+                    protocol_fee_token_account: &ctx.accounts.protocol_fee_token_account,
+                    marketplace_fee_recipient: &ctx.accounts.marketplace_fee_recipient,
+                    marketplace_fee_token_account: &ctx.accounts.marketplace_fee_token_account,
+                },
+                &ctx.accounts.mint,
+                &ctx.accounts.escrow_token_vault,
+                &ctx.accounts.recipient_token_account,
+                &Some(ctx.accounts.escrow_vault.to_account_info()),
+                &Some(ctx.accounts.recipient.to_account_info()),
+                &Some(ctx.accounts.escrow_vault.to_account_info()),
+                &ctx.accounts.recipient.to_account_info(),
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+
+            MultiWallet::check_state_validity(&multi_wallet.threshold, &multi_wallet.members)?;
+        }
+        multi_wallet.settle_offer(escrow.key());
+
+        MultiWallet::realloc_if_needed(
+            multi_wallet.to_account_info(),
+            multi_wallet.members.len(),
+            multi_wallet.pending_offers.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        let (buyer_members, outcome) = match escrow.proposer {
+            Some(_) => (Some(&multi_wallet.members), EscrowOutcome::Executed),
+            None => (None, EscrowOutcome::Expired),
+        };
+        EscrowReceipt::record(
+            &ctx.accounts.receipt,
+            Some(&ctx.accounts.payer.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            escrow,
+            Some(&seller_members),
+            buyer_members,
+            outcome,
+        )?;
+
+        emit_cpi!(EscrowEvent {
+            create_key: multi_wallet.create_key,
+            identifier: escrow.identifier,
+            is_pending: false,
+            is_rejected: escrow.proposer.is_none(),
+            recipient: escrow.recipient,
+            approver: None,
+            proposer: escrow.proposer,
+            new_members: escrow.new_members.clone(),
+            additional_legs: escrow.additional_legs.clone(),
+            seat: escrow.seat,
+            threshold: escrow.threshold,
+            fees
+        });
+
+        Ok(())
+    }
+}
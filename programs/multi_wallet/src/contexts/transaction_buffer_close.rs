@@ -1,13 +1,15 @@
 use crate::{
-    state::{MultiWallet, SEED_MULTISIG},
-    MultisigError, TransactionBuffer, SEED_TRANSACTION_BUFFER,
+    state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG},
+    MultisigError, TransactionBuffer, SEED_TRANSACTION_BUFFER, TRANSACTION_BUFFER_VERSION,
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct TransactionBufferClose<'info> {
     #[account(
+        mut,
         address = transaction_buffer.multi_wallet,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Account<'info, MultiWallet>,
 
@@ -22,7 +24,8 @@ pub struct TransactionBufferClose<'info> {
             creator.key().as_ref(),
             &transaction_buffer.buffer_index.to_le_bytes()
         ],
-        bump = transaction_buffer.bump
+        bump = transaction_buffer.bump,
+        constraint = transaction_buffer.version == TRANSACTION_BUFFER_VERSION @MultisigError::TransactionBufferNotMigrated,
     )]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
 
@@ -44,6 +47,7 @@ impl TransactionBufferClose<'_> {
     /// Close a transaction buffer account.
     #[access_control(ctx.accounts.validate())]
     pub fn process(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.multi_wallet.close_buffer();
         Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    state::{MultiWallet, SEED_MULTISIG},
-    MultisigError, Permission, TransactionBuffer, MAX_BUFFER_SIZE, SEED_TRANSACTION_BUFFER,
+    state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG},
+    BalanceAssertion, MultisigError, Permission, TransactionBuffer, MAX_BUFFER_SIZE, MAX_EPHEMERAL_SIGNERS, SEED_TRANSACTION_BUFFER, TRANSACTION_BUFFER_VERSION,
 };
 use anchor_lang::prelude::*;
 
@@ -10,10 +10,15 @@ pub struct TransactionBufferCreateArgs {
     pub buffer_index: u8,
     /// Index of the vault this transaction belongs to.
     pub vault_index: u8,
-    /// Hash of the final assembled transaction message.
+    /// Number of ephemeral signer PDAs, derived from the buffer key, the transaction nonce and an index,
+    /// the program signs with when executing the transaction. At most `MAX_EPHEMERAL_SIGNERS`.
+    pub num_ephemeral_signers: u8,
+    /// Hash of the final assembled transaction message, followed by the Borsh-encoded `assertions` if there are any.
     pub final_buffer_hash: [u8; 32],
     /// Final size of the buffer.
     pub final_buffer_size: u16,
+    /// Balance assertions checked after the transaction is executed, at most `MAX_BALANCE_ASSERTIONS`.
+    pub assertions: Vec<BalanceAssertion>,
     /// Initial slice of the buffer.
     pub buffer: Vec<u8>,
 }
@@ -22,15 +27,17 @@ pub struct TransactionBufferCreateArgs {
 #[instruction(args: TransactionBufferCreateArgs)]
 pub struct TransactionBufferCreate<'info> {
     #[account(
+        mut,
         seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
-        bump = multi_wallet.bump
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Account<'info, MultiWallet>,
 
     #[account(
         init,
         payer = rent_payer,
-        space = TransactionBuffer::size(args.final_buffer_size)?,
+        space = TransactionBuffer::size(args.final_buffer_size, args.assertions.len())?,
         seeds = [
             SEED_MULTISIG,
             multi_wallet.key().as_ref(),
@@ -60,21 +67,26 @@ impl TransactionBufferCreate<'_> {
 
         require!(
             multi_wallet
-                .members
-                .iter()
-                .filter(|x| x.pubkey.eq(creator.key)
-                    && x.permissions.is_some()
-                    && x.permissions.unwrap().has(Permission::InitiateTransaction))
-                .count()
-                == 1,
+                .find_member(creator.key)
+                .is_some_and(|x| x.permissions.is_some_and(|x| x.has(Permission::InitiateTransaction))),
             MultisigError::InsufficientSignerWithInitiatePermission
         );
 
+        require!(
+            multi_wallet.listing_state().allows_vault_transaction(),
+            MultisigError::MultisigIsCurrentlyLocked
+        );
+
         require!(
             args.final_buffer_size as usize <= MAX_BUFFER_SIZE,
             MultisigError::FinalBufferSizeExceeded
         );
 
+        require!(
+            args.num_ephemeral_signers <= MAX_EPHEMERAL_SIGNERS,
+            MultisigError::TooManyEphemeralSigners
+        );
+
         Ok(())
     }
 
@@ -82,9 +94,9 @@ impl TransactionBufferCreate<'_> {
     pub fn process(ctx: Context<Self>, args: TransactionBufferCreateArgs) -> Result<()> {
         // Mutable Accounts
         let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
 
         // Readonly Accounts
-        let multi_wallet = &ctx.accounts.multi_wallet;
         let creator = &mut ctx.accounts.creator;
         let rent_payer = &ctx.accounts.rent_payer;
 
@@ -96,15 +108,21 @@ impl TransactionBufferCreate<'_> {
         transaction_buffer.creator = creator.key();
         transaction_buffer.rent_payer = rent_payer.key();
         transaction_buffer.vault_index = args.vault_index;
+        transaction_buffer.num_ephemeral_signers = args.num_ephemeral_signers;
         transaction_buffer.buffer_index = buffer_index;
         transaction_buffer.final_buffer_hash = args.final_buffer_hash;
         transaction_buffer.final_buffer_size = args.final_buffer_size;
+        transaction_buffer.assertions = args.assertions;
         transaction_buffer.buffer = args.buffer;
         transaction_buffer.bump = ctx.bumps.transaction_buffer;
+        transaction_buffer.version = TRANSACTION_BUFFER_VERSION;
 
         // Invariant function on the transaction buffer
         transaction_buffer.invariant()?;
 
+        transaction_buffer.transaction_nonce = multi_wallet.next_transaction_nonce()?;
+        multi_wallet.open_buffer()?;
+
         Ok(())
     }
 }
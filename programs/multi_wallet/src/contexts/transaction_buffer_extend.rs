@@ -1,4 +1,4 @@
-use crate::{state::SEED_MULTISIG, MultisigError, TransactionBuffer, SEED_TRANSACTION_BUFFER};
+use crate::{state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, MultisigError, TransactionBuffer, SEED_TRANSACTION_BUFFER, TRANSACTION_BUFFER_VERSION};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -10,6 +10,13 @@ pub struct TransactionBufferExtendArgs {
 #[derive(Accounts)]
 #[instruction(args: TransactionBufferExtendArgs)]
 pub struct TransactionBufferExtend<'info> {
+    #[account(
+        address = transaction_buffer.multi_wallet,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Account<'info, MultiWallet>,
     #[account(
         mut,
         constraint = transaction_buffer.creator == creator.key() @ MultisigError::UnauthorisedToModifyBuffer,
@@ -21,6 +28,7 @@ pub struct TransactionBufferExtend<'info> {
             &transaction_buffer.buffer_index.to_le_bytes()
         ],
         bump = transaction_buffer.bump,
+        constraint = transaction_buffer.version == TRANSACTION_BUFFER_VERSION @MultisigError::TransactionBufferNotMigrated,
     )]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
 
@@ -30,9 +38,16 @@ pub struct TransactionBufferExtend<'info> {
 impl TransactionBufferExtend<'_> {
     fn validate(&self, args: &TransactionBufferExtendArgs) -> Result<()> {
         let Self {
-            transaction_buffer, ..
+            multi_wallet,
+            transaction_buffer,
+            ..
         } = self;
 
+        require!(
+            multi_wallet.listing_state().allows_vault_transaction(),
+            MultisigError::MultisigIsCurrentlyLocked
+        );
+
         // Extended Buffer size must not exceed final buffer size
         // Calculate remaining space in the buffer
         let current_buffer_size = transaction_buffer.buffer.len() as u16;
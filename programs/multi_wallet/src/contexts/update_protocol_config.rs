@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::{state::{ProtocolConfig, SEED_PROTOCOL_CONFIG}, MultisigError};
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PROTOCOL_CONFIG],
+        bump = protocol_config.bump,
+        constraint = protocol_config.authority == authority.key() @ MultisigError::UnauthorisedToUpdateProtocolConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub authority: Signer<'info>,
+}
+
+impl UpdateProtocolConfig<'_> {
+    fn validate(&self, fee_bps: &u16) -> Result<()> {
+        ProtocolConfig::validate_fee_bps(*fee_bps)?;
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&fee_bps))]
+    pub fn process(ctx: Context<Self>, authority: Pubkey, fee_recipient: Pubkey, fee_bps: u16) -> Result<()> {
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.authority = authority;
+        protocol_config.fee_recipient = fee_recipient;
+        protocol_config.fee_bps = fee_bps;
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::{state::{MultiWallet, SEED_MULTISIG}, ExecutableTransactionMessage, MultisigError, Permission, TransactionBuffer, TransactionMessage, VaultTransactionMessage, SEED_TRANSACTION_BUFFER, SEED_VAULT};
-use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
+use crate::{state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, derive_ephemeral_signers, load_vault_transaction, validate_vault_transaction_signers, MultisigError, VaultSigner, VaultTransactionEvent, TransactionBuffer, TransactionMessage, VaultTransactionMessage, SEED_TRANSACTION_BUFFER, TRANSACTION_BUFFER_VERSION};
+use anchor_lang::solana_program::{program::set_return_data, sysvar::instructions as tx_instructions};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct VaultTransactionExecute<'info> {
     #[account(
@@ -15,11 +16,13 @@ pub struct VaultTransactionExecute<'info> {
             &transaction_buffer.buffer_index.to_le_bytes(),
         ],
         bump = transaction_buffer.bump,
+        constraint = transaction_buffer.version == TRANSACTION_BUFFER_VERSION @MultisigError::TransactionBufferNotMigrated,
     )]
     pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
     #[account(
         mut, 
         address = transaction_buffer.multi_wallet,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
     )]
     pub multi_wallet: Box<Account<'info, MultiWallet>>,
     /// CHECK:
@@ -47,86 +50,66 @@ impl<'info> VaultTransactionExecute<'info> {
         
         MultiWallet::durable_nonce_check(instruction_sysvar)?;
         let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
-        let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
-
-        require!(
-            multi_wallet.threshold
-                <= unique_signers
-                    .iter()
-                    .filter(|x| x.permissions.is_some()
-                        && x.permissions.unwrap().has(Permission::VoteTransaction))
-                    .count()
-                    .try_into()
-                    .unwrap(),
-            MultisigError::NotEnoughSigners
-        );
-
-        require!(
-            unique_signers
-                .iter()
-                .filter(|x| x.permissions.is_some()
-                    && x.permissions.unwrap().has(Permission::ExecuteTransaction))
-                .count()
-                >= 1,
-            MultisigError::InsufficientSignerWithExecutePermission
-        );
-
-              
-        require!(
-            multi_wallet.pending_offers.len() == 0,
-            MultisigError::MultisigIsCurrentlyLocked
-        );
+        validate_vault_transaction_signers(multi_wallet, account_infos)?;
 
         Ok(())
     }
 
     #[access_control(ctx.accounts.validate(&ctx))]
-    pub fn process(ctx: Context<'_, '_, '_, 'info, Self>, vault_index: u16) -> Result<()> {       
+    pub fn process(
+        ctx: Context<'_, '_, '_, 'info, Self>,
+        vault_index: u16,
+        forward_return_data: bool,
+    ) -> Result<()> {       
         let multi_wallet = &mut ctx.accounts.multi_wallet;
+        multi_wallet.close_buffer();
         let transaction_message = TransactionMessage::deserialize(&mut ctx.accounts.transaction_buffer.buffer.as_slice())?;
         let vault_transaction_message = VaultTransactionMessage::try_from(transaction_message)?;
 
-        let num_lookups = vault_transaction_message.address_table_lookups.len();
-        let message_end_index = num_lookups + vault_transaction_message.num_all_account_keys();
-
-        let message_account_infos = ctx
-            .remaining_accounts
-            .get(num_lookups..message_end_index)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-
-        let address_lookup_table_account_infos = ctx
-            .remaining_accounts
-            .get(..num_lookups)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-
-        let multi_wallet_key = multi_wallet.key();
-        let vault_index_ref = vault_index.to_le_bytes();
-        let vault_seed_slices: &[&[u8]] = &[
-            SEED_MULTISIG,
-            multi_wallet_key.as_ref(),
-            SEED_VAULT,
-            vault_index_ref.as_ref(),
-        ];
-        let (vault_pubkey, vault_bump) =
-            Pubkey::find_program_address(vault_seed_slices, ctx.program_id);
-        let executable_message = ExecutableTransactionMessage::new_validated(
+        let vault_signer = VaultSigner::derive(multi_wallet.key(), vault_index, ctx.program_id);
+        let (ephemeral_signer_keys, ephemeral_signer_seeds) = derive_ephemeral_signers(
+            ctx.accounts.transaction_buffer.key(),
+            ctx.accounts.transaction_buffer.transaction_nonce,
+            ctx.accounts.transaction_buffer.num_ephemeral_signers,
+        );
+        let executable_message = load_vault_transaction(
             vault_transaction_message,
-            message_account_infos,
-            address_lookup_table_account_infos,
-            &vault_pubkey,
+            ctx.remaining_accounts,
+            &vault_signer.pubkey,
+            &ephemeral_signer_keys,
         )?;
 
-        let vault_signer_seed_slices: &[&[u8]] = &[
-            SEED_MULTISIG,
-            multi_wallet_key.as_ref(),
-            SEED_VAULT,
-            vault_index_ref.as_ref(),
-            &[vault_bump],
-        ];
-        executable_message.execute_message(vault_signer_seed_slices)?;
+        let assertions = &ctx.accounts.transaction_buffer.assertions;
+        let balances_before = assertions
+            .iter()
+            .map(|x| x.read_balance(ctx.remaining_accounts))
+            .collect::<Result<Vec<u64>>>()?;
+
+        let return_data = executable_message.execute_message(&vault_signer.signer_seeds(), &ephemeral_signer_seeds)?;
+
+        for (assertion, balance_before) in assertions.iter().zip(balances_before) {
+            assertion.check(balance_before, assertion.read_balance(ctx.remaining_accounts)?)?;
+        }
 
         multi_wallet.reload()?;
 
+        let forwarded_return_data = return_data
+            .last()
+            .cloned()
+            .flatten()
+            .filter(|_| forward_return_data);
+
+        emit_cpi!(VaultTransactionEvent {
+            create_key: multi_wallet.create_key,
+            vault_index,
+            return_data,
+        });
+
+        // Must come after the event CPI, which resets the return data.
+        if let Some(forwarded_return_data) = forwarded_return_data {
+            set_return_data(&forwarded_return_data.data);
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::{state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, load_vault_transaction, validate_vault_transaction_signers, MultisigError, VaultSigner, VaultTransactionEvent, TransactionMessage, VaultTransactionMessage};
+use anchor_lang::solana_program::{program::set_return_data, sysvar::instructions as tx_instructions};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VaultTransactionExecuteInline<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_MULTISIG, multi_wallet.create_key.as_ref()],
+        bump = multi_wallet.bump,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+    /// CHECK: instructions sysvar
+    #[account(address = tx_instructions::ID)]
+    pub instruction_sysvar: UncheckedAccount<'info>
+}
+
+impl<'info> VaultTransactionExecuteInline<'info> {
+    fn validate(&self, ctx: &Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let Self {
+            multi_wallet,
+            instruction_sysvar,
+            ..
+        } = self;
+
+        MultiWallet::durable_nonce_check(instruction_sysvar)?;
+        let account_infos = &[ctx.remaining_accounts, &ctx.accounts.to_account_infos()].concat();
+        validate_vault_transaction_signers(multi_wallet, account_infos)?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx))]
+    pub fn process(
+        ctx: Context<'_, '_, '_, 'info, Self>,
+        vault_index: u16,
+        transaction_message: TransactionMessage,
+        forward_return_data: bool,
+    ) -> Result<()> {
+        let multi_wallet = &mut ctx.accounts.multi_wallet;
+        let vault_transaction_message = VaultTransactionMessage::try_from(transaction_message)?;
+
+        let vault_signer = VaultSigner::derive(multi_wallet.key(), vault_index, ctx.program_id);
+        let executable_message = load_vault_transaction(
+            vault_transaction_message,
+            ctx.remaining_accounts,
+            &vault_signer.pubkey,
+            &[],
+        )?;
+
+        let return_data = executable_message.execute_message(&vault_signer.signer_seeds(), &[])?;
+
+        multi_wallet.reload()?;
+
+        let forwarded_return_data = return_data
+            .last()
+            .cloned()
+            .flatten()
+            .filter(|_| forward_return_data);
+
+        emit_cpi!(VaultTransactionEvent {
+            create_key: multi_wallet.create_key,
+            vault_index,
+            return_data,
+        });
+
+        // Must come after the event CPI, which resets the return data.
+        if let Some(forwarded_return_data) = forwarded_return_data {
+            set_return_data(&forwarded_return_data.data);
+        }
+
+        Ok(())
+    }
+}
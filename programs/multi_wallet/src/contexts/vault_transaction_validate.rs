@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::{state::{MultiWallet, MULTI_WALLET_VERSION, SEED_MULTISIG}, derive_ephemeral_signers, load_vault_transaction, MultisigError, TransactionBuffer, TransactionMessage, VaultSigner, VaultTransactionMessage, VaultTransactionSummary, SEED_TRANSACTION_BUFFER, TRANSACTION_BUFFER_VERSION};
+
+#[derive(Accounts)]
+pub struct VaultTransactionValidate<'info> {
+    #[account(
+        seeds = [
+            SEED_MULTISIG,
+            transaction_buffer.multi_wallet.as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            transaction_buffer.creator.as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump = transaction_buffer.bump,
+        constraint = transaction_buffer.version == TRANSACTION_BUFFER_VERSION @MultisigError::TransactionBufferNotMigrated,
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+    #[account(
+        address = transaction_buffer.multi_wallet,
+        constraint = multi_wallet.version == MULTI_WALLET_VERSION @MultisigError::MultiWalletNotMigrated,
+    )]
+    pub multi_wallet: Box<Account<'info, MultiWallet>>,
+}
+
+impl<'info> VaultTransactionValidate<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            transaction_buffer,
+            ..
+        } = self;
+        transaction_buffer.validate_hash()?;
+        transaction_buffer.validate_size()?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(
+        ctx: Context<'_, '_, '_, 'info, Self>,
+        vault_index: u16,
+    ) -> Result<VaultTransactionSummary> {
+        let multi_wallet = &ctx.accounts.multi_wallet;
+        let transaction_message = TransactionMessage::deserialize(&mut ctx.accounts.transaction_buffer.buffer.as_slice())?;
+        let vault_transaction_message = VaultTransactionMessage::try_from(transaction_message)?;
+
+        let vault_signer = VaultSigner::derive(multi_wallet.key(), vault_index, ctx.program_id);
+        let (ephemeral_signer_keys, _) = derive_ephemeral_signers(
+            ctx.accounts.transaction_buffer.key(),
+            ctx.accounts.transaction_buffer.transaction_nonce,
+            ctx.accounts.transaction_buffer.num_ephemeral_signers,
+        );
+        let executable_message = load_vault_transaction(
+            vault_transaction_message,
+            ctx.remaining_accounts,
+            &vault_signer.pubkey,
+            &ephemeral_signer_keys,
+        )?;
+
+        Ok(executable_message.summary(vault_signer.pubkey))
+    }
+}
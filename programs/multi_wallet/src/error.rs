@@ -80,4 +80,142 @@ pub enum MultisigError {
 
     #[msg("Final buffer size mismatch")]
     FinalBufferSizeMismatch,
+
+    #[msg("The transaction buffer declares too many ephemeral signers.")]
+    TooManyEphemeralSigners,
+
+    #[msg("The transaction buffer already uses the current account layout.")]
+    TransactionBufferAlreadyMigrated,
+
+    #[msg("The transaction buffer uses an old account layout and must be migrated first.")]
+    TransactionBufferNotMigrated,
+
+    #[msg("A balance assertion on the transaction buffer was violated after execution.")]
+    BalanceAssertionFailed,
+
+    #[msg("The transaction buffer has too many balance assertions.")]
+    TooManyBalanceAssertions,
+
+    #[msg("The escrow expiry must be in the future.")]
+    InvalidEscrowExpiry,
+
+    #[msg("The escrow has expired.")]
+    EscrowExpired,
+
+    #[msg("The escrow has not expired yet.")]
+    EscrowNotExpired,
+
+    #[msg("The escrow already uses the current account layout.")]
+    EscrowAlreadyMigrated,
+
+    #[msg("The escrow uses an old account layout and must be migrated first.")]
+    EscrowNotMigrated,
+
+    #[msg("Too many pending offers on the multi-wallet. Wait for existing offers to be settled or cancelled.")]
+    TooManyPendingOffers,
+
+    #[msg("Invalid auction parameters or the escrow is not an auction of the expected kind.")]
+    InvalidAuction,
+
+    #[msg("The auction has already ended.")]
+    AuctionEnded,
+
+    #[msg("The auction has not ended yet.")]
+    AuctionNotEnded,
+
+    #[msg("The bid must meet the reserve price and exceed the highest bid by the minimum increment.")]
+    AuctionBidTooLow,
+
+    #[msg("Fees cannot exceed 10,000 basis points in total.")]
+    InvalidFeeBasisPoints,
+
+    #[msg("The fee recipient must match the account stated in the protocol config or the escrow.")]
+    InvalidFeeRecipient,
+
+    #[msg("Only the protocol config authority can update the protocol config.")]
+    UnauthorisedToUpdateProtocolConfig,
+
+    #[msg("The owners did not approve the marketplace fee of the escrow.")]
+    MarketplaceFeeNotApproved,
+
+    #[msg("The escrow has no pending counter-offer.")]
+    MissingCounterOffer,
+
+    #[msg("The escrow asset manifest cannot have more than 16 entries.")]
+    AssetManifestTooLong,
+
+    #[msg("The multi-wallet vaults no longer hold the assets listed in the escrow asset manifest.")]
+    AssetManifestMismatch,
+
+    #[msg("The multi-wallet cannot be listed while offers are pending. Cancel them first.")]
+    PendingOffersExist,
+
+    #[msg("Escrows do not support mints with a transfer hook.")]
+    UnsupportedMintTransferHook,
+
+    #[msg("Escrows do not support non-transferable mints.")]
+    UnsupportedMintNonTransferable,
+
+    #[msg("Escrows do not support mints with confidential transfers.")]
+    UnsupportedMintConfidentialTransfer,
+
+    #[msg("Failed to calculate the transfer fee of the mint.")]
+    TransferFeeCalculationFailed,
+
+    #[msg("Escrows do not support mints with a permanent delegate.")]
+    UnsupportedMintPermanentDelegate,
+
+    #[msg("Escrows do not support mints with a close authority.")]
+    UnsupportedMintCloseAuthority,
+
+    #[msg("Escrows do not support mints whose token accounts are frozen by default.")]
+    UnsupportedMintDefaultFrozen,
+
+    #[msg("Escrow payment legs must be non-zero, pay in distinct assets and not exceed the maximum count.")]
+    InvalidPaymentLegs,
+
+    #[msg("The seat must belong to a current member and be taken by a non-member.")]
+    InvalidSeat,
+
+    #[msg("The escrow has no room left to record another vote.")]
+    TooManyEscrowVotes,
+
+    #[msg("Too many allowed buyers on the escrow listing.")]
+    TooManyAllowedBuyers,
+
+    #[msg("The buyer is not allowed to execute this escrow listing.")]
+    BuyerNotAllowed,
+
+    #[msg("No ed25519 instruction verifies the buyer's signature of the offer.")]
+    InvalidOfferSignature,
+
+    #[msg("The signed offer has already been accepted.")]
+    SignedOfferAlreadyAccepted,
+
+    #[msg("An escrow with this identifier already exists.")]
+    EscrowIdentifierInUse,
+
+    #[msg("The escrow identifier is outside of the allowed range.")]
+    InvalidEscrowIdentifier,
+
+    #[msg("The multi-wallet has too many open transaction buffers.")]
+    TooManyOpenBuffers,
+
+    #[msg("The multi-wallet still has open transaction buffers.")]
+    OpenBuffersExist,
+
+    #[msg("A vault holds account data and cannot be swept.")]
+    VaultHoldsData,
+
+    #[msg("The multi-wallet already uses the current account layout.")]
+    MultiWalletAlreadyMigrated,
+
+    #[msg("The multi-wallet uses an old account layout and must be migrated first.")]
+    MultiWalletNotMigrated,
+
+    #[msg("The wallet metadata exceeds its size limits.")]
+    WalletMetadataTooLarge,
+
+    #[msg("A member label is longer than allowed.")]
+    MemberLabelTooLong,
 }
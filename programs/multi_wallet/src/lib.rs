@@ -49,6 +49,83 @@ pub mod multi_wallet {
         ChangeConfig::process(ctx, config_actions)
     }
 
+    /// Upgrades an escrow created before escrows were versioned to the current layout. Escrows in the old
+    /// layout are rejected by every other escrow instruction until they are migrated. Anyone can migrate an
+    /// escrow once its multi-wallet is migrated. Owner listings refund their rent to the default vault.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the multi-wallet, the escrow and the payer of any extra rent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the escrow is upgraded in place, or grown towards its upgraded size.
+    /// - `Err`: If the account is not an escrow of the multi-wallet or is already up to date.
+    ///
+    pub fn migrate_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateEscrow<'info>>,
+    ) -> Result<()> {
+        MigrateEscrow::process(ctx)
+    }
+
+    /// Upgrades a transaction buffer created before buffers were versioned to the current layout, giving
+    /// it a transaction nonce and counting it as an open buffer of the multi-wallet. Buffers in the old
+    /// layout are rejected by every other buffer instruction until they are migrated. Buffers too large
+    /// for the current layout are closed and their rent refunded.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the multi-wallet, the buffer, its rent payer and the payer of any extra rent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the buffer is upgraded in place or closed.
+    /// - `Err`: If the account is not a buffer of the multi-wallet or is already up to date.
+    ///
+    pub fn migrate_transaction_buffer<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateTransactionBuffer<'info>>,
+    ) -> Result<()> {
+        MigrateTransactionBuffer::process(ctx)
+    }
+
+    /// Closes a multi-wallet with no pending offers or open transaction buffers, returning its rent
+    /// and the rent of its wallet metadata account to a signing member and emitting a final `ConfigEvent`
+    /// without members. The multi-wallet can be re-created by anyone with the same create key, so every
+    /// vault holding assets should be swept.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the multi-wallet, its wallet metadata PDA and the signing members.
+    ///   The vaults to sweep are passed as the first remaining accounts, followed by
+    ///   `[token account, mint, destination token account, token program]` for each token account to sweep.
+    /// - `vault_indices`: Indices of the vaults whose lamports are swept to the sweep recipient.
+    /// - `token_vault_indices`: Vault index owning each token account to sweep. The token accounts are
+    ///   emptied into their destination and closed to the sweep recipient.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the multi-wallet is closed.
+    /// - `Err`: If the threshold is not met, offers or buffers are still open, or a vault or token account is invalid.
+    ///
+    pub fn close_multi_wallet<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseMultiWallet<'info>>,
+        vault_indices: Vec<u16>,
+        token_vault_indices: Vec<u16>,
+    ) -> Result<()> {
+        CloseMultiWallet::process(ctx, vault_indices, token_vault_indices)
+    }
+
+    /// Upgrades a multi-wallet created with an older account layout to the current version.
+    /// Anyone can migrate a multi-wallet; its configuration is left unchanged. Accounts growing by more than
+    /// 10240 bytes are grown over several calls, and the upgrade is written by the last one.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the multi-wallet and the payer of any extra rent.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the multi-wallet is upgraded in place, or grown towards its upgraded size.
+    /// - `Err`: If the account is not a multi-wallet or is already up to date.
+    ///
+    pub fn migrate_multi_wallet<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateMultiWallet<'info>>,
+    ) -> Result<()> {
+        MigrateMultiWallet::process(ctx)
+    }
+
     /// Creates a new transaction buffer.
     ///
     /// # Parameters
@@ -73,7 +150,7 @@ pub mod multi_wallet {
     ///
     /// # Returns
     /// - `Ok(())`: If the transaction buffer is successfully extended.
-    /// - `Err`: If validation fails or the provided arguments are invalid.
+    /// - `Err`: If validation fails, the multi-wallet is listed or the provided arguments are invalid.
     pub fn transaction_buffer_extend<'info>(
         ctx: Context<'_, '_, '_, 'info, TransactionBufferExtend<'info>>,
         args: TransactionBufferExtendArgs,
@@ -100,25 +177,65 @@ pub mod multi_wallet {
     /// # Parameters
     /// - `ctx`: The context of the vault transaction execution.
     /// - `vault_index`: The index of the vault.
-    /// - `transaction_message`: The transaction message to be executed.
+    /// - `forward_return_data`: Whether to forward the return data of the last executed instruction.
     ///
     /// # Returns
     /// - `Result<()>`: The result of the vault transaction execution.
     pub fn vault_transaction_execute<'info>(
         ctx: Context<'_, '_, '_, 'info, VaultTransactionExecute<'info>>,
         vault_index: u16,
+        forward_return_data: bool,
     ) -> Result<()> {
-        VaultTransactionExecute::process(ctx, vault_index)
+        VaultTransactionExecute::process(ctx, vault_index, forward_return_data)
+    }
+
+    /// Executes a vault transaction whose message is passed directly as instruction data,
+    /// skipping the transaction buffer for messages small enough to fit in a single transaction.
+    /// Balance assertions are stored on transaction buffers, so inline execution does not check any;
+    /// use a transaction buffer when the vault balances must be asserted after execution.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context of the vault transaction execution.
+    /// - `vault_index`: The index of the vault.
+    /// - `transaction_message`: The transaction message to be executed.
+    /// - `forward_return_data`: Whether to forward the return data of the last executed instruction.
+    ///
+    /// # Returns
+    /// - `Result<()>`: The result of the vault transaction execution.
+    pub fn vault_transaction_execute_inline<'info>(
+        ctx: Context<'_, '_, '_, 'info, VaultTransactionExecuteInline<'info>>,
+        vault_index: u16,
+        transaction_message: TransactionMessage,
+        forward_return_data: bool,
+    ) -> Result<()> {
+        VaultTransactionExecuteInline::process(ctx, vault_index, transaction_message, forward_return_data)
+    }
+
+    /// Validates a buffered vault transaction against the supplied accounts without executing it.
+    /// No member signatures are required, so backends can check a buffer before collecting them.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context of the vault transaction validation.
+    /// - `vault_index`: The index of the vault.
+    ///
+    /// # Returns
+    /// - `Ok(VaultTransactionSummary)`: The accounts, programs and writable set of the transaction, set as return data.
+    /// - `Err`: If the buffer does not decode or the accounts do not match the message.
+    pub fn vault_transaction_validate<'info>(
+        ctx: Context<'_, '_, '_, 'info, VaultTransactionValidate<'info>>,
+        vault_index: u16,
+    ) -> Result<VaultTransactionSummary> {
+        VaultTransactionValidate::process(ctx, vault_index)
     }
 
     /// Initializes an escrow. This function locks funds into an escrow vault
-    /// and sets up the necessary metadata for the escrow.
+    /// and sets up the necessary metadata for the escrow. The escrow identifier is
+    /// allocated from the escrow counter of the multi-wallet.
     ///
     /// # Parameters
     /// - `ctx`: The context containing all relevant accounts for initializing the escrow.
-    /// - `identifier`: A unique identifier for the escrow, used to distinguish it from others.
-    /// - `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.
-    /// - `amount`: The amount to be transferred to the escrow.
+    ///   The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.
+    /// - `args`: Arguments for the escrow initialization.
     ///
     /// # Returns
     /// - `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.
@@ -126,23 +243,41 @@ pub mod multi_wallet {
     ///
     pub fn initiate_escrow_as_non_owner<'info>(
         ctx: Context<'_, '_, '_, 'info, InitializeEscrowAsNonOwner<'info>>,
+        args: InitializeEscrowAsNonOwnerArgs,
+    ) -> Result<()> {
+        InitializeEscrowAsNonOwner::process(ctx, args)
+    }
+
+    /// Initializes an escrow under a client-chosen identifier, as `initiate_escrow_as_non_owner` does
+    /// with an identifier allocated on-chain. Client-chosen identifiers start at `CLIENT_ESCROW_IDENTIFIER_START`.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts for initializing the escrow.
+    ///   The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.
+    /// - `identifier`: The identifier of the escrow.
+    /// - `args`: Arguments for the escrow initialization.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.
+    /// - `Err`: If the identifier is out of range or already in use, or any validation or transfer fails.
+    ///
+    pub fn initiate_escrow_as_non_owner_with_identifier<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeEscrowAsNonOwnerWithIdentifier<'info>>,
         identifier: u64,
-        new_members: Vec<Member>,
-        amount: u64,
-        threshold: u8,
+        args: InitializeEscrowAsNonOwnerArgs,
     ) -> Result<()> {
-        InitializeEscrowAsNonOwner::process(ctx, identifier, new_members, amount, threshold)
+        InitializeEscrowAsNonOwnerWithIdentifier::process(ctx, identifier, args)
     }
 
     /// Initializes an escrow as an owner. This function locks the multi-wallet
     /// and prepares the escrow account with the specified metadata and recipient details.
+    /// The escrow identifier is allocated from the escrow counter of the multi-wallet.
+    /// Votes are recorded on an existing escrow, so the threshold of `VoteEscrow` members must sign
+    /// this instruction itself.
     ///
     /// # Parameters
     /// - `ctx`: The context containing all relevant accounts required for initializing the escrow.
-    /// - `identifier`: A unique identifier for the escrow, used to distinguish it from others.
-    /// - `recipient`: The recipient's account,
-    /// - `amount`: The amount to be transferred.
-    /// - `mint`: Token mint that needs to be transferred(if any)
+    /// - `args`: Arguments for the escrow initialization, including the buyers allowed to execute it.
     ///
     /// # Returns
     /// - `Ok(())`: If the escrow is successfully initialized and the multi-wallet is locked.
@@ -150,12 +285,9 @@ pub mod multi_wallet {
     ///
     pub fn initiate_escrow_as_owner<'info>(
         ctx: Context<'_, '_, '_, 'info, InitializeEscrowAsOwner<'info>>,
-        identifier: u64,
-        recipient: Pubkey,
-        amount: u64,
-        mint: Option<Pubkey>,
+        args: InitializeEscrowAsOwnerArgs,
     ) -> Result<()> {
-        InitializeEscrowAsOwner::process(ctx, identifier, recipient, amount, mint)
+        InitializeEscrowAsOwner::process(ctx, args)
     }
 
     /// Executes an escrow. This function transfers funds from the escrow vault
@@ -184,6 +316,8 @@ pub mod multi_wallet {
     ///
     /// # Parameters
     /// - `ctx`: The context containing all relevant accounts required for executing the escrow.
+    ///   The escrow rent payer receives the escrow rent and the rent of its emptied vault token accounts.
+    /// - `marketplace_fee`: The marketplace fee the owners agree to pay out of the proceeds. Must match the escrow.
     ///
     /// # Returns
     /// - `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.
@@ -191,8 +325,9 @@ pub mod multi_wallet {
     ///
     pub fn execute_escrow_as_owner<'info>(
         ctx: Context<'_, '_, '_, 'info, ExecuteEscrowAsOwner<'info>>,
+        marketplace_fee: Option<MarketplaceFee>,
     ) -> Result<()> {
-        ExecuteEscrowAsOwner::process(ctx)
+        ExecuteEscrowAsOwner::process(ctx, marketplace_fee)
     }
 
     /// Cancels an escrow as a proposer. This function returns the locked funds in the escrow
@@ -226,4 +361,234 @@ pub mod multi_wallet {
     ) -> Result<()> {
         CancelEscrowAsOwner::process(ctx)
     }
+
+    /// Cancels an expired escrow. Anyone can crank this once the escrow's expiry has passed.
+    /// Locked funds are refunded to the proposer, the escrow rent to the account that paid it, and the offer
+    /// is removed from the multi-wallet.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for canceling the escrow.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.
+    /// - `Err`: If the escrow has not expired or the accounts provided are invalid.
+    ///
+    pub fn cancel_expired_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelExpiredEscrow<'info>>,
+    ) -> Result<()> {
+        CancelExpiredEscrow::process(ctx)
+    }
+
+    /// Accepts an offer signed off-chain by a buyer, without the buyer funding an escrow first.
+    /// The buyer's signature is checked against an ed25519 program instruction earlier in the transaction,
+    /// and the amount is spent from the buyer's token account through the delegate approved for the offer.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for accepting the offer.
+    /// - `offer`: The offer signed by the buyer.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the buyer is paid out and the multi-wallet is handed over to the new members.
+    /// - `Err`: If the signature is missing, the offer expired or was already accepted, or the owners did not approve it.
+    ///
+    pub fn accept_signed_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSignedOffer<'info>>,
+        offer: SignedOffer,
+    ) -> Result<()> {
+        AcceptSignedOffer::process(ctx, offer)
+    }
+
+    /// Records the approval of an escrow by a member with the `VoteEscrow` permission. Recorded approvals
+    /// count towards the threshold of `execute_escrow_as_owner` along with the signers of the execution.
+    /// Room for the votes is reserved when the escrow is created, so voting costs no rent.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the escrow and the voting member.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the vote is recorded, replacing any previous vote of the member.
+    /// - `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.
+    ///
+    pub fn escrow_approve<'info>(
+        ctx: Context<'_, '_, '_, 'info, EscrowCastVote<'info>>,
+    ) -> Result<()> {
+        EscrowCastVote::process(ctx, true)
+    }
+
+    /// Records the rejection of an escrow by a member with the `VoteEscrow` permission. Recorded rejections
+    /// count towards the threshold of `cancel_escrow_as_owner` along with the signers of the cancellation.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the escrow and the voting member.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the vote is recorded, replacing any previous vote of the member.
+    /// - `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.
+    ///
+    pub fn escrow_reject<'info>(
+        ctx: Context<'_, '_, '_, 'info, EscrowCastVote<'info>>,
+    ) -> Result<()> {
+        EscrowCastVote::process(ctx, false)
+    }
+
+    /// Proposes new terms on a non-owner escrow as an owner. The proposer can accept them
+    /// through `accept_escrow_counter_offer`, after which the owners can execute the escrow.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for the counter-offer.
+    /// - `amount`: The amount the owners ask for.
+    /// - `mint`: Token mint the amount is denominated in (if any).
+    /// - `threshold`: The threshold of the multi-wallet once the escrow is executed.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the counter-offer is recorded on the escrow.
+    /// - `Err`: If validation fails or the escrow is not a pending non-owner escrow.
+    ///
+    pub fn escrow_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, EscrowCounterOffer<'info>>,
+        amount: u64,
+        mint: Option<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        EscrowCounterOffer::process(ctx, amount, mint, threshold)
+    }
+
+    /// Accepts the owners' counter-offer as the proposer. The escrow vault is topped up by the proposer
+    /// or partially refunded to match the new amount, and fully refunded and re-funded if the mint changes.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for accepting the counter-offer.
+    ///   When the mint changes, the accounts of the new mint use `new_token_program`.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the escrow terms are updated to the counter-offer.
+    /// - `Err`: If there is no counter-offer or the transfer operation encounters an issue.
+    ///
+    pub fn accept_escrow_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptEscrowCounterOffer<'info>>,
+    ) -> Result<()> {
+        AcceptEscrowCounterOffer::process(ctx)
+    }
+
+    /// Initializes an auction as an owner. This function locks the multi-wallet and lists it
+    /// either as an English auction, won by the highest bid at the deadline, or as a Dutch auction,
+    /// whose price decreases linearly until it is bought through `execute_escrow_as_non_owner`.
+    /// A Dutch auction expires at `ends_at` and can then be cancelled through `cancel_expired_escrow`.
+    /// The escrow identifier is allocated from the escrow counter of the multi-wallet.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for initializing the auction.
+    /// - `recipient`: The account receiving the proceeds of the sale.
+    /// - `mint`: Token mint the auction is priced in (if any).
+    /// - `kind`: The auction kind and its pricing parameters.
+    /// - `ends_at`: Unix timestamp at which the auction ends.
+    /// - `marketplace_fee`: Optional fee paid to the marketplace the auction was created through.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the auction is successfully initialized and the multi-wallet is locked.
+    /// - `Err`: If any validation fails or the auction parameters are invalid.
+    ///
+    pub fn initiate_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeAuction<'info>>,
+        recipient: Pubkey,
+        mint: Option<Pubkey>,
+        kind: AuctionKind,
+        ends_at: i64,
+        marketplace_fee: Option<MarketplaceFee>,
+    ) -> Result<()> {
+        InitializeAuction::process(ctx, recipient, mint, kind, ends_at, marketplace_fee)
+    }
+
+    /// Places a bid on an English auction. The bid is locked in the escrow vault and the
+    /// previous highest bid is refunded to its bidder.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for placing the bid.
+    /// - `amount`: The amount bid.
+    /// - `new_members`: The members of the multi-wallet if the bid wins.
+    /// - `threshold`: The threshold of the multi-wallet if the bid wins.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the bid is placed and the previous bid is refunded.
+    /// - `Err`: If the auction has ended or the bid is too low.
+    ///
+    pub fn place_auction_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceAuctionBid<'info>>,
+        amount: u64,
+        new_members: Vec<Member>,
+        threshold: u8,
+    ) -> Result<()> {
+        PlaceAuctionBid::process(ctx, amount, new_members, threshold)
+    }
+
+    /// Settles an English auction once it has ended. Anyone can crank this. The highest bid
+    /// is paid to the recipient and the members of the multi-wallet are handed over to the winner.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for settling the auction.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the auction is settled, or lapses without a bid.
+    /// - `Err`: If the auction has not ended or the accounts provided are invalid.
+    ///
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    ) -> Result<()> {
+        SettleAuction::process(ctx)
+    }
+
+    /// Initializes the protocol config. Only the program upgrade authority can do this.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for initializing the protocol config.
+    /// - `fee_recipient`: The account receiving the protocol fee on escrow settlements.
+    /// - `fee_bps`: The protocol fee in basis points.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the protocol config is successfully initialized.
+    /// - `Err`: If the signer is not the upgrade authority or the fee is invalid.
+    ///
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        fee_recipient: Pubkey,
+        fee_bps: u16,
+    ) -> Result<()> {
+        InitializeProtocolConfig::process(ctx, fee_recipient, fee_bps)
+    }
+
+    /// Updates the protocol config.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing all relevant accounts required for updating the protocol config.
+    /// - `authority`: The new authority of the protocol config.
+    /// - `fee_recipient`: The account receiving the protocol fee on escrow settlements.
+    /// - `fee_bps`: The protocol fee in basis points.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the protocol config is successfully updated.
+    /// - `Err`: If the signer is not the protocol config authority or the fee is invalid.
+    ///
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        authority: Pubkey,
+        fee_recipient: Pubkey,
+        fee_bps: u16,
+    ) -> Result<()> {
+        UpdateProtocolConfig::process(ctx, authority, fee_recipient, fee_bps)
+    }
+
+    /// Closes the receipt of a settled escrow, returning its rent to a member of the multi-wallet.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context containing the receipt and the signing members.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the receipt is closed.
+    /// - `Err`: If the threshold is not met or the rent collector is not a signing member.
+    ///
+    pub fn close_escrow_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseEscrowReceipt<'info>>,
+    ) -> Result<()> {
+        CloseEscrowReceipt::process(ctx)
+    }
 }
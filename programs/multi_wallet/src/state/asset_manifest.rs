@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{MultisigError, SEED_MULTISIG, SEED_VAULT};
+
+/// Maximum number of entries in an escrow asset manifest.
+pub const MAX_ASSET_MANIFEST_LENGTH: usize = 16;
+
+/// A vault balance the buyer expects to still be there when the escrow is executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum VaultAssetExpectation {
+    /// The vault at `vault_index` holds at least `amount` lamports.
+    Lamports { vault_index: u16, amount: u64 },
+    /// A token account of `mint` owned by the vault at `vault_index` holds at least `amount` tokens,
+    /// and is neither frozen nor delegated nor closable by anyone but the vault.
+    Token {
+        vault_index: u16,
+        mint: Pubkey,
+        amount: u64,
+    },
+}
+
+impl VaultAssetExpectation {
+    pub const SIZE: usize = 1 + 2 + 32 + 8;
+
+    pub fn validate_manifest(asset_manifest: &[Self]) -> Result<()> {
+        require!(
+            asset_manifest.len() <= MAX_ASSET_MANIFEST_LENGTH,
+            MultisigError::AssetManifestTooLong
+        );
+        Ok(())
+    }
+
+    /// Verifies the expectation against the matching accounts in `account_infos`.
+    pub fn verify(&self, multi_wallet_key: &Pubkey, account_infos: &[AccountInfo]) -> Result<()> {
+        let vault_index = match self {
+            VaultAssetExpectation::Lamports { vault_index, .. } => vault_index,
+            VaultAssetExpectation::Token { vault_index, .. } => vault_index,
+        };
+        let (vault, _) = Pubkey::find_program_address(
+            &[
+                SEED_MULTISIG,
+                multi_wallet_key.as_ref(),
+                SEED_VAULT,
+                vault_index.to_le_bytes().as_ref(),
+            ],
+            &crate::id(),
+        );
+
+        let holds = match self {
+            VaultAssetExpectation::Lamports { amount, .. } => account_infos
+                .iter()
+                .find(|x| x.key.eq(&vault))
+                .ok_or(MultisigError::MissingAccount)?
+                .lamports()
+                >= *amount,
+            VaultAssetExpectation::Token { mint, amount, .. } => {
+                account_infos.iter().any(|account_info| {
+                    (*account_info.owner == anchor_spl::token::ID
+                        || *account_info.owner == anchor_spl::token_2022::ID)
+                        && TokenAccount::try_deserialize(&mut &account_info.data.borrow()[..])
+                            .is_ok_and(|token_account| {
+                                Self::token_account_holds(&token_account, &vault, mint, *amount)
+                            })
+                })
+            }
+        };
+        require!(holds, MultisigError::AssetManifestMismatch);
+        Ok(())
+    }
+
+    /// Whether `token_account` holds at least `amount` tokens of `mint` that only `vault` can move.
+    /// A delegate or close authority could drain the account after the escrow is executed, and
+    /// frozen tokens cannot be moved at all.
+    fn token_account_holds(token_account: &TokenAccount, vault: &Pubkey, mint: &Pubkey, amount: u64) -> bool {
+        token_account.owner == *vault
+            && token_account.mint == *mint
+            && token_account.amount >= amount
+            && token_account.delegate.is_none()
+            && token_account.close_authority.is_none()
+            && !token_account.is_frozen()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        solana_program::{program_option::COption, program_pack::Pack},
+        state::{Account as TokenAccountState, AccountState},
+    };
+
+    fn token_account(vault: Pubkey, mint: Pubkey, update: impl FnOnce(&mut TokenAccountState)) -> TokenAccount {
+        let mut state = TokenAccountState {
+            mint,
+            owner: vault,
+            amount: 100,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        update(&mut state);
+        let mut data = [0u8; TokenAccountState::LEN];
+        TokenAccountState::pack(state, &mut data).unwrap();
+        TokenAccount::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn token_expectation_requires_an_unencumbered_account() {
+        let (vault, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let holds = |update: fn(&mut TokenAccountState)| {
+            VaultAssetExpectation::token_account_holds(&token_account(vault, mint, update), &vault, &mint, 100)
+        };
+
+        assert!(holds(|_| {}));
+        assert!(!holds(|x| x.amount = 99));
+        assert!(!holds(|x| x.delegate = COption::Some(Pubkey::new_unique())));
+        assert!(!holds(|x| x.close_authority = COption::Some(Pubkey::new_unique())));
+        assert!(!holds(|x| x.state = AccountState::Frozen));
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::MultisigError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionKind {
+    /// Ascending price auction, won by the highest bid standing at `ends_at`.
+    English {
+        reserve_price: u64,
+        min_increment: u64,
+    },
+    /// Descending price auction, sold to the first buyer at the current price.
+    /// The price decreases linearly from `start_price` to `end_price` between `starts_at` and `ends_at`.
+    Dutch { start_price: u64, end_price: u64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct Auction {
+    pub kind: AuctionKind,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+impl Auction {
+    pub const SIZE: usize = 1 + // kind discriminator
+        16 + // kind
+        8 + // starts_at
+        8; // ends_at
+
+    pub fn new(kind: AuctionKind, ends_at: i64) -> Result<Self> {
+        let starts_at = Clock::get()?.unix_timestamp;
+        require!(ends_at > starts_at, MultisigError::InvalidAuction);
+        if let AuctionKind::Dutch {
+            start_price,
+            end_price,
+        } = kind
+        {
+            require!(start_price >= end_price, MultisigError::InvalidAuction);
+        }
+        Ok(Self {
+            kind,
+            starts_at,
+            ends_at,
+        })
+    }
+
+    pub fn has_ended(&self) -> Result<bool> {
+        Ok(Clock::get()?.unix_timestamp >= self.ends_at)
+    }
+
+    /// Returns the current price of a Dutch auction.
+    pub fn current_price(&self) -> Result<u64> {
+        let AuctionKind::Dutch {
+            start_price,
+            end_price,
+        } = self.kind
+        else {
+            return err!(MultisigError::InvalidAuction);
+        };
+
+        let now = Clock::get()?.unix_timestamp.clamp(self.starts_at, self.ends_at);
+        let elapsed = (now - self.starts_at) as u128;
+        let duration = (self.ends_at - self.starts_at) as u128;
+        let decrease = u128::from(start_price - end_price) * elapsed / duration;
+
+        Ok(start_price - decrease as u64)
+    }
+
+    /// Returns the minimum amount the next bid of an English auction must reach.
+    pub fn min_bid(&self, highest_bid: Option<u64>) -> Result<u64> {
+        let AuctionKind::English {
+            reserve_price,
+            min_increment,
+        } = self.kind
+        else {
+            return err!(MultisigError::InvalidAuction);
+        };
+
+        match highest_bid {
+            Some(highest_bid) => Ok(highest_bid
+                .checked_add(min_increment)
+                .ok_or(MultisigError::AuctionBidTooLow)?
+                .max(reserve_price)),
+            None => Ok(reserve_price),
+        }
+    }
+
+    pub fn is_english(&self) -> bool {
+        matches!(self.kind, AuctionKind::English { .. })
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::MultisigError;
+
+/// A condition on an account balance that must hold after a vault transaction is executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum BalanceAssertion {
+    /// Lamports of `account` may decrease by at most `amount`.
+    MaxLamportsDecrease { account: Pubkey, amount: u64 },
+    /// Token balance of `token_account` may decrease by at most `amount`.
+    MaxTokenDecrease { token_account: Pubkey, amount: u64 },
+    /// Token balance of `token_account` must be at least `amount` after execution.
+    MinTokenBalance { token_account: Pubkey, amount: u64 },
+}
+
+impl BalanceAssertion {
+    pub const SIZE: usize = 1 + 32 + 8;
+
+    pub fn account(&self) -> &Pubkey {
+        match self {
+            BalanceAssertion::MaxLamportsDecrease { account, .. } => account,
+            BalanceAssertion::MaxTokenDecrease { token_account, .. } => token_account,
+            BalanceAssertion::MinTokenBalance { token_account, .. } => token_account,
+        }
+    }
+
+    /// Reads the balance this assertion is about from the matching account in `account_infos`.
+    pub fn read_balance(&self, account_infos: &[AccountInfo]) -> Result<u64> {
+        let account_info = account_infos
+            .iter()
+            .find(|x| x.key.eq(self.account()))
+            .ok_or(MultisigError::MissingAccount)?;
+
+        match self {
+            BalanceAssertion::MaxLamportsDecrease { .. } => Ok(account_info.lamports()),
+            BalanceAssertion::MaxTokenDecrease { .. } | BalanceAssertion::MinTokenBalance { .. } => {
+                require!(
+                    *account_info.owner == anchor_spl::token::ID
+                        || *account_info.owner == anchor_spl::token_2022::ID,
+                    MultisigError::InvalidAccount
+                );
+                let token_account =
+                    TokenAccount::try_deserialize(&mut &account_info.data.borrow()[..])?;
+                Ok(token_account.amount)
+            }
+        }
+    }
+
+    /// Checks the assertion given the balance before and after execution.
+    pub fn check(&self, before: u64, after: u64) -> Result<()> {
+        let holds = match self {
+            BalanceAssertion::MaxLamportsDecrease { amount, .. }
+            | BalanceAssertion::MaxTokenDecrease { amount, .. } => {
+                before.saturating_sub(after) <= *amount
+            }
+            BalanceAssertion::MinTokenBalance { amount, .. } => after >= *amount,
+        };
+        require!(holds, MultisigError::BalanceAssertionFailed);
+        Ok(())
+    }
+}
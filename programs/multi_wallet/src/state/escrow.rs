@@ -1,15 +1,26 @@
 use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
+    Discriminator,
 };
-use anchor_spl::token_interface::{
-    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
-    TransferChecked,
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address_with_program_id, Create},
+    token_2022_extensions::transfer_fee::{
+        harvest_withheld_tokens_to_mint, transfer_checked_with_fee, HarvestWithheldTokensToMint,
+        TransferCheckedWithFee,
+    },
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
 };
 
-use crate::{MultisigError, SEED_ESCROW};
+use crate::{
+    get_token_account_balances, get_transfer_fee_config, validate_escrow_mint, MultisigError,
+    ProtocolConfig, MAX_FEE_BPS, SEED_ESCROW,
+};
 
-use super::{Member, SEED_VAULT};
+use super::{Auction, LegacyMember, Member, MultiWallet, Permission, VaultAssetExpectation, SEED_VAULT};
 
 #[account]
 #[derive(Default, Debug, Copy)]
@@ -19,7 +30,122 @@ pub struct Recipient {
     pub mint: Option<Pubkey>, // if none it means the mint is native sol
 }
 
+/// Fee charged by the marketplace an escrow was created through.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarketplaceFee {
+    pub recipient: Pubkey,
+    pub fee_bps: u16,
+}
+
+/// Fees deducted from the settled amount of an escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct EscrowFees {
+    pub protocol_fee: u64,
+    pub marketplace_fee: u64,
+}
+
+impl EscrowFees {
+    pub fn total(&self) -> u64 {
+        self.protocol_fee + self.marketplace_fee
+    }
+}
+
+/// Accounts receiving the fees of an escrow settlement.
+#[derive(Clone, Copy)]
+pub struct FeeAccounts<'a, 'info> {
+    pub protocol_config: &'a AccountInfo<'info>,
+    pub protocol_fee_recipient: &'a Option<UncheckedAccount<'info>>,
+    pub protocol_fee_token_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub marketplace_fee_recipient: &'a Option<UncheckedAccount<'info>>,
+    pub marketplace_fee_token_account: &'a Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> FeeAccounts<'_, 'info> {
+    /// Returns the protocol fee recipient, checked against the protocol config.
+    fn protocol_fee_recipient(&self, protocol_config: &Option<ProtocolConfig>) -> Result<AccountInfo<'info>> {
+        let protocol_fee_recipient = self
+            .protocol_fee_recipient
+            .as_ref()
+            .ok_or(MultisigError::MissingAccount)?;
+        require!(
+            protocol_config
+                .as_ref()
+                .is_some_and(|x| x.fee_recipient == protocol_fee_recipient.key()),
+            MultisigError::InvalidFeeRecipient
+        );
+        Ok(protocol_fee_recipient.to_account_info())
+    }
+
+    /// Returns the marketplace fee recipient, checked against the escrow marketplace fee.
+    fn marketplace_fee_recipient(&self, marketplace_fee: &Option<MarketplaceFee>) -> Result<AccountInfo<'info>> {
+        let marketplace_fee_recipient = self
+            .marketplace_fee_recipient
+            .as_ref()
+            .ok_or(MultisigError::MissingAccount)?;
+        require!(
+            marketplace_fee.is_some_and(|x| x.recipient == marketplace_fee_recipient.key()),
+            MultisigError::InvalidFeeRecipient
+        );
+        Ok(marketplace_fee_recipient.to_account_info())
+    }
+}
+
+/// First escrow identifier that clients can choose. Identifiers below it are allocated from the
+/// escrow counter of the multi-wallet.
+pub const CLIENT_ESCROW_IDENTIFIER_START: u64 = 1 << 63;
+
+/// Maximum number of buyers an owner listing can be restricted to.
+pub const MAX_ALLOWED_BUYERS: usize = 8;
+
+/// Maximum number of additional payment legs on an escrow.
+pub const MAX_ADDITIONAL_LEGS: usize = 4;
+
+/// An asset paid into the escrow in addition to the recipient amount.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PaymentLeg {
+    pub mint: Option<Pubkey>, // if none it means the mint is native sol
+    pub amount: u64,
+}
+
+impl PaymentLeg {
+    pub const SIZE: usize = 1 + 32 + 8;
+}
+
+/// Vote recorded on an escrow by a member with `Permission::VoteEscrow`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct EscrowVote {
+    pub member: Pubkey,
+    pub approve: bool,
+}
+
+impl EscrowVote {
+    pub const SIZE: usize = 32 + 1;
+}
+
+/// Accounts used to transfer the additional legs of an escrow.
+///
+/// Each token leg needs its mint, its token program and the associated token accounts of the
+/// sender and receiver in `account_infos`, which are looked up by address.
+pub struct LegAccounts<'a, 'info> {
+    pub account_infos: &'a [AccountInfo<'info>],
+    /// Creates missing receiving token accounts, if set.
+    pub rent_payer: Option<&'a AccountInfo<'info>>,
+    /// Receives the rent of escrow vault token accounts closed once emptied. Must be the escrow rent payer.
+    pub vault_rent_refund: Option<&'a AccountInfo<'info>>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+/// Terms proposed by the owners in response to a non-owner escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CounterOffer {
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub threshold: u8,
+}
+
 #[account]
+#[derive(Default)]
 pub struct Escrow {
     pub create_key: Pubkey,
     pub identifier: u64,
@@ -29,10 +155,53 @@ pub struct Escrow {
     pub recipient: Recipient,
     pub new_members: Option<Vec<Member>>,
     pub threshold: Option<u8>,
+    pub expires_at: Option<i64>,
+    pub auction: Option<Auction>,
+    pub marketplace_fee: Option<MarketplaceFee>,
+    pub counter_offer: Option<CounterOffer>,
+    /// Vault balances the proposer expects to receive along with the multi-wallet.
+    pub asset_manifest: Vec<VaultAssetExpectation>,
+    /// Assets paid in addition to the recipient amount.
+    pub additional_legs: Vec<PaymentLeg>,
+    /// Member whose seat is sold to the proposer. The other members and the threshold are kept.
+    pub seat: Option<Pubkey>,
+    /// Votes recorded by the owners ahead of executing or cancelling the escrow. Room for one vote per
+    /// member with `Permission::VoteEscrow` is reserved when the escrow is created.
+    pub votes: Vec<EscrowVote>,
+    /// Buyers allowed to execute an owner listing. Anyone can buy it if empty.
+    pub allowed_buyers: Vec<Pubkey>,
+    pub created_at: i64,
+    /// Account that paid the escrow rent, refunded when an expired escrow is cancelled.
+    pub rent_payer: Pubkey,
+    /// Layout version of the account. Escrows created before versioning are upgraded by `migrate_escrow`
+    /// and cannot be used until then.
+    pub version: u8,
+}
+
+/// Layout version of newly created and migrated escrows.
+pub const ESCROW_VERSION: u8 = 1;
+
+/// Layout of escrows created before the account was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EscrowV0 {
+    pub create_key: Pubkey,
+    pub identifier: u64,
+    pub bump: u8,
+    pub proposer: Option<Pubkey>,
+    pub vault_bump: Option<u8>,
+    pub recipient: Recipient,
+    pub new_members: Option<Vec<LegacyMember>>,
+    pub threshold: Option<u8>,
 }
 
 impl Escrow {
-    pub fn size(new_members_length: usize) -> usize {
+    pub fn size(
+        new_members_length: usize,
+        asset_manifest_length: usize,
+        additional_legs_length: usize,
+        allowed_buyers_length: usize,
+        votes_length: usize,
+    ) -> usize {
         8  + // anchor account discriminator
         32 + // multi_wallet
 		8  + // identifier
@@ -43,10 +212,240 @@ impl Escrow {
         80 + // recipient
 		1  + // optional
 		4  + // vector
-        new_members_length * 34 +
-        2 // threshold
+        new_members_length * Member::INIT_SPACE +
+        2 + // threshold
+        9 + // expires_at
+        1 + Auction::SIZE + // auction
+        1 + 32 + 2 + // marketplace_fee
+        1 + 8 + 33 + 1 + // counter_offer
+        4 + // vector
+        asset_manifest_length * VaultAssetExpectation::SIZE +
+        4 + // vector
+        additional_legs_length * PaymentLeg::SIZE +
+        1 + 32 + // seat
+        4 + // votes vector
+        votes_length * EscrowVote::SIZE +
+        4 + // vector
+        allowed_buyers_length * 32 +
+        8 + // created_at
+        32 + // rent_payer
+        1 // version
+    }
+
+    /// Reads the account data of an escrow created before versioning and upgrades it to the current
+    /// version. Owner listings did not record who paid their rent, so it is refunded to `owner_rent_payer`.
+    pub fn upgrade(data: &[u8], owner_rent_payer: Pubkey, created_at: i64) -> Result<Escrow> {
+        require!(
+            data.starts_with(&Escrow::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        if let Ok(escrow) = Escrow::try_deserialize(&mut &data[..]) {
+            require!(
+                escrow.version < ESCROW_VERSION,
+                MultisigError::EscrowAlreadyMigrated
+            );
+        }
+
+        // Accounts being grown by `migrate_escrow` keep their old layout followed by zeroes.
+        let v0 = EscrowV0::deserialize(&mut &data[Escrow::DISCRIMINATOR.len()..])?;
+        Ok(Escrow {
+            create_key: v0.create_key,
+            identifier: v0.identifier,
+            bump: v0.bump,
+            proposer: v0.proposer,
+            vault_bump: v0.vault_bump,
+            recipient: v0.recipient,
+            new_members: v0
+                .new_members
+                .map(|x| x.into_iter().map(Member::from).collect()),
+            threshold: v0.threshold,
+            created_at,
+            rent_payer: v0.proposer.unwrap_or(owner_rent_payer),
+            version: ESCROW_VERSION,
+            ..Default::default()
+        })
+    }
+
+    /// Space needed to store `new_members_length` new members and `votes_length` votes.
+    pub fn space(&self, new_members_length: usize, votes_length: usize) -> usize {
+        Self::size(
+            new_members_length,
+            self.asset_manifest.len(),
+            self.additional_legs.len(),
+            self.allowed_buyers.len(),
+            votes_length,
+        )
+    }
+
+    /// Number of votes that fit in an escrow account of `data_len` bytes. Room for the votes of the
+    /// voting members is reserved when the escrow is created, so voters never pay for it.
+    pub fn vote_capacity(&self, data_len: usize) -> usize {
+        data_len.saturating_sub(self.space(self.new_members.as_ref().map_or(0, Vec::len), 0)) / EscrowVote::SIZE
     }
 
+    /// Records the vote of `member`, replacing any previous vote. Once the reserved room is used up,
+    /// the votes of accounts that can no longer vote on escrows of `multi_wallet` are dropped.
+    pub fn record_vote(&mut self, member: Pubkey, approve: bool, vote_capacity: usize, multi_wallet: &MultiWallet) -> Result<()> {
+        if let Some(vote) = self.votes.iter_mut().find(|x| x.member == member) {
+            vote.approve = approve;
+            return Ok(());
+        }
+        if self.votes.len() >= vote_capacity {
+            self.votes.retain(|x| {
+                multi_wallet
+                    .find_member(&x.member)
+                    .is_some_and(|x| x.permissions.is_some_and(|x| x.has(Permission::VoteEscrow)))
+            });
+        }
+        require!(self.votes.len() < vote_capacity, MultisigError::TooManyEscrowVotes);
+        self.votes.push(EscrowVote { member, approve });
+        Ok(())
+    }
+
+    pub fn is_expired(&self) -> Result<bool> {
+        match self.expires_at {
+            Some(expires_at) => Ok(Clock::get()?.unix_timestamp >= expires_at),
+            None => Ok(false),
+        }
+    }
+
+    pub fn validate_expiry(expires_at: &Option<i64>) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            require!(
+                *expires_at > Clock::get()?.unix_timestamp,
+                MultisigError::InvalidEscrowExpiry
+            );
+        }
+        Ok(())
+    }
+
+    pub fn validate_marketplace_fee(marketplace_fee: &Option<MarketplaceFee>) -> Result<()> {
+        if let Some(marketplace_fee) = marketplace_fee {
+            ProtocolConfig::validate_fee_bps(marketplace_fee.fee_bps)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate_allowed_buyers(allowed_buyers: &[Pubkey]) -> Result<()> {
+        require!(
+            allowed_buyers.len() <= MAX_ALLOWED_BUYERS,
+            MultisigError::TooManyAllowedBuyers
+        );
+        Ok(())
+    }
+
+    /// Returns `true` if `buyer` may execute the owner listing.
+    pub fn is_allowed_buyer(&self, buyer: &Pubkey) -> bool {
+        self.allowed_buyers.is_empty() || self.allowed_buyers.contains(buyer)
+    }
+
+    /// Checks the additional legs are bounded, non-zero and each pay in a distinct asset.
+    pub fn validate_additional_legs(recipient_mint: &Option<Pubkey>, additional_legs: &[PaymentLeg]) -> Result<()> {
+        require!(
+            additional_legs.len() <= MAX_ADDITIONAL_LEGS,
+            MultisigError::InvalidPaymentLegs
+        );
+        let mut seen = std::collections::HashSet::from([*recipient_mint]);
+        for leg in additional_legs {
+            require!(
+                leg.amount > 0 && seen.insert(leg.mint),
+                MultisigError::InvalidPaymentLegs
+            );
+        }
+        Ok(())
+    }
+
+    /// Computes the protocol and marketplace fees on the escrow amount.
+    pub fn fees(&self, protocol_config: &Option<ProtocolConfig>) -> Result<EscrowFees> {
+        self.fees_on(self.recipient.amount, protocol_config)
+    }
+
+    /// Computes the protocol and marketplace fees on `amount`.
+    pub fn fees_on(&self, amount: u64, protocol_config: &Option<ProtocolConfig>) -> Result<EscrowFees> {
+        let protocol_fee_bps = protocol_config.as_ref().map_or(0, |x| x.fee_bps);
+        let marketplace_fee_bps = self.marketplace_fee.map_or(0, |x| x.fee_bps);
+        require!(
+            u32::from(protocol_fee_bps) + u32::from(marketplace_fee_bps) <= u32::from(MAX_FEE_BPS),
+            MultisigError::InvalidFeeBasisPoints
+        );
+
+        let fee = |fee_bps: u16| {
+            (u128::from(amount) * u128::from(fee_bps) / u128::from(MAX_FEE_BPS)) as u64
+        };
+        Ok(EscrowFees {
+            protocol_fee: fee(protocol_fee_bps),
+            marketplace_fee: fee(marketplace_fee_bps),
+        })
+    }
+
+    /// Transfers the escrow amount to the recipient, after paying out the protocol and marketplace fees.
+    #[allow(clippy::too_many_arguments)]
+    pub fn escrow_transfer_with_fees<'info>(
+        &self,
+        fee_accounts: FeeAccounts<'_, 'info>,
+        mint: &Option<Box<InterfaceAccount<'info, Mint>>>,
+        from: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        to: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        from_native: &Option<AccountInfo<'info>>,
+        to_native: &Option<AccountInfo<'info>>,
+        escrow_vault: &Option<AccountInfo<'info>>,
+        payer: &AccountInfo<'info>,
+        token_program: &Option<Interface<'info, TokenInterface>>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<EscrowFees> {
+        let protocol_config = ProtocolConfig::load(fee_accounts.protocol_config)?;
+        let fees = self.fees(&protocol_config)?;
+
+        if fees.protocol_fee > 0 {
+            let protocol_fee_recipient = fee_accounts.protocol_fee_recipient(&protocol_config)?;
+            self.transfer_amount(
+                fees.protocol_fee,
+                mint,
+                from,
+                fee_accounts.protocol_fee_token_account,
+                from_native,
+                &Some(protocol_fee_recipient.to_account_info()),
+                escrow_vault,
+                payer,
+                token_program,
+                system_program,
+            )?;
+        }
+
+        if fees.marketplace_fee > 0 {
+            let marketplace_fee_recipient =
+                fee_accounts.marketplace_fee_recipient(&self.marketplace_fee)?;
+            self.transfer_amount(
+                fees.marketplace_fee,
+                mint,
+                from,
+                fee_accounts.marketplace_fee_token_account,
+                from_native,
+                &Some(marketplace_fee_recipient.to_account_info()),
+                escrow_vault,
+                payer,
+                token_program,
+                system_program,
+            )?;
+        }
+
+        self.transfer_amount(
+            self.recipient.amount - fees.total(),
+            mint,
+            from,
+            to,
+            from_native,
+            to_native,
+            escrow_vault,
+            payer,
+            token_program,
+            system_program,
+        )?;
+
+        Ok(fees)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn escrow_transfer<'info>(
         &self,
         mint: &Option<Box<InterfaceAccount<'info, Mint>>>,
@@ -59,12 +458,38 @@ impl Escrow {
         token_program: &Option<Interface<'info, TokenInterface>>,
         system_program: &AccountInfo<'info>,
     ) -> Result<()> {
-        if self.recipient.amount == 0 {
+        self.transfer_amount(
+            self.recipient.amount,
+            mint,
+            from,
+            to,
+            from_native,
+            to_native,
+            escrow_vault,
+            payer,
+            token_program,
+            system_program,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_amount<'info>(
+        &self,
+        amount: u64,
+        mint: &Option<Box<InterfaceAccount<'info, Mint>>>,
+        from: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        to: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+        from_native: &Option<AccountInfo<'info>>,
+        to_native: &Option<AccountInfo<'info>>,
+        escrow_vault: &Option<AccountInfo<'info>>,
+        payer: &AccountInfo<'info>,
+        token_program: &Option<Interface<'info, TokenInterface>>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if amount == 0 {
             return Ok(());
         }
-        let multi_wallet_key = self.create_key.key();
-        let identifier = self.identifier.to_le_bytes();
-        let authority = escrow_vault.as_ref().map_or(payer, |x| x.as_ref());
+        let authority = escrow_vault.as_ref().unwrap_or(payer);
 
         match self.recipient.mint {
             // Token transfer case
@@ -81,55 +506,16 @@ impl Escrow {
                     MultisigError::MissingAccount
                 );
 
-                let transfer_ctx = CpiContext::new(
-                    token_account.to_account_info(),
-                    TransferChecked {
-                        from: from_account.to_account_info(),
-                        mint: mint_account.to_account_info(),
-                        to: to_account.to_account_info(),
-                        authority: authority.to_account_info(),
-                    },
-                );
-                if escrow_vault.is_some() {
-                    let signer_seeds: &[&[&[u8]]] = &[&[
-                        SEED_ESCROW,
-                        multi_wallet_key.as_ref(),
-                        identifier.as_ref(),
-                        SEED_VAULT,
-                        &[self.vault_bump.unwrap()],
-                    ]];
-                    transfer_checked(
-                        transfer_ctx.with_signer(signer_seeds),
-                        self.recipient.amount,
-                        mint_account.decimals,
-                    )?;
-                } else {
-                    transfer_checked(transfer_ctx, self.recipient.amount, mint_account.decimals)?;
-                }
-
-                // Close account if balance is zero
-                if from_account.amount == 0 {
-                    let close_ctx = CpiContext::new(
-                        token_account.to_account_info(),
-                        CloseAccount {
-                            account: from_account.to_account_info(),
-                            destination: payer.to_account_info(),
-                            authority: authority.to_account_info(),
-                        },
-                    );
-                    if escrow_vault.is_some() {
-                        let signer_seeds: &[&[&[u8]]] = &[&[
-                            SEED_ESCROW,
-                            multi_wallet_key.as_ref(),
-                            identifier.as_ref(),
-                            SEED_VAULT,
-                            &[self.vault_bump.unwrap()],
-                        ]];
-                        close_account(close_ctx.with_signer(signer_seeds))?;
-                    } else {
-                        close_account(close_ctx)?;
-                    }
-                }
+                self.transfer_token(
+                    amount,
+                    &mint_account.to_account_info(),
+                    &from_account.to_account_info(),
+                    &to_account.to_account_info(),
+                    authority,
+                    escrow_vault.is_some(),
+                    payer,
+                    &token_account.to_account_info(),
+                )?;
             }
             // Native transfer case
             None => {
@@ -137,31 +523,375 @@ impl Escrow {
                     from_native.as_ref().ok_or(MultisigError::MissingAccount)?;
                 let to_native_account = to_native.as_ref().ok_or(MultisigError::MissingAccount)?;
 
-                let transfer_ctx = CpiContext::new(
-                    system_program.to_account_info(),
-                    Transfer {
-                        from: from_native_account.to_account_info(),
-                        to: to_native_account.to_account_info(),
-                    },
-                );
-                if escrow_vault.is_some() {
-                    let signer_seeds: &[&[&[u8]]] = &[&[
-                        SEED_ESCROW,
-                        multi_wallet_key.as_ref(),
-                        identifier.as_ref(),
-                        SEED_VAULT,
-                        &[self.vault_bump.unwrap()],
-                    ]];
-                    transfer(
-                        transfer_ctx.with_signer(signer_seeds),
-                        self.recipient.amount,
-                    )?;
+                self.transfer_native(
+                    amount,
+                    from_native_account,
+                    to_native_account,
+                    escrow_vault.is_some(),
+                    system_program,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers `amount` lamports, signing as the escrow vault if `from_vault` is set.
+    fn transfer_native<'info>(
+        &self,
+        amount: u64,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        from_vault: bool,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let multi_wallet_key = self.create_key.key();
+        let identifier = self.identifier.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED_ESCROW,
+            multi_wallet_key.as_ref(),
+            identifier.as_ref(),
+            SEED_VAULT,
+            &[self.vault_bump.unwrap_or_default()],
+        ]];
+        let signer_seeds = if from_vault { signer_seeds } else { &[] };
+
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+
+    /// Transfers `amount` tokens from `from` to `to`, signing as the escrow vault if `from_vault` is set.
+    /// The escrow token vault is closed to `payer` once it is emptied.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_token<'info>(
+        &self,
+        amount: u64,
+        mint: &AccountInfo<'info>,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        from_vault: bool,
+        payer: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        validate_escrow_mint(mint)?;
+        let decimals = Mint::try_deserialize(&mut &mint.try_borrow_data()?[..])?.decimals;
+
+        let multi_wallet_key = self.create_key.key();
+        let identifier = self.identifier.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED_ESCROW,
+            multi_wallet_key.as_ref(),
+            identifier.as_ref(),
+            SEED_VAULT,
+            &[self.vault_bump.unwrap_or_default()],
+        ]];
+        let signer_seeds = if from_vault { signer_seeds } else { &[] };
+
+        match get_transfer_fee_config(mint)? {
+            Some(transfer_fee_config) => {
+                let transfer_fee = transfer_fee_config.get_epoch_fee(Clock::get()?.epoch);
+                // Funds leaving the escrow vault are paid out net of the transfer fee.
+                // Funds sent by a signer are grossed up so the receiver nets `amount`.
+                let amount = if from_vault {
+                    amount
                 } else {
-                    transfer(transfer_ctx, self.recipient.amount)?;
+                    transfer_fee
+                        .calculate_pre_fee_amount(amount)
+                        .ok_or(MultisigError::TransferFeeCalculationFailed)?
                 };
+                let fee = transfer_fee
+                    .calculate_fee(amount)
+                    .ok_or(MultisigError::TransferFeeCalculationFailed)?;
+
+                transfer_checked_with_fee(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferCheckedWithFee {
+                            token_program_id: token_program.to_account_info(),
+                            source: from.to_account_info(),
+                            mint: mint.to_account_info(),
+                            destination: to.to_account_info(),
+                            authority: authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                    decimals,
+                    fee,
+                )?;
             }
+            None => {
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: from.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: authority.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                    decimals,
+                )?;
+            }
+        }
+
+        // Close the escrow token vault once it is emptied, harvesting any withheld
+        // transfer fees to the (writable) mint first so the account can be closed.
+        // Accounts the vault only spends from as a delegate are left open.
+        let is_vault_owned = from_vault
+            && TokenAccount::try_deserialize(&mut &from.try_borrow_data()?[..])?.owner == authority.key();
+        if is_vault_owned {
+            let (balance, withheld_amount) = get_token_account_balances(from)?;
+            if balance == 0 {
+                if withheld_amount > 0 {
+                    harvest_withheld_tokens_to_mint(
+                        CpiContext::new(
+                            token_program.to_account_info(),
+                            HarvestWithheldTokensToMint {
+                                token_program_id: token_program.to_account_info(),
+                                mint: mint.to_account_info(),
+                            },
+                        ),
+                        vec![from.to_account_info()],
+                    )?;
+                }
+                close_account(CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    CloseAccount {
+                        account: from.to_account_info(),
+                        destination: payer.to_account_info(),
+                        authority: authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers `amount` of the leg asset `leg_mint` from `from` to `to`, signing as the escrow vault
+    /// if `from_vault` is set. Token legs move between the associated token accounts of `from` and `to`.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_leg<'info>(
+        &self,
+        leg_mint: Option<Pubkey>,
+        amount: u64,
+        leg_accounts: &LegAccounts<'_, 'info>,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        from_vault: bool,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let Some(leg_mint) = leg_mint else {
+            return self.transfer_native(amount, from, to, from_vault, leg_accounts.system_program);
+        };
+
+        let find_account = |key: &Pubkey| {
+            leg_accounts
+                .account_infos
+                .iter()
+                .find(|x| x.key.eq(key))
+                .ok_or(MultisigError::MissingAccount)
+        };
+        let mint = find_account(&leg_mint)?;
+        let token_program = find_account(mint.owner)?;
+        require!(
+            *token_program.key == anchor_spl::token::ID
+                || *token_program.key == anchor_spl::token_2022::ID,
+            MultisigError::InvalidAccount
+        );
+        let from_token_account = find_account(&get_associated_token_address_with_program_id(
+            from.key,
+            &leg_mint,
+            token_program.key,
+        ))?;
+        let to_token_account = find_account(&get_associated_token_address_with_program_id(
+            to.key,
+            &leg_mint,
+            token_program.key,
+        ))?;
+
+        if let Some(rent_payer) = leg_accounts.rent_payer {
+            create_idempotent(CpiContext::new(
+                leg_accounts.associated_token_program.to_account_info(),
+                Create {
+                    payer: rent_payer.to_account_info(),
+                    associated_token: to_token_account.to_account_info(),
+                    authority: to.to_account_info(),
+                    mint: mint.to_account_info(),
+                    system_program: leg_accounts.system_program.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        // Emptied escrow vault token accounts are refunded to whoever paid for them, not to `to`,
+        // which can be a fee recipient.
+        let close_destination = if from_vault {
+            let vault_rent_refund = leg_accounts
+                .vault_rent_refund
+                .ok_or(MultisigError::MissingAccount)?;
+            require_keys_eq!(*vault_rent_refund.key, self.rent_payer, MultisigError::InvalidAccount);
+            vault_rent_refund
+        } else {
+            to
+        };
+
+        self.transfer_token(
+            amount,
+            mint,
+            from_token_account,
+            to_token_account,
+            from,
+            from_vault,
+            close_destination,
+            token_program,
+        )
+    }
+
+    /// Transfers every additional leg from `from` to `to`, signing as the escrow vault if `from_vault` is set.
+    pub fn transfer_additional_legs<'info>(
+        &self,
+        leg_accounts: &LegAccounts<'_, 'info>,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        from_vault: bool,
+    ) -> Result<()> {
+        for leg in &self.additional_legs {
+            self.transfer_leg(leg.mint, leg.amount, leg_accounts, from, to, from_vault)?;
         }
+        Ok(())
+    }
 
+    /// Pays every additional leg out of the escrow vault to `to`, after paying out the protocol and marketplace fees.
+    pub fn transfer_additional_legs_with_fees<'info>(
+        &self,
+        fee_accounts: FeeAccounts<'_, 'info>,
+        leg_accounts: &LegAccounts<'_, 'info>,
+        escrow_vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let protocol_config = ProtocolConfig::load(fee_accounts.protocol_config)?;
+        for leg in &self.additional_legs {
+            let fees = self.fees_on(leg.amount, &protocol_config)?;
+
+            if fees.protocol_fee > 0 {
+                let protocol_fee_recipient =
+                    fee_accounts.protocol_fee_recipient(&protocol_config)?;
+                self.transfer_leg(
+                    leg.mint,
+                    fees.protocol_fee,
+                    leg_accounts,
+                    escrow_vault,
+                    &protocol_fee_recipient,
+                    true,
+                )?;
+            }
+            if fees.marketplace_fee > 0 {
+                let marketplace_fee_recipient =
+                    fee_accounts.marketplace_fee_recipient(&self.marketplace_fee)?;
+                self.transfer_leg(
+                    leg.mint,
+                    fees.marketplace_fee,
+                    leg_accounts,
+                    escrow_vault,
+                    &marketplace_fee_recipient,
+                    true,
+                )?;
+            }
+            self.transfer_leg(
+                leg.mint,
+                leg.amount - fees.total(),
+                leg_accounts,
+                escrow_vault,
+                to,
+                true,
+            )?;
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Permissions;
+
+    fn v0_snapshot(proposer: Option<Pubkey>) -> (EscrowV0, Vec<u8>) {
+        let v0 = EscrowV0 {
+            create_key: Pubkey::new_unique(),
+            identifier: 7,
+            bump: 253,
+            proposer,
+            vault_bump: proposer.map(|_| 252),
+            recipient: Recipient {
+                amount: 1_000,
+                pubkey: Some(Pubkey::new_unique()),
+                mint: None,
+            },
+            new_members: proposer.map(|x| {
+                vec![LegacyMember {
+                    pubkey: x,
+                    permissions: Some(Permissions { mask: 63 }),
+                }]
+            }),
+            threshold: proposer.map(|_| 1),
+        };
+        let mut data = Escrow::DISCRIMINATOR.to_vec();
+        data.extend(v0.try_to_vec().unwrap());
+        (v0, data)
+    }
+
+    #[test]
+    fn upgrades_v0_snapshots() {
+        let owner_rent_payer = Pubkey::new_unique();
+        for proposer in [None, Some(Pubkey::new_unique())] {
+            let (v0, mut data) = v0_snapshot(proposer);
+            // Partially grown accounts keep the old layout followed by zeroes.
+            for padding in [0, 512] {
+                data.resize(data.len() + padding, 0);
+                let escrow = Escrow::upgrade(&data, owner_rent_payer, 100).unwrap();
+
+                assert_eq!(escrow.create_key, v0.create_key);
+                assert_eq!(escrow.identifier, v0.identifier);
+                assert_eq!(escrow.bump, v0.bump);
+                assert_eq!(escrow.proposer, v0.proposer);
+                assert_eq!(escrow.vault_bump, v0.vault_bump);
+                assert_eq!(escrow.recipient.amount, v0.recipient.amount);
+                assert_eq!(escrow.recipient.pubkey, v0.recipient.pubkey);
+                assert_eq!(
+                    escrow.new_members.as_ref().map(|x| x.iter().map(|x| (x.pubkey, x.permissions)).collect::<Vec<_>>()),
+                    v0.new_members.as_ref().map(|x| x.iter().map(|x| (x.pubkey, x.permissions)).collect::<Vec<_>>())
+                );
+                assert_eq!(escrow.threshold, v0.threshold);
+                assert_eq!(escrow.created_at, 100);
+                assert_eq!(escrow.rent_payer, proposer.unwrap_or(owner_rent_payer));
+                assert_eq!(escrow.version, ESCROW_VERSION);
+
+                let mut migrated = Vec::new();
+                escrow.try_serialize(&mut migrated).unwrap();
+                assert!(migrated.len() <= escrow.space(escrow.new_members.as_ref().map_or(0, Vec::len), 0));
+                assert_eq!(
+                    Escrow::upgrade(&migrated, owner_rent_payer, 100).err().unwrap(),
+                    MultisigError::EscrowAlreadyMigrated.into()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::{create_program_account, id, MultisigError};
+
+use super::{Escrow, Member, SEED_ESCROW_RECEIPT};
+
+/// How an escrow was settled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowOutcome {
+    /// The multi-wallet was sold.
+    Executed,
+    /// The escrow was withdrawn by its proposer, or an owner listing was cancelled by the owners.
+    Cancelled,
+    /// The owners rejected a non-owner escrow.
+    Rejected,
+    /// The escrow expired, or an auction ended without a bid.
+    Expired,
+}
+
+/// Compact on-chain record of a settled escrow, kept after the escrow account is closed.
+#[account]
+pub struct EscrowReceipt {
+    pub create_key: Pubkey,
+    pub identifier: u64,
+    pub price: u64,
+    pub mint: Option<Pubkey>,
+    /// Hash of the members of the multi-wallet before settlement.
+    pub seller_members_hash: [u8; 32],
+    /// Hash of the members after settlement, or of the members the buyer proposed if the escrow
+    /// was not executed. Zeroed if there are none.
+    pub buyer_members_hash: [u8; 32],
+    pub created_at: i64,
+    pub settled_at: i64,
+    pub outcome: EscrowOutcome,
+    pub bump: u8,
+}
+
+impl EscrowReceipt {
+    pub const SIZE: usize = 8  + // anchor account discriminator
+        32 + // create_key
+        8  + // identifier
+        8  + // price
+        1 + 32 + // mint
+        32 + // seller_members_hash
+        32 + // buyer_members_hash
+        8  + // created_at
+        8  + // settled_at
+        1  + // outcome
+        1; // bump
+
+    /// Hashes a member set, returning zeroes if there is none.
+    pub fn hash_members(members: Option<&Vec<Member>>) -> Result<[u8; 32]> {
+        match members {
+            Some(members) => Ok(hash(&members.try_to_vec()?).to_bytes()),
+            None => Ok([0; 32]),
+        }
+    }
+
+    /// Creates the receipt of `escrow`, if a receipt account is passed.
+    pub fn record<'info>(
+        receipt: &Option<UncheckedAccount<'info>>,
+        payer: Option<&AccountInfo<'info>>,
+        system_program: &AccountInfo<'info>,
+        escrow: &Escrow,
+        seller_members: Option<&Vec<Member>>,
+        buyer_members: Option<&Vec<Member>>,
+        outcome: EscrowOutcome,
+    ) -> Result<()> {
+        let Some(receipt) = receipt else {
+            return Ok(());
+        };
+        let payer = payer.ok_or(MultisigError::MissingAccount)?;
+
+        let create_key = escrow.create_key;
+        let identifier = escrow.identifier.to_le_bytes();
+        let (receipt_key, bump) = Pubkey::find_program_address(
+            &[SEED_ESCROW_RECEIPT, create_key.as_ref(), identifier.as_ref()],
+            &id(),
+        );
+        require_keys_eq!(receipt.key(), receipt_key, MultisigError::InvalidAccount);
+
+        create_program_account(
+            payer,
+            &receipt.to_account_info(),
+            system_program,
+            Self::SIZE,
+            &[SEED_ESCROW_RECEIPT, create_key.as_ref(), identifier.as_ref(), &[bump]],
+        )?;
+
+        let receipt_data = EscrowReceipt {
+            create_key,
+            identifier: escrow.identifier,
+            price: escrow.recipient.amount,
+            mint: escrow.recipient.mint,
+            seller_members_hash: Self::hash_members(seller_members)?,
+            buyer_members_hash: Self::hash_members(buyer_members)?,
+            created_at: escrow.created_at,
+            settled_at: Clock::get()?.unix_timestamp,
+            outcome,
+            bump,
+        };
+        receipt_data.try_serialize(&mut &mut receipt.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use super::{Member, Recipient};
+use super::{EscrowFees, Member, PaymentLeg, Recipient, WalletMetadataUpdate};
 
 #[event]
 pub struct ConfigEvent {
@@ -8,6 +8,30 @@ pub struct ConfigEvent {
     pub members: Vec<Member>,
     pub threshold: u8,
     pub metadata: Option<Pubkey>,
+    /// Content written to the wallet metadata account by this instruction, if any.
+    pub wallet_metadata: Option<WalletMetadataUpdate>,
+}
+
+/// Return data set by a single instruction of an executed vault transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InstructionReturnData {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+}
+
+#[event]
+pub struct VaultTransactionEvent {
+    pub create_key: Pubkey,
+    pub vault_index: u16,
+    pub return_data: Vec<Option<InstructionReturnData>>,
+}
+
+#[event]
+pub struct EscrowVoteEvent {
+    pub create_key: Pubkey,
+    pub identifier: u64,
+    pub member: Pubkey,
+    pub approve: bool,
 }
 
 #[event]
@@ -20,5 +44,8 @@ pub struct EscrowEvent {
     pub approver: Option<Pubkey>,
     pub recipient: Recipient,
     pub new_members: Option<Vec<Member>>,
+    pub additional_legs: Vec<PaymentLeg>,
+    pub seat: Option<Pubkey>,
     pub threshold: Option<u8>,
+    pub fees: EscrowFees,
 }
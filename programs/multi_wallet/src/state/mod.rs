@@ -15,3 +15,24 @@ pub use escrow::*;
 
 pub mod transaction_buffer;
 pub use transaction_buffer::*;
+
+pub mod balance_assertion;
+pub use balance_assertion::*;
+
+pub mod auction;
+pub use auction::*;
+
+pub mod protocol_config;
+pub use protocol_config::*;
+
+pub mod asset_manifest;
+pub use asset_manifest::*;
+
+pub mod signed_offer;
+pub use signed_offer::*;
+
+pub mod escrow_receipt;
+pub use escrow_receipt::*;
+
+pub mod wallet_metadata;
+pub use wallet_metadata::*;
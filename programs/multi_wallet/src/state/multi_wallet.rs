@@ -1,6 +1,6 @@
-use crate::{error::MultisigError, id};
-use anchor_lang::solana_program::sysvar::instructions as tx_instructions;
-use anchor_lang::{prelude::*, system_program};
+use crate::{error::MultisigError, id, EscrowVote, CLIENT_ESCROW_IDENTIFIER_START, WalletMetadataUpdate, SEED_MULTISIG, SEED_VAULT};
+use anchor_lang::solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, sysvar::instructions as tx_instructions};
+use anchor_lang::{prelude::*, system_program, Discriminator};
 use std::collections::HashSet;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -10,13 +10,44 @@ pub enum ConfigAction {
     RemoveMembers(Vec<Pubkey>),
     SetThreshold(u8),
     SetMetadata(Option<Pubkey>),
+    /// Replaces the content of the `WalletMetadata` account, pointing `metadata` at it.
+    UpdateMetadata(WalletMetadataUpdate),
 }
 
 
+/// Maximum length of a member label, in bytes.
+pub const MAX_MEMBER_LABEL_LEN: usize = 32;
+
 #[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Hash)]
 pub struct Member {
     pub pubkey: Pubkey,
     pub permissions: Option<Permissions>,
+    /// Optional short label shown in place of the public key.
+    #[max_len(MAX_MEMBER_LABEL_LEN)]
+    pub label: Option<String>,
+    /// Unix timestamp at which the member was added, set by the program. Zero if unknown.
+    pub added_at: i64,
+    /// Signer that added the member, set by the program.
+    pub added_by: Option<Pubkey>,
+}
+
+/// Member layout of multi-wallets created before the account was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyMember {
+    pub pubkey: Pubkey,
+    pub permissions: Option<Permissions>,
+}
+
+impl From<LegacyMember> for Member {
+    fn from(member: LegacyMember) -> Self {
+        Member {
+            pubkey: member.pubkey,
+            permissions: member.permissions,
+            label: None,
+            added_at: 0,
+            added_by: None,
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -52,6 +83,56 @@ impl Permissions {
     }
 }
 
+/// Maximum number of escrows that can be pending on a multi-wallet at once.
+pub const MAX_PENDING_OFFERS: usize = 16;
+
+/// Sale state of a multi-wallet, derived from its listing and pending offers.
+///
+/// | Instruction                                    | Idle | BidPending | Listed |
+/// |------------------------------------------------|------|------------|--------|
+/// | vault transactions, transaction buffers        | yes  | yes        | no     |
+/// | change config                                  | yes  | yes        | no     |
+/// | initialize escrow as owner, initialize auction | yes  | no         | no     |
+/// | initialize escrow as non-owner                 | yes  | yes        | no     |
+/// | execute, cancel and counter escrows            | yes  | yes        | yes    |
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ListingState {
+    /// No listing and no pending offers.
+    Idle,
+    /// One or more non-owner bids are pending, the owners keep full control of the vaults.
+    BidPending,
+    /// The owners listed the multi-wallet for sale, the vaults are frozen until it settles.
+    Listed,
+}
+
+impl ListingState {
+    /// Returns `true` if vault transactions can be created and executed.
+    pub fn allows_vault_transaction(&self) -> bool {
+        *self != ListingState::Listed
+    }
+
+    /// Returns `true` if the members, threshold or metadata can be changed.
+    pub fn allows_config_change(&self) -> bool {
+        *self != ListingState::Listed
+    }
+
+    /// Returns `true` if the owners can list the multi-wallet for sale.
+    pub fn allows_listing(&self) -> bool {
+        *self == ListingState::Idle
+    }
+
+    /// Returns `true` if a non-owner can place a bid.
+    pub fn allows_bid(&self) -> bool {
+        *self != ListingState::Listed
+    }
+}
+
+/// Layout version of newly created and migrated multi-wallets.
+pub const MULTI_WALLET_VERSION: u8 = 1;
+
+/// Offset of the member count, shared by the legacy and the current layout.
+const MEMBER_COUNT_OFFSET: usize = 8 + 32 + 1 + 1;
+
 #[account]
 pub struct MultiWallet {
     pub create_key: Pubkey,
@@ -60,6 +141,29 @@ pub struct MultiWallet {
     pub members: Vec<Member>,
     pub pending_offers: Vec<Pubkey>,
     pub metadata: Option<Pubkey>,
+    /// Nonce given to the next transaction buffer, so that buffers re-created at a closed buffer's
+    /// address derive new ephemeral signers.
+    pub transaction_counter: u64,
+    /// Owner-created sale listing, if any. The multi-wallet is locked while it is set.
+    pub listing: Option<Pubkey>,
+    /// Next escrow identifier allocated on-chain. Always greater than any identifier in use.
+    pub escrow_counter: u64,
+    /// Number of transaction buffers of the multi-wallet that are not closed yet.
+    pub open_buffers: u16,
+    /// Layout version of the account. Accounts created before versioning are upgraded by
+    /// `migrate_multi_wallet`.
+    pub version: u8,
+}
+
+/// Layout of multi-wallets created before the account was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultiWalletV0 {
+    pub create_key: Pubkey,
+    pub threshold: u8,
+    pub bump: u8,
+    pub members: Vec<LegacyMember>,
+    pub pending_offers: Vec<Pubkey>,
+    pub metadata: Option<Pubkey>,
 }
 
  // Helper struct to track permission counts
@@ -80,11 +184,59 @@ impl MultiWallet {
         1  + // threshold
         1  + // bump
         4  + // members vector length
-        members_length * 34 + // members
+        members_length * Member::INIT_SPACE + // members
         4 + // pending offer vector length
         num_offers * 32 + 
         1 + // option
-        32 // metadata
+        32 + // metadata
+        8 + // transaction_counter
+        1 + // option
+        32 + // listing
+        8 + // escrow_counter
+        2 + // open_buffers
+        1 // version
+    }
+
+    /// Reads the account data of a multi-wallet in an older layout and upgrades it to the current version.
+    pub fn upgrade(data: &[u8]) -> Result<MultiWallet> {
+        require!(
+            data.starts_with(&MultiWallet::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(Self::is_legacy(data)?, MultisigError::MultiWalletAlreadyMigrated);
+
+        let v0 = MultiWalletV0::deserialize(&mut &data[MultiWallet::DISCRIMINATOR.len()..])?;
+        let mut multi_wallet = MultiWallet {
+            create_key: v0.create_key,
+            threshold: v0.threshold,
+            bump: v0.bump,
+            members: v0.members.into_iter().map(Member::from).collect(),
+            pending_offers: v0.pending_offers,
+            metadata: v0.metadata,
+            transaction_counter: 0,
+            listing: None,
+            escrow_counter: 0,
+            open_buffers: 0,
+            version: MULTI_WALLET_VERSION,
+        };
+        multi_wallet.sort_members();
+        Ok(multi_wallet)
+    }
+
+    /// Returns `true` if `data` holds a multi-wallet created before the account was versioned. Both
+    /// layouts store the member count at the same offset, and a versioned account is always allocated
+    /// for at least its members in the current layout, so a shorter account is a legacy one. Legacy
+    /// accounts only reach that length when grown for many more members or offers than they hold, and
+    /// those do not read in the current layout.
+    pub fn is_legacy(data: &[u8]) -> Result<bool> {
+        let member_count = data
+            .get(MEMBER_COUNT_OFFSET..MEMBER_COUNT_OFFSET + 4)
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        let member_count = u32::from_le_bytes(member_count.try_into().unwrap()) as usize;
+        if data.len() < Self::size(member_count, 0) {
+            return Ok(true);
+        }
+        Ok(MultiWallet::try_deserialize(&mut &data[..]).is_err())
     }
 
     pub fn durable_nonce_check(instruction_sysvar: &AccountInfo) -> Result<()> {
@@ -97,20 +249,34 @@ impl MultiWallet {
     }
 
 
+    /// Returns the members signing among `all_accounts`, sorted by public key and without duplicates.
     pub fn get_unique_signers(
         &self,
         all_accounts: &[AccountInfo],
-    ) -> Result<HashSet<&Member>> {
-       
-        let unique_signers: HashSet<_> = self.members
+    ) -> Result<Vec<&Member>> {
+        let mut unique_signers: Vec<&Member> = all_accounts
             .iter()
-            .filter(|member| {
-                all_accounts.iter().any(|x| x.is_signer && x.key().eq(&member.pubkey))
-            })
+            .filter(|x| x.is_signer)
+            .filter_map(|x| self.find_member(x.key))
             .collect();
+        unique_signers.sort_unstable_by_key(|x| x.pubkey);
+        unique_signers.dedup_by_key(|x| x.pubkey);
         Ok(unique_signers)
     }
 
+    /// Returns the member with `pubkey`, looked up by binary search over the sorted members.
+    pub fn find_member(&self, pubkey: &Pubkey) -> Option<&Member> {
+        self.members
+            .binary_search_by(|x| x.pubkey.cmp(pubkey))
+            .ok()
+            .map(|i| &self.members[i])
+    }
+
+    /// Sorts the members by public key, as `find_member` expects.
+    pub fn sort_members(&mut self) {
+        self.members.sort_unstable_by_key(|x| x.pubkey);
+    }
+
     /// Returns `true` if the account was reallocated.
     pub fn realloc_if_needed<'a>(
         multi_wallet: AccountInfo<'a>,
@@ -126,8 +292,29 @@ impl MultiWallet {
             MultisigError::IllegalAccountOwner
         );
 
-        let current_account_size = multi_wallet.data.borrow().len();
-        let new_account_size = Self::size(members_length, num_offers);
+        Self::realloc_to_size(
+            multi_wallet,
+            Self::size(members_length, num_offers),
+            rent_payer,
+            system_program,
+        )
+    }
+
+    /// Grows the account to `new_account_size` bytes if it is smaller, topping up its rent from `rent_payer`.
+    pub fn realloc_to_size<'a>(
+        account: AccountInfo<'a>,
+        new_account_size: usize,
+        rent_payer: Option<AccountInfo<'a>>,
+        system_program: Option<AccountInfo<'a>>,
+    ) -> Result<bool> {
+        // Sanity checks
+        require_keys_eq!(
+            *account.owner,
+            id(),
+            MultisigError::IllegalAccountOwner
+        );
+
+        let current_account_size = account.data.borrow().len();
 
         // Check if we need to reallocate space.
         if current_account_size >= new_account_size {
@@ -135,7 +322,7 @@ impl MultiWallet {
         }
 
         // Reallocate more space.
-        AccountInfo::realloc(&multi_wallet, new_account_size, false)?;
+        AccountInfo::realloc(&account, new_account_size, false)?;
 
         // If more lamports are needed, transfer them to the account.
         let rent_exempt_lamports = Rent::get()
@@ -143,7 +330,7 @@ impl MultiWallet {
             .minimum_balance(new_account_size)
             .max(1);
         let top_up_lamports =
-            rent_exempt_lamports.saturating_sub(multi_wallet.to_account_info().lamports());
+            rent_exempt_lamports.saturating_sub(account.to_account_info().lamports());
 
         if top_up_lamports > 0 {
             let system_program = system_program.ok_or(MultisigError::MissingAccount)?;
@@ -160,7 +347,7 @@ impl MultiWallet {
                     system_program,
                     system_program::Transfer {
                         from: rent_payer,
-                        to: multi_wallet,
+                        to: account,
                     },
                 ),
                 top_up_lamports,
@@ -170,6 +357,56 @@ impl MultiWallet {
         Ok(true)
     }
 
+    /// Size to grow an account of `current_size` bytes to on the way to `target_size`. An account can
+    /// grow by at most `MAX_PERMITTED_DATA_INCREASE` bytes per instruction.
+    pub fn growth_step(current_size: usize, target_size: usize) -> usize {
+        target_size.min(current_size.saturating_add(MAX_PERMITTED_DATA_INCREASE))
+    }
+
+    /// Returns `true` if the account was shrunk to the size of its current members and offers.
+    /// The rent freed by shrinking is refunded to `rent_collector`, which must be a signing member
+    /// or the default vault of the multi-wallet. Nothing is done without a rent collector.
+    pub fn shrink_if_needed<'a>(
+        multi_wallet: &Account<'a, MultiWallet>,
+        rent_collector: Option<AccountInfo<'a>>,
+    ) -> Result<bool> {
+        let Some(rent_collector) = rent_collector else {
+            return Ok(false);
+        };
+        let multi_wallet_key = multi_wallet.key();
+        let (default_vault, _) = Pubkey::find_program_address(
+            &[SEED_MULTISIG, multi_wallet_key.as_ref(), SEED_VAULT, 0u16.to_le_bytes().as_ref()],
+            &id(),
+        );
+        require!(
+            rent_collector.key() == default_vault
+                || (rent_collector.is_signer
+                    && multi_wallet.find_member(rent_collector.key).is_some()),
+            MultisigError::InvalidAccount
+        );
+
+        let new_account_size = Self::size(multi_wallet.members.len(), multi_wallet.pending_offers.len());
+        let multi_wallet = multi_wallet.to_account_info();
+        let current_account_size = multi_wallet.data.borrow().len();
+
+        // Check if the account can be shrunk.
+        if current_account_size <= new_account_size {
+            return Ok(false);
+        }
+
+        AccountInfo::realloc(&multi_wallet, new_account_size, false)?;
+
+        // Refund the lamports above the rent exemption of the new size.
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(new_account_size).max(1);
+        let surplus_lamports = multi_wallet.lamports().saturating_sub(rent_exempt_lamports);
+        if surplus_lamports > 0 {
+            **multi_wallet.try_borrow_mut_lamports()? -= surplus_lamports;
+            **rent_collector.try_borrow_mut_lamports()? += surplus_lamports;
+        }
+
+        Ok(true)
+    }
+
     // Makes sure the multisig state is valid.
     // This must be called at the end of every instruction that modifies a Multisig account.
     pub fn check_state_validity(threshold: &u8, members: &Vec<Member>) -> Result<()> {
@@ -180,14 +417,21 @@ impl MultiWallet {
         require!(*threshold > 0, MultisigError::InvalidThreshold);
         require!(*threshold as usize <= member_count, MultisigError::InvalidThreshold);
 
-        let mut seen = std::collections::HashSet::new();
+        // Check for duplicate public keys
+        let mut pubkeys: Vec<&Pubkey> = members.iter().map(|x| &x.pubkey).collect();
+        pubkeys.sort_unstable();
+        require!(
+            pubkeys.windows(2).all(|x| x[0] != x[1]),
+            MultisigError::DuplicateMember
+        );
+
         let mut permission_counts = PermissionCounts::default();
 
         for member in members {
-            // Check for duplicate public keys
-            if !seen.insert(&member.pubkey) {
-                return Err(MultisigError::DuplicateMember.into());
-            }
+            require!(
+                member.label.as_ref().map_or(0, String::len) <= MAX_MEMBER_LABEL_LEN,
+                MultisigError::MemberLabelTooLong
+            );
 
             // Count permissions
             if let Some(permissions) = &member.permissions {
@@ -249,9 +493,14 @@ impl MultiWallet {
         Ok(())
     }
     
-    /// Add `new_member` to the multisig `members` vec and sort the vec.
-    pub fn add_members(&mut self, new_members: Vec<Member>) {
-        self.members.extend(new_members);
+    /// Adds `new_members`, recorded as added by `added_by` at `added_at`.
+    pub fn add_members(&mut self, new_members: Vec<Member>, added_by: Pubkey, added_at: i64) {
+        self.members.extend(new_members.into_iter().map(|x| Member {
+            added_at,
+            added_by: Some(added_by),
+            ..x
+        }));
+        self.sort_members();
     }
 
     /// Remove `member_pubkeys` from the multisig `members` vec.
@@ -260,8 +509,43 @@ impl MultiWallet {
         self.members.retain(|x| !set.contains(&x.pubkey));
     }
 
-    pub fn set_members(&mut self, new_members: Vec<Member>) {
-        self.members = new_members;
+    /// Hands the seat of `seller` to `buyer`, keeping the seller's permissions. The buyer is
+    /// recorded as adding itself at `added_at`.
+    pub fn transfer_seat(&mut self, seller: Pubkey, buyer: Pubkey, added_at: i64) -> Result<()> {
+        require!(self.find_member(&buyer).is_none(), MultisigError::InvalidSeat);
+        let member = self
+            .members
+            .iter_mut()
+            .find(|x| x.pubkey == seller)
+            .ok_or(MultisigError::InvalidSeat)?;
+        member.pubkey = buyer;
+        member.label = None;
+        member.added_at = added_at;
+        member.added_by = Some(buyer);
+        self.sort_members();
+        Ok(())
+    }
+
+    /// Replaces the members. Members that stay keep when and by whom they were added, the others
+    /// are recorded as added by `added_by` at `added_at`.
+    pub fn set_members(&mut self, new_members: Vec<Member>, added_by: Pubkey, added_at: i64) {
+        let members = new_members
+            .into_iter()
+            .map(|x| match self.find_member(&x.pubkey) {
+                Some(member) => Member {
+                    added_at: member.added_at,
+                    added_by: member.added_by,
+                    ..x
+                },
+                None => Member {
+                    added_at,
+                    added_by: Some(added_by),
+                    ..x
+                },
+            })
+            .collect();
+        self.members = members;
+        self.sort_members();
     }
 
     /// Sets the threshold of an existing multi-wallet.
@@ -274,16 +558,558 @@ impl MultiWallet {
         self.metadata = metadata;
     }
 
-    pub fn add_offer(&mut self, offer: Pubkey) {
+    /// Number of members with `Permission::VoteEscrow`, for whom escrows reserve room to record votes.
+    pub fn escrow_voter_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|x| x.permissions.is_some_and(|x| x.has(Permission::VoteEscrow)))
+            .count()
+    }
+
+    /// Counts the members with `Permission::VoteEscrow` that either sign or recorded a vote
+    /// matching `approve` on the escrow.
+    pub fn count_escrow_votes(
+        &self,
+        unique_signers: &[&Member],
+        votes: &[EscrowVote],
+        approve: bool,
+    ) -> usize {
+        self.members
+            .iter()
+            .filter(|member| {
+                member
+                    .permissions
+                    .is_some_and(|x| x.has(Permission::VoteEscrow))
+                    && (unique_signers
+                        .binary_search_by(|x| x.pubkey.cmp(&member.pubkey))
+                        .is_ok()
+                        || votes
+                            .iter()
+                            .any(|x| x.member == member.pubkey && x.approve == approve))
+            })
+            .count()
+    }
+
+    pub fn add_offer(&mut self, offer: Pubkey) -> Result<()> {
+        require!(
+            self.pending_offers.len() < MAX_PENDING_OFFERS,
+            MultisigError::TooManyPendingOffers
+        );
         self.pending_offers.push(offer);
+        Ok(())
     }
 
-    pub fn remove_offer(&mut self, offer:Pubkey) {
+    /// Removes `offer` from the pending offers, releasing the lock if it is the active listing.
+    pub fn remove_offer(&mut self, offer: Pubkey) {
         self.pending_offers.retain(|x| !x.eq(&offer));
+        if self.listing == Some(offer) {
+            self.listing = None;
+        }
+    }
+
+    /// Adds a non-owner bid.
+    pub fn add_bid(&mut self, bid: Pubkey) -> Result<()> {
+        require!(
+            self.listing_state().allows_bid(),
+            MultisigError::MultisigIsCurrentlyLocked
+        );
+        self.add_offer(bid)
+    }
+
+    /// Adds an owner-created listing, locking the multi-wallet until it is executed or cancelled.
+    pub fn add_listing(&mut self, listing: Pubkey) -> Result<()> {
+        match self.listing_state() {
+            ListingState::Idle => {}
+            ListingState::BidPending => return err!(MultisigError::PendingOffersExist),
+            ListingState::Listed => return err!(MultisigError::MultisigIsCurrentlyLocked),
+        }
+        self.add_offer(listing)?;
+        self.listing = Some(listing);
+        Ok(())
+    }
+
+    /// Settles `offer`, dropping it and any active listing from the pending offers.
+    pub fn settle_offer(&mut self, offer: Pubkey) {
+        self.remove_offer(offer);
+        if let Some(listing) = self.listing {
+            self.remove_offer(listing);
+        }
+    }
+
+    /// Fails unless `offer` is one of the pending offers of the multi-wallet.
+    pub fn require_pending_offer(&self, offer: &Pubkey) -> Result<()> {
+        require!(
+            self.pending_offers.contains(offer),
+            MultisigError::EscrowDoesNotExist
+        );
+        Ok(())
+    }
+
+    /// Allocates the next escrow identifier from the escrow counter. Identifiers allocated on-chain stay
+    /// below `CLIENT_ESCROW_IDENTIFIER_START`, so they never collide with client-chosen ones.
+    pub fn allocate_escrow_identifier(&mut self) -> Result<u64> {
+        let identifier = self.escrow_counter;
+        require!(
+            identifier < CLIENT_ESCROW_IDENTIFIER_START,
+            MultisigError::InvalidEscrowIdentifier
+        );
+        self.escrow_counter = identifier
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidEscrowIdentifier)?;
+        Ok(identifier)
+    }
+
+    /// Checks that a client-chosen escrow identifier or offer nonce lies in the range reserved for them.
+    pub fn validate_client_escrow_identifier(identifier: u64) -> Result<()> {
+        require!(
+            (CLIENT_ESCROW_IDENTIFIER_START..u64::MAX).contains(&identifier),
+            MultisigError::InvalidEscrowIdentifier
+        );
+        Ok(())
+    }
+
+    /// Records that a transaction buffer of the multi-wallet was created.
+    pub fn open_buffer(&mut self) -> Result<()> {
+        self.open_buffers = self
+            .open_buffers
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyOpenBuffers)?;
+        Ok(())
     }
 
-    pub fn clear_pending_offers<'info>(&mut self) {
-     self.pending_offers.clear();
+    /// Records that a transaction buffer of the multi-wallet was closed.
+    pub fn close_buffer(&mut self) {
+        self.open_buffers = self.open_buffers.saturating_sub(1);
+    }
+
+    pub fn listing_state(&self) -> ListingState {
+        if self.listing.is_some() {
+            ListingState::Listed
+        } else if !self.pending_offers.is_empty() {
+            ListingState::BidPending
+        } else {
+            ListingState::Idle
+        }
+    }
+
+    /// Returns the nonce for a new transaction buffer and advances the counter.
+    pub fn next_transaction_nonce(&mut self) -> Result<u64> {
+        let nonce = self.transaction_counter;
+        self.transaction_counter = nonce
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(nonce)
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_members() -> Vec<LegacyMember> {
+        vec![
+            LegacyMember {
+                pubkey: Pubkey::new_unique(),
+                permissions: Some(Permissions { mask: 7 }),
+            },
+            LegacyMember {
+                pubkey: Pubkey::new_unique(),
+                permissions: None,
+            },
+        ]
+    }
+
+    fn v0_snapshot(metadata: Option<Pubkey>) -> (MultiWalletV0, Vec<u8>) {
+        let v0 = MultiWalletV0 {
+            create_key: Pubkey::new_unique(),
+            threshold: 2,
+            bump: 254,
+            members: legacy_members(),
+            pending_offers: vec![Pubkey::new_unique()],
+            metadata,
+        };
+        // v0 accounts were allocated for their members and offers, with room for the metadata.
+        let v0_size = 8 + 32 + 1 + 1 + 4 + 2 * 34 + 4 + 32 + 1 + 32;
+        let mut data = MultiWallet::DISCRIMINATOR.to_vec();
+        data.extend(v0.try_to_vec().unwrap());
+        data.resize(v0_size, 0);
+        (v0, data)
+    }
+
+    fn assert_upgraded_members(members: &[Member], legacy_members: &[LegacyMember]) {
+        assert_eq!(members.len(), legacy_members.len());
+        assert!(members.windows(2).all(|x| x[0].pubkey < x[1].pubkey));
+        for legacy_member in legacy_members {
+            let member = members.iter().find(|x| x.pubkey == legacy_member.pubkey).unwrap();
+            assert_eq!(member.permissions, legacy_member.permissions);
+            assert_eq!(member.label, None);
+            assert_eq!(member.added_at, 0);
+            assert_eq!(member.added_by, None);
+        }
+    }
+
+    fn test_multi_wallet() -> MultiWallet {
+        MultiWallet {
+            create_key: Pubkey::new_unique(),
+            threshold: 1,
+            bump: 253,
+            members: Vec::new(),
+            pending_offers: Vec::new(),
+            metadata: None,
+            transaction_counter: 0,
+            listing: None,
+            escrow_counter: 0,
+            open_buffers: 0,
+            version: MULTI_WALLET_VERSION,
+        }
+    }
+
+    #[test]
+    fn upgrades_v0_snapshots() {
+        for metadata in [None, Some(Pubkey::new_unique())] {
+            let (v0, data) = v0_snapshot(metadata);
+            let multi_wallet = MultiWallet::upgrade(&data).unwrap();
+
+            assert_eq!(multi_wallet.create_key, v0.create_key);
+            assert_eq!(multi_wallet.threshold, v0.threshold);
+            assert_eq!(multi_wallet.bump, v0.bump);
+            assert_upgraded_members(&multi_wallet.members, &v0.members);
+            assert_eq!(multi_wallet.pending_offers, v0.pending_offers);
+            assert_eq!(multi_wallet.metadata, v0.metadata);
+            assert_eq!(multi_wallet.transaction_counter, 0);
+            assert_eq!(multi_wallet.listing, None);
+            assert_eq!(multi_wallet.escrow_counter, 0);
+            assert_eq!(multi_wallet.open_buffers, 0);
+            assert_eq!(multi_wallet.version, MULTI_WALLET_VERSION);
+
+            let mut migrated = Vec::new();
+            multi_wallet.try_serialize(&mut migrated).unwrap();
+            assert!(migrated.len() <= MultiWallet::size(v0.members.len(), v0.pending_offers.len()));
+            let reloaded = MultiWallet::try_deserialize(&mut migrated.as_slice()).unwrap();
+            assert_eq!(reloaded.version, MULTI_WALLET_VERSION);
+            assert_eq!(reloaded.metadata, v0.metadata);
+        }
+    }
+
+    #[test]
+    fn detects_legacy_accounts() {
+        let (v0, data) = v0_snapshot(None);
+        assert!(MultiWallet::is_legacy(&data).unwrap());
+
+        let mut migrated = Vec::new();
+        MultiWallet::upgrade(&data).unwrap().try_serialize(&mut migrated).unwrap();
+        migrated.resize(MultiWallet::size(v0.members.len(), 0), 0);
+        assert!(!MultiWallet::is_legacy(&migrated).unwrap());
+
+        // A legacy account grown for more members than it holds, with keys that do not read as
+        // current members.
+        let v0 = MultiWalletV0 {
+            members: vec![LegacyMember {
+                pubkey: Pubkey::new_from_array([u8::MAX; 32]),
+                permissions: None,
+            }; 2],
+            ..v0
+        };
+        let mut data = MultiWallet::DISCRIMINATOR.to_vec();
+        data.extend(v0.try_to_vec().unwrap());
+        data.resize(MultiWallet::size(v0.members.len(), 0) + 64, 0);
+        assert!(MultiWallet::is_legacy(&data).unwrap());
+    }
+
+    #[test]
+    fn records_who_added_members() {
+        let (_, data) = v0_snapshot(None);
+        let mut multi_wallet = MultiWallet::upgrade(&data).unwrap();
+        let kept = multi_wallet.members[0].clone();
+        let added_by = Pubkey::new_unique();
+        let added = Member {
+            pubkey: Pubkey::new_unique(),
+            permissions: None,
+            label: Some("treasury".to_string()),
+            added_at: 0,
+            added_by: None,
+        };
+
+        multi_wallet.set_members(vec![kept.clone(), added.clone()], added_by, 1_700_000_000);
+
+        let member = multi_wallet.find_member(&kept.pubkey).unwrap();
+        assert_eq!(member.added_at, kept.added_at);
+        assert_eq!(member.added_by, kept.added_by);
+        let member = multi_wallet.find_member(&added.pubkey).unwrap();
+        assert_eq!(member.label, added.label);
+        assert_eq!(member.added_at, 1_700_000_000);
+        assert_eq!(member.added_by, Some(added_by));
+    }
+
+    #[test]
+    fn finds_members_of_large_sets() {
+        for count in [10, 100, 1000] {
+            let (_, data) = v0_snapshot(None);
+            let mut multi_wallet = MultiWallet::upgrade(&data).unwrap();
+            multi_wallet.set_members(
+                (0..count)
+                    .map(|_| Member {
+                        pubkey: Pubkey::new_unique(),
+                        permissions: None,
+                        label: None,
+                        added_at: 0,
+                        added_by: None,
+                    })
+                    .collect(),
+                Pubkey::new_unique(),
+                0,
+            );
+
+            for member in multi_wallet.members.clone() {
+                assert!(multi_wallet.find_member(&member.pubkey) == Some(&member));
+            }
+            assert!(multi_wallet.find_member(&Pubkey::new_unique()).is_none());
+        }
+    }
+
+    #[test]
+    fn rejects_migrated_accounts() {
+        let (v0, data) = v0_snapshot(None);
+        let mut migrated = Vec::new();
+        MultiWallet::upgrade(&data).unwrap().try_serialize(&mut migrated).unwrap();
+        migrated.resize(MultiWallet::size(v0.members.len(), v0.pending_offers.len()), 0);
+
+        assert!(matches!(
+            MultiWallet::upgrade(&migrated),
+            Err(err) if err == MultisigError::MultiWalletAlreadyMigrated.into()
+        ));
+    }
+
+    #[test]
+    fn hands_out_buffer_nonces() {
+        let mut multi_wallet = test_multi_wallet();
+
+        assert_eq!(multi_wallet.next_transaction_nonce().unwrap(), 0);
+        assert_eq!(multi_wallet.next_transaction_nonce().unwrap(), 1);
+        assert_eq!(multi_wallet.transaction_counter, 2);
+
+        let buffer = Pubkey::new_unique();
+        let (first, _) = crate::derive_ephemeral_signers(buffer, 0, 2);
+        let (second, _) = crate::derive_ephemeral_signers(buffer, 1, 2);
+        assert!(first.iter().all(|x| !second.contains(x)));
+    }
+
+    #[test]
+    fn listing_states_allow_instructions() {
+        let cases = [
+            (ListingState::Idle, true, true, true, true),
+            (ListingState::BidPending, true, true, false, true),
+            (ListingState::Listed, false, false, false, false),
+        ];
+        for (state, vault_transaction, config_change, listing, bid) in cases {
+            assert_eq!(state.allows_vault_transaction(), vault_transaction);
+            assert_eq!(state.allows_config_change(), config_change);
+            assert_eq!(state.allows_listing(), listing);
+            assert_eq!(state.allows_bid(), bid);
+        }
+    }
+
+    #[test]
+    fn bids_and_listings_move_the_listing_state() {
+        let mut multi_wallet = test_multi_wallet();
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+
+        let bid = Pubkey::new_unique();
+        multi_wallet.add_bid(bid).unwrap();
+        assert_eq!(multi_wallet.listing_state(), ListingState::BidPending);
+        assert!(matches!(
+            multi_wallet.add_listing(Pubkey::new_unique()),
+            Err(err) if err == MultisigError::PendingOffersExist.into()
+        ));
+
+        multi_wallet.remove_offer(bid);
+        let listing = Pubkey::new_unique();
+        multi_wallet.add_listing(listing).unwrap();
+        assert_eq!(multi_wallet.listing_state(), ListingState::Listed);
+        assert!(matches!(
+            multi_wallet.add_bid(Pubkey::new_unique()),
+            Err(err) if err == MultisigError::MultisigIsCurrentlyLocked.into()
+        ));
+        assert!(matches!(
+            multi_wallet.add_listing(Pubkey::new_unique()),
+            Err(err) if err == MultisigError::MultisigIsCurrentlyLocked.into()
+        ));
+
+        multi_wallet.remove_offer(listing);
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+    }
+
+    fn multi_wallet_in(state: ListingState) -> (MultiWallet, Option<Pubkey>) {
+        let mut multi_wallet = test_multi_wallet();
+        let offer = Pubkey::new_unique();
+        let offer = match state {
+            ListingState::Idle => None,
+            ListingState::BidPending => {
+                multi_wallet.add_bid(offer).unwrap();
+                Some(offer)
+            }
+            ListingState::Listed => {
+                multi_wallet.add_listing(offer).unwrap();
+                Some(offer)
+            }
+        };
+        assert_eq!(multi_wallet.listing_state(), state);
+        (multi_wallet, offer)
+    }
+
+    fn assert_fails_with(result: Result<()>, error: MultisigError) {
+        let error: Error = error.into();
+        assert!(matches!(result, Err(err) if err == error));
+    }
+
+    #[test]
+    fn initialize_escrow_as_owner_in_each_listing_state() {
+        let (mut multi_wallet, _) = multi_wallet_in(ListingState::Idle);
+        let listing = Pubkey::new_unique();
+        multi_wallet.add_listing(listing).unwrap();
+        assert_eq!(multi_wallet.listing_state(), ListingState::Listed);
+        assert_eq!(multi_wallet.listing, Some(listing));
+
+        for (state, error) in [
+            (ListingState::BidPending, MultisigError::PendingOffersExist),
+            (ListingState::Listed, MultisigError::MultisigIsCurrentlyLocked),
+        ] {
+            let (mut multi_wallet, _) = multi_wallet_in(state);
+            let listing = Pubkey::new_unique();
+            assert_fails_with(multi_wallet.add_listing(listing), error);
+            assert_eq!(multi_wallet.listing_state(), state);
+            assert!(!multi_wallet.pending_offers.contains(&listing));
+        }
+    }
+
+    #[test]
+    fn initialize_escrow_as_non_owner_in_each_listing_state() {
+        for state in [ListingState::Idle, ListingState::BidPending] {
+            let (mut multi_wallet, _) = multi_wallet_in(state);
+            let bid = Pubkey::new_unique();
+            multi_wallet.add_bid(bid).unwrap();
+            assert_eq!(multi_wallet.listing_state(), ListingState::BidPending);
+            assert!(multi_wallet.pending_offers.contains(&bid));
+            assert_eq!(multi_wallet.listing, None);
+        }
+
+        let (mut multi_wallet, listing) = multi_wallet_in(ListingState::Listed);
+        let bid = Pubkey::new_unique();
+        assert_fails_with(multi_wallet.add_bid(bid), MultisigError::MultisigIsCurrentlyLocked);
+        assert_eq!(multi_wallet.listing_state(), ListingState::Listed);
+        assert_eq!(multi_wallet.pending_offers, vec![listing.unwrap()]);
+    }
+
+    #[test]
+    fn execute_escrow_as_owner_in_each_listing_state() {
+        // Nothing is pending to execute.
+        let (multi_wallet, _) = multi_wallet_in(ListingState::Idle);
+        assert_fails_with(
+            multi_wallet.require_pending_offer(&Pubkey::new_unique()),
+            MultisigError::EscrowDoesNotExist,
+        );
+
+        // Executing a bid keeps the other bids pending.
+        let (mut multi_wallet, bid) = multi_wallet_in(ListingState::BidPending);
+        let other_bid = Pubkey::new_unique();
+        multi_wallet.add_bid(other_bid).unwrap();
+        multi_wallet.require_pending_offer(&other_bid).unwrap();
+        multi_wallet.settle_offer(other_bid);
+        assert_eq!(multi_wallet.listing_state(), ListingState::BidPending);
+        multi_wallet.require_pending_offer(&bid.unwrap()).unwrap();
+        multi_wallet.settle_offer(bid.unwrap());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+
+        // A listing whose counter offer was accepted is executed by the owners.
+        let (mut multi_wallet, listing) = multi_wallet_in(ListingState::Listed);
+        multi_wallet.require_pending_offer(&listing.unwrap()).unwrap();
+        multi_wallet.settle_offer(listing.unwrap());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+        assert_eq!(multi_wallet.listing, None);
+    }
+
+    #[test]
+    fn execute_escrow_as_non_owner_in_each_listing_state() {
+        let (multi_wallet, _) = multi_wallet_in(ListingState::Idle);
+        assert_fails_with(
+            multi_wallet.require_pending_offer(&Pubkey::new_unique()),
+            MultisigError::EscrowDoesNotExist,
+        );
+
+        // Only listings are executed by the buyer, a pending bid is not one.
+        let (multi_wallet, _) = multi_wallet_in(ListingState::BidPending);
+        assert_fails_with(
+            multi_wallet.require_pending_offer(&Pubkey::new_unique()),
+            MultisigError::EscrowDoesNotExist,
+        );
+
+        let (mut multi_wallet, listing) = multi_wallet_in(ListingState::Listed);
+        multi_wallet.require_pending_offer(&listing.unwrap()).unwrap();
+        multi_wallet.settle_offer(listing.unwrap());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+        assert!(multi_wallet.pending_offers.is_empty());
+    }
+
+    #[test]
+    fn cancel_escrow_in_each_listing_state() {
+        // Cancelling an escrow that is not pending leaves the state as is.
+        let (mut multi_wallet, _) = multi_wallet_in(ListingState::Idle);
+        multi_wallet.remove_offer(Pubkey::new_unique());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+
+        let (mut multi_wallet, bid) = multi_wallet_in(ListingState::BidPending);
+        let other_bid = Pubkey::new_unique();
+        multi_wallet.add_bid(other_bid).unwrap();
+        multi_wallet.remove_offer(other_bid);
+        assert_eq!(multi_wallet.listing_state(), ListingState::BidPending);
+        multi_wallet.remove_offer(bid.unwrap());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+
+        let (mut multi_wallet, listing) = multi_wallet_in(ListingState::Listed);
+        multi_wallet.remove_offer(Pubkey::new_unique());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Listed);
+        multi_wallet.remove_offer(listing.unwrap());
+        assert_eq!(multi_wallet.listing_state(), ListingState::Idle);
+        assert_eq!(multi_wallet.listing, None);
+    }
+
+    #[test]
+    fn allocates_escrow_identifiers_below_client_range() {
+        let mut multi_wallet = test_multi_wallet();
+
+        assert_eq!(multi_wallet.allocate_escrow_identifier().unwrap(), 0);
+        assert_eq!(multi_wallet.allocate_escrow_identifier().unwrap(), 1);
+        assert_eq!(multi_wallet.escrow_counter, 2);
+
+        multi_wallet.escrow_counter = CLIENT_ESCROW_IDENTIFIER_START;
+        assert!(matches!(
+            multi_wallet.allocate_escrow_identifier(),
+            Err(err) if err == MultisigError::InvalidEscrowIdentifier.into()
+        ));
+        assert_eq!(multi_wallet.escrow_counter, CLIENT_ESCROW_IDENTIFIER_START);
+    }
+
+    #[test]
+    fn restricts_client_escrow_identifiers() {
+        assert!(MultiWallet::validate_client_escrow_identifier(CLIENT_ESCROW_IDENTIFIER_START).is_ok());
+        assert!(MultiWallet::validate_client_escrow_identifier(u64::MAX - 1).is_ok());
+        assert!(MultiWallet::validate_client_escrow_identifier(CLIENT_ESCROW_IDENTIFIER_START - 1).is_err());
+        assert!(MultiWallet::validate_client_escrow_identifier(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn grows_large_multi_wallets_in_steps() {
+        let target_size = MultiWallet::size(1000, 0);
+        let mut size = MultiWallet::size(2, 0);
+        let mut steps = 0;
+        while size < target_size {
+            let next = MultiWallet::growth_step(size, target_size);
+            assert!(next - size <= MAX_PERMITTED_DATA_INCREASE);
+            size = next;
+            steps += 1;
+        }
+        assert_eq!(size, target_size);
+        assert_eq!(steps, target_size.div_ceil(MAX_PERMITTED_DATA_INCREASE));
+        assert_eq!(MultiWallet::growth_step(target_size, target_size), target_size);
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{id, MultisigError};
+
+/// Maximum fee in basis points.
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+#[account]
+pub struct ProtocolConfig {
+    /// Authority allowed to update the protocol config.
+    pub authority: Pubkey,
+    /// Account receiving the protocol fee on escrow settlements.
+    pub fee_recipient: Pubkey,
+    /// Protocol fee in basis points of the settled amount.
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub fn size() -> usize {
+        8  + // anchor account discriminator
+        32 + // authority
+        32 + // fee_recipient
+        2  + // fee_bps
+        1 // bump
+    }
+
+    /// Loads the protocol config, returning `None` if it has not been initialized.
+    pub fn load(protocol_config: &AccountInfo) -> Result<Option<Self>> {
+        if protocol_config.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(
+            *protocol_config.owner,
+            id(),
+            MultisigError::IllegalAccountOwner
+        );
+        Ok(Some(Self::try_deserialize(
+            &mut &protocol_config.data.borrow()[..],
+        )?))
+    }
+
+    pub fn validate_fee_bps(fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, MultisigError::InvalidFeeBasisPoints);
+        Ok(())
+    }
+}
@@ -2,3 +2,8 @@ pub const SEED_MULTISIG: &[u8] = b"multi_wallet";
 pub const SEED_VAULT: &[u8] = b"vault";
 pub const SEED_ESCROW: &[u8] = b"escrow";
 pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
+pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
+pub const SEED_PROTOCOL_CONFIG: &[u8] = b"protocol_config";
+pub const SEED_ESCROW_RECEIPT: &[u8] = b"escrow_receipt";
+pub const SEED_WALLET_METADATA: &[u8] = b"wallet_metadata";
+pub const SEED_SIGNED_OFFER: &[u8] = b"signed_offer";
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::MultisigError;
+
+use super::{MarketplaceFee, Member};
+
+/// Prefix of the message a buyer signs, so that offer signatures cannot be replayed as other messages.
+pub const SIGNED_OFFER_DOMAIN: &[u8] = b"multi_wallet:signed_offer";
+
+/// Offer signed off-chain by a buyer, accepted by the owners through `accept_signed_offer`.
+///
+/// The buyer approves the escrow vault PDA of `nonce` as delegate of `amount` on their token account,
+/// which the program spends from when the owners accept the offer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SignedOffer {
+    pub multi_wallet: Pubkey,
+    /// Identifies the offer and its escrow vault. Must be at least `CLIENT_ESCROW_IDENTIFIER_START`.
+    pub nonce: u64,
+    pub buyer: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub new_members: Vec<Member>,
+    pub threshold: u8,
+    pub expires_at: i64,
+    pub marketplace_fee: Option<MarketplaceFee>,
+}
+
+impl SignedOffer {
+    /// Returns the message signed by the buyer.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        Ok([SIGNED_OFFER_DOMAIN, &self.try_to_vec()?].concat())
+    }
+}
+
+/// Marks the nonce of an accepted signed offer as used, so that the offer cannot be accepted twice.
+///
+/// Unlike escrow receipts, nonce accounts are never closed.
+#[account]
+pub struct SignedOfferNonce {
+    pub create_key: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl SignedOfferNonce {
+    pub const SIZE: usize = 8 + // anchor account discriminator
+        32 + // create_key
+        8 + // nonce
+        1; // bump
+
+    /// Checks that the nonce account of an offer has not been created yet.
+    pub fn check_unused(account: &AccountInfo) -> Result<()> {
+        require!(
+            account.data_is_empty() && account.owner.eq(&System::id()),
+            MultisigError::SignedOfferAlreadyAccepted
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id;
+
+    fn account_info<'a>(
+        key: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+        owner: &'a Pubkey,
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+    }
+
+    #[test]
+    fn unused_nonce_passes() {
+        let key = Pubkey::new_unique();
+        let owner = System::id();
+        let mut lamports = 0;
+        let mut data = [];
+        let account = account_info(&key, &mut lamports, &mut data, &owner);
+        assert!(SignedOfferNonce::check_unused(&account).is_ok());
+    }
+
+    #[test]
+    fn second_acceptance_fails() {
+        // After the first acceptance the nonce account is allocated and owned by the program.
+        let key = Pubkey::new_unique();
+        let owner = id();
+        let mut lamports = 1_000_000;
+        let mut data = [0u8; SignedOfferNonce::SIZE];
+        let account = account_info(&key, &mut lamports, &mut data, &owner);
+        assert_eq!(
+            SignedOfferNonce::check_unused(&account).unwrap_err(),
+            MultisigError::SignedOfferAlreadyAccepted.into()
+        );
+    }
+}
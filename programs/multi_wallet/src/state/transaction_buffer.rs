@@ -1,12 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::Discriminator;
 
-use crate::MultisigError;
+use crate::{BalanceAssertion, MultisigError};
 
 // Maximum PDA allocation size in an inner ix is 10240 bytes.
 // 10240 - account contents = 10128 bytes
 pub const MAX_BUFFER_SIZE: usize = 10128;
 
+// Maximum size of the whole TransactionBuffer account, buffer and assertions included.
+pub const MAX_TRANSACTION_BUFFER_ACCOUNT_SIZE: usize = 10240;
+
+/// Maximum number of ephemeral signers a transaction buffer can declare.
+pub const MAX_EPHEMERAL_SIGNERS: u8 = 8;
+
+/// Maximum number of balance assertions on a transaction buffer.
+pub const MAX_BALANCE_ASSERTIONS: usize = 16;
+
+/// Layout version of newly created and migrated transaction buffers.
+pub const TRANSACTION_BUFFER_VERSION: u8 = 1;
+
 #[account]
 #[derive(Default, Debug)]
 pub struct TransactionBuffer {
@@ -22,21 +35,48 @@ pub struct TransactionBuffer {
     pub buffer_index: u8,
     /// Vault index of the transaction this buffer belongs to.
     pub vault_index: u8,
-    /// Hash of the final assembled transaction message.
+    /// Number of ephemeral signer PDAs the transaction message expects the program to sign for.
+    pub num_ephemeral_signers: u8,
+    /// Nonce of the transaction, taken from the multi-wallet transaction counter. Mixed into the
+    /// ephemeral signer seeds, since the buffer address is reused once the buffer is closed.
+    pub transaction_nonce: u64,
+    /// Hash of the final assembled transaction message, followed by the Borsh-encoded assertions if there are any.
     pub final_buffer_hash: [u8; 32],
     /// The size of the final assembled transaction message.
     pub final_buffer_size: u16,
+    /// Balance assertions that must hold after the transaction is executed.
+    pub assertions: Vec<BalanceAssertion>,
     /// The buffer of the transaction message.
     pub buffer: Vec<u8>,
+    /// Layout version of the account. Buffers created before versioning are upgraded by
+    /// `migrate_transaction_buffer` and cannot be used until then.
+    pub version: u8,
+}
+
+/// Layout of transaction buffers created before the account was versioned.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionBufferV0 {
+    pub multi_wallet: Pubkey,
+    pub creator: Pubkey,
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+    pub buffer_index: u8,
+    pub vault_index: u8,
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u16,
+    pub buffer: Vec<u8>,
 }
 
 impl TransactionBuffer {
-    pub fn size(final_message_buffer_size: u16) -> Result<usize> {
+    pub fn size(final_message_buffer_size: u16, num_assertions: usize) -> Result<usize> {
         // Make sure final size is not greater than MAX_BUFFER_SIZE bytes.
         if (final_message_buffer_size as usize) > MAX_BUFFER_SIZE {
             return err!(MultisigError::FinalBufferSizeExceeded);
         }
-        Ok(
+        if num_assertions > MAX_BALANCE_ASSERTIONS {
+            return err!(MultisigError::TooManyBalanceAssertions);
+        }
+        let size =
             8 +   // anchor account discriminator
             32 +  // multisig
             32 +  // creator
@@ -44,15 +84,64 @@ impl TransactionBuffer {
             1 +  // bump
             1 +   // buffer_index
             1 +   // vault_index
+            1 +   // num_ephemeral_signers
+            8 +   // transaction_nonce
             32 +  // transaction_message_hash
             2 +  // final_buffer_size
+            4 + // assertions vec length bytes
+            num_assertions * BalanceAssertion::SIZE + // assertions
             4 + // vec length bytes
-            final_message_buffer_size as usize, // buffer
-        )
+            final_message_buffer_size as usize + // buffer
+            1; // version
+
+        // The account is created in a single instruction, so the buffer and the assertions share its size limit.
+        if size > MAX_TRANSACTION_BUFFER_ACCOUNT_SIZE {
+            return err!(MultisigError::FinalBufferSizeExceeded);
+        }
+        Ok(size)
+    }
+
+    /// Reads the account data of a transaction buffer created before versioning and upgrades it to the
+    /// current version. The buffer has neither ephemeral signers nor assertions, and is left for the caller
+    /// to give a transaction nonce.
+    pub fn upgrade(data: &[u8]) -> Result<TransactionBuffer> {
+        require!(
+            data.starts_with(&TransactionBuffer::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        if let Ok(transaction_buffer) = TransactionBuffer::try_deserialize(&mut &data[..]) {
+            require!(
+                transaction_buffer.version < TRANSACTION_BUFFER_VERSION,
+                MultisigError::TransactionBufferAlreadyMigrated
+            );
+        }
+
+        let v0 = TransactionBufferV0::deserialize(&mut &data[TransactionBuffer::DISCRIMINATOR.len()..])?;
+        Ok(TransactionBuffer {
+            multi_wallet: v0.multi_wallet,
+            creator: v0.creator,
+            rent_payer: v0.rent_payer,
+            bump: v0.bump,
+            buffer_index: v0.buffer_index,
+            vault_index: v0.vault_index,
+            num_ephemeral_signers: 0,
+            transaction_nonce: 0,
+            final_buffer_hash: v0.final_buffer_hash,
+            final_buffer_size: v0.final_buffer_size,
+            assertions: Vec::new(),
+            buffer: v0.buffer,
+            version: TRANSACTION_BUFFER_VERSION,
+        })
     }
 
+    /// Checks the message and the assertions against the hash approved at creation. Buffers without
+    /// assertions hash the message alone, like the buffers created before assertions existed.
     pub fn validate_hash(&self) -> Result<()> {
-        let message_buffer_hash = hash(&self.buffer);
+        let message_buffer_hash = if self.assertions.is_empty() {
+            hash(&self.buffer)
+        } else {
+            hashv(&[&self.buffer, &self.assertions.try_to_vec()?])
+        };
         require!(
             message_buffer_hash.to_bytes() == self.final_buffer_hash,
             MultisigError::FinalBufferHashMismatch
@@ -85,3 +174,93 @@ impl TransactionBuffer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_stays_within_account_limit() {
+        let fixed = TransactionBuffer::size(0, 0).unwrap();
+        let max_buffer = (MAX_TRANSACTION_BUFFER_ACCOUNT_SIZE - fixed) as u16;
+
+        assert_eq!(
+            TransactionBuffer::size(max_buffer, 0).unwrap(),
+            MAX_TRANSACTION_BUFFER_ACCOUNT_SIZE
+        );
+        assert!(TransactionBuffer::size(max_buffer + 1, 0).is_err());
+        assert!(TransactionBuffer::size(max_buffer, 1).is_err());
+        assert!(TransactionBuffer::size(0, MAX_BALANCE_ASSERTIONS).is_ok());
+        assert_eq!(
+            TransactionBuffer::size(0, MAX_BALANCE_ASSERTIONS + 1).unwrap_err(),
+            MultisigError::TooManyBalanceAssertions.into()
+        );
+    }
+
+    #[test]
+    fn hash_covers_assertions() {
+        let account = Pubkey::new_unique();
+        let assertions = vec![BalanceAssertion::MaxLamportsDecrease { account, amount: 1_000 }];
+        let mut transaction_buffer = TransactionBuffer {
+            final_buffer_hash: hash(&[1, 2, 3]).to_bytes(),
+            buffer: vec![1, 2, 3],
+            ..Default::default()
+        };
+        transaction_buffer.validate_hash().unwrap();
+
+        transaction_buffer.assertions = assertions.clone();
+        assert_eq!(
+            transaction_buffer.validate_hash().unwrap_err(),
+            MultisigError::FinalBufferHashMismatch.into()
+        );
+
+        transaction_buffer.final_buffer_hash =
+            hashv(&[&[1, 2, 3], &assertions.try_to_vec().unwrap()]).to_bytes();
+        transaction_buffer.validate_hash().unwrap();
+
+        transaction_buffer.assertions = vec![BalanceAssertion::MaxLamportsDecrease { account, amount: u64::MAX }];
+        assert_eq!(
+            transaction_buffer.validate_hash().unwrap_err(),
+            MultisigError::FinalBufferHashMismatch.into()
+        );
+    }
+
+    #[test]
+    fn upgrades_v0_snapshots() {
+        let v0 = TransactionBufferV0 {
+            multi_wallet: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            rent_payer: Pubkey::new_unique(),
+            bump: 251,
+            buffer_index: 2,
+            vault_index: 1,
+            final_buffer_hash: [3; 32],
+            final_buffer_size: 4,
+            buffer: vec![1, 2, 3, 4],
+        };
+        let mut data = TransactionBuffer::DISCRIMINATOR.to_vec();
+        data.extend(v0.try_to_vec().unwrap());
+        let transaction_buffer = TransactionBuffer::upgrade(&data).unwrap();
+
+        assert_eq!(transaction_buffer.multi_wallet, v0.multi_wallet);
+        assert_eq!(transaction_buffer.creator, v0.creator);
+        assert_eq!(transaction_buffer.rent_payer, v0.rent_payer);
+        assert_eq!(transaction_buffer.bump, v0.bump);
+        assert_eq!(transaction_buffer.buffer_index, v0.buffer_index);
+        assert_eq!(transaction_buffer.vault_index, v0.vault_index);
+        assert_eq!(transaction_buffer.num_ephemeral_signers, 0);
+        assert_eq!(transaction_buffer.final_buffer_hash, v0.final_buffer_hash);
+        assert_eq!(transaction_buffer.final_buffer_size, v0.final_buffer_size);
+        assert!(transaction_buffer.assertions.is_empty());
+        assert_eq!(transaction_buffer.buffer, v0.buffer);
+        assert_eq!(transaction_buffer.version, TRANSACTION_BUFFER_VERSION);
+
+        let mut migrated = Vec::new();
+        transaction_buffer.try_serialize(&mut migrated).unwrap();
+        assert_eq!(migrated.len(), TransactionBuffer::size(v0.final_buffer_size, 0).unwrap());
+        assert_eq!(
+            TransactionBuffer::upgrade(&migrated).unwrap_err(),
+            MultisigError::TransactionBufferAlreadyMigrated.into()
+        );
+    }
+}
@@ -16,8 +16,8 @@ impl From<CompiledInstruction> for MultisigCompiledInstruction {
     fn from(compiled_instruction: CompiledInstruction) -> Self {
         Self {
             program_id_index: compiled_instruction.program_id_index,
-            account_indexes: compiled_instruction.account_indexes.into(),
-            data: compiled_instruction.data.into(),
+            account_indexes: compiled_instruction.account_indexes,
+            data: compiled_instruction.data,
         }
     }
 }
@@ -38,8 +38,8 @@ impl From<MessageAddressTableLookup> for MultisigMessageAddressTableLookup {
     fn from(m: MessageAddressTableLookup) -> Self {
         Self {
             account_key: m.account_key,
-            writable_indexes: m.writable_indexes.into(),
-            readonly_indexes: m.readonly_indexes.into(),
+            writable_indexes: m.writable_indexes,
+            readonly_indexes: m.readonly_indexes,
         }
     }
 }
@@ -111,6 +111,19 @@ pub struct VaultTransactionMessage {
     pub address_table_lookups: Vec<MultisigMessageAddressTableLookup>,
 }
 
+/// Summary of a validated vault transaction, returned by `vault_transaction_validate`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VaultTransactionSummary {
+    /// The vault PDA that signs the transaction.
+    pub vault: Pubkey,
+    /// All accounts of the message, static keys first, then loaded writable and loaded readonly keys.
+    pub accounts: Vec<Pubkey>,
+    /// Indexes into `accounts` of the programs invoked by the message.
+    pub program_indexes: Vec<u8>,
+    /// Indexes into `accounts` of the accounts the message requests as writable.
+    pub writable_indexes: Vec<u8>,
+}
+
 impl VaultTransactionMessage {
     /// Returns the number of all the account keys (static + dynamic) in the message.
     pub fn num_all_account_keys(&self) -> usize {
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{create_program_account, id, MultisigError};
+
+use super::{SEED_MULTISIG, SEED_WALLET_METADATA};
+
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+pub const MAX_METADATA_DESCRIPTION_LEN: usize = 256;
+pub const MAX_METADATA_IMAGE_URI_LEN: usize = 200;
+pub const MAX_METADATA_TAGS: usize = 8;
+pub const MAX_METADATA_TAG_LEN: usize = 16;
+pub const MAX_METADATA_ENTRIES: usize = 8;
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+pub const MAX_METADATA_VALUE_LEN: usize = 128;
+
+/// Free-form key/value pair of the wallet metadata.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataEntry {
+    pub key: String,
+    pub value: String,
+}
+
+/// Content of the wallet metadata, replaced as a whole by `ConfigAction::UpdateMetadata`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WalletMetadataUpdate {
+    pub name: String,
+    pub description: String,
+    pub image_uri: String,
+    pub tags: Vec<String>,
+    pub entries: Vec<MetadataEntry>,
+}
+
+impl WalletMetadataUpdate {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.name.len() <= MAX_METADATA_NAME_LEN
+                && self.description.len() <= MAX_METADATA_DESCRIPTION_LEN
+                && self.image_uri.len() <= MAX_METADATA_IMAGE_URI_LEN
+                && self.tags.len() <= MAX_METADATA_TAGS
+                && self.tags.iter().all(|x| x.len() <= MAX_METADATA_TAG_LEN)
+                && self.entries.len() <= MAX_METADATA_ENTRIES
+                && self.entries.iter().all(|x| {
+                    x.key.len() <= MAX_METADATA_KEY_LEN && x.value.len() <= MAX_METADATA_VALUE_LEN
+                }),
+            MultisigError::WalletMetadataTooLarge
+        );
+        Ok(())
+    }
+}
+
+/// Structured metadata of a multi-wallet, readable by explorers without an off-chain index.
+#[account]
+pub struct WalletMetadata {
+    pub multi_wallet: Pubkey,
+    pub bump: u8,
+    pub name: String,
+    pub description: String,
+    pub image_uri: String,
+    pub tags: Vec<String>,
+    pub entries: Vec<MetadataEntry>,
+}
+
+impl WalletMetadata {
+    pub const SIZE: usize = 8  + // anchor account discriminator
+        32 + // multi_wallet
+        1  + // bump
+        4 + MAX_METADATA_NAME_LEN + // name
+        4 + MAX_METADATA_DESCRIPTION_LEN + // description
+        4 + MAX_METADATA_IMAGE_URI_LEN + // image_uri
+        4 + MAX_METADATA_TAGS * (4 + MAX_METADATA_TAG_LEN) + // tags
+        4 + MAX_METADATA_ENTRIES * (4 + MAX_METADATA_KEY_LEN + 4 + MAX_METADATA_VALUE_LEN); // entries
+
+    /// Writes `update` to the metadata account of `multi_wallet`, creating it on first use.
+    pub fn write<'info>(
+        wallet_metadata: &AccountInfo<'info>,
+        payer: Option<&AccountInfo<'info>>,
+        system_program: Option<&AccountInfo<'info>>,
+        multi_wallet: Pubkey,
+        update: WalletMetadataUpdate,
+    ) -> Result<()> {
+        update.validate()?;
+        let (wallet_metadata_key, bump) = Pubkey::find_program_address(
+            &[SEED_MULTISIG, multi_wallet.as_ref(), SEED_WALLET_METADATA],
+            &id(),
+        );
+        require_keys_eq!(wallet_metadata.key(), wallet_metadata_key, MultisigError::InvalidAccount);
+
+        if wallet_metadata.data_is_empty() {
+            create_program_account(
+                payer.ok_or(MultisigError::MissingAccount)?,
+                wallet_metadata,
+                system_program.ok_or(MultisigError::MissingAccount)?,
+                Self::SIZE,
+                &[SEED_MULTISIG, multi_wallet.as_ref(), SEED_WALLET_METADATA, &[bump]],
+            )?;
+        }
+
+        let wallet_metadata_data = WalletMetadata {
+            multi_wallet,
+            bump,
+            name: update.name,
+            description: update.description,
+            image_uri: update.image_uri,
+            tags: update.tags,
+            entries: update.entries,
+        };
+        wallet_metadata_data.try_serialize(&mut &mut wallet_metadata.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+use crate::MultisigError;
+
+/// Size of the offsets of one signature in an ed25519 program instruction.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+/// Offset of the first signature offsets, after the signature count and padding.
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Verifies that an ed25519 program instruction preceding the current one checks
+/// `message` signed by `signer`. The precompile fails the transaction if the signature
+/// is invalid, so finding the instruction is enough.
+pub fn verify_ed25519_signature(
+    instruction_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instruction_sysvar)?;
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index.into(), instruction_sysvar)?;
+        if instruction.program_id == ed25519_program::ID
+            && verifies_signature(&instruction.data, signer, message)
+        {
+            return Ok(());
+        }
+    }
+    err!(MultisigError::InvalidOfferSignature)
+}
+
+/// Returns `true` if one of the signatures of the ed25519 instruction `data` is by `signer` over `message`.
+/// Only signatures whose data is held in the instruction itself are considered.
+fn verifies_signature(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    let read_u16 = |offset: usize| {
+        data.get(offset..offset + 2)
+            .map(|x| usize::from(u16::from_le_bytes([x[0], x[1]])))
+    };
+    let num_signatures = data.first().copied().unwrap_or_default();
+
+    (0..usize::from(num_signatures)).any(|i| {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SIZE;
+        let (
+            Some(signature_instruction_index),
+            Some(public_key_offset),
+            Some(public_key_instruction_index),
+            Some(message_data_offset),
+            Some(message_data_size),
+            Some(message_instruction_index),
+        ) = (
+            read_u16(start + 2),
+            read_u16(start + 4),
+            read_u16(start + 6),
+            read_u16(start + 8),
+            read_u16(start + 10),
+            read_u16(start + 12),
+        )
+        else {
+            return false;
+        };
+        let current_instruction = usize::from(u16::MAX);
+        signature_instruction_index == current_instruction
+            && public_key_instruction_index == current_instruction
+            && message_instruction_index == current_instruction
+            && data.get(public_key_offset..public_key_offset + 32) == Some(signer.as_ref())
+            && data.get(message_data_offset..message_data_offset + message_data_size) == Some(message)
+    })
+}
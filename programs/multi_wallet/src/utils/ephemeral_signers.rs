@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{SEED_EPHEMERAL_SIGNER, SEED_MULTISIG};
+
+/// Derives the ephemeral signer PDAs of a transaction buffer together with their signer seeds.
+///
+/// # Arguments
+/// `transaction_buffer_key` - The transaction buffer the ephemeral signers are derived from.
+/// `transaction_nonce` - The nonce of the transaction held by the buffer.
+/// `num_ephemeral_signers` - The number of ephemeral signers declared by the buffer.
+pub fn derive_ephemeral_signers(
+    transaction_buffer_key: Pubkey,
+    transaction_nonce: u64,
+    num_ephemeral_signers: u8,
+) -> (Vec<Pubkey>, Vec<Vec<Vec<u8>>>) {
+    (0..num_ephemeral_signers)
+        .map(|index| {
+            let seeds = vec![
+                SEED_MULTISIG.to_vec(),
+                transaction_buffer_key.to_bytes().to_vec(),
+                SEED_EPHEMERAL_SIGNER.to_vec(),
+                transaction_nonce.to_le_bytes().to_vec(),
+                index.to_le_bytes().to_vec(),
+            ];
+            let seed_slices: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+            let (pubkey, bump) = Pubkey::find_program_address(&seed_slices, &crate::id());
+
+            let mut signer_seeds = seeds;
+            signer_seeds.push(vec![bump]);
+
+            (pubkey, signer_seeds)
+        })
+        .unzip()
+}
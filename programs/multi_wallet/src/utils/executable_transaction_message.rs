@@ -5,7 +5,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::address_lookup_table;
 use anchor_lang::solana_program::address_lookup_table::state::AddressLookupTable;
 use anchor_lang::solana_program::instruction::Instruction;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program::{get_return_data, invoke_signed};
 
 use crate::{state::*, MultisigError};
 
@@ -27,11 +27,13 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
     /// `message_account_infos` - AccountInfo's that are expected to be mentioned in the message.
     /// `address_lookup_table_account_infos` - AccountInfo's that are expected to correspond to the lookup tables mentioned in `message.address_table_lookups`.
     /// `vault_pubkey` - The vault PDA that is expected to sign the message.
+    /// `ephemeral_signer_pdas` - The ephemeral signer PDAs that are expected to sign the message.
     pub fn new_validated(
         message: VaultTransactionMessage,
         message_account_infos: &'a [AccountInfo<'info>],
         address_lookup_table_account_infos: &'a [AccountInfo<'info>],
         vault_pubkey: &'a Pubkey,
+        ephemeral_signer_pdas: &'a [Pubkey],
     ) -> Result<Self> {
         // CHECK: `address_lookup_table_account_infos` must be valid `AddressLookupTable`s
         //         and be the ones mentioned in `message.address_table_lookups`.
@@ -80,9 +82,12 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
                 MultisigError::InvalidAccount
             );
             // If the account is marked as signer in the message, it must be a signer in the account infos too.
-            // Unless it's a vault PDA, as they cannot be passed as signers to `remaining_accounts`,
-            // because they are PDA's and can't sign the transaction.
-            if message.is_signer_index(i) && account_info.key != vault_pubkey {
+            // Unless it's a vault or ephemeral signer PDA, as they cannot be passed as signers to
+            // `remaining_accounts`, because they are PDA's and can't sign the transaction.
+            if message.is_signer_index(i)
+                && account_info.key != vault_pubkey
+                && !ephemeral_signer_pdas.contains(account_info.key)
+            {
                 require!(account_info.is_signer, MultisigError::InvalidAccount);
             }
             // If the account is marked as writable in the message, it must be writable in the account infos too.
@@ -167,18 +172,70 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         })
     }
 
-    /// Executes all instructions in the message via CPI calls.
+    /// Summarizes the resolved accounts, invoked programs and writable set of the message.
+    /// # Arguments
+    /// * `vault_pubkey` - The vault PDA that is expected to sign the message.
+    pub fn summary(&self, vault_pubkey: Pubkey) -> VaultTransactionSummary {
+        let accounts: Vec<Pubkey> = self
+            .static_accounts
+            .iter()
+            .chain(self.loaded_writable_accounts.iter())
+            .chain(self.loaded_readonly_accounts.iter())
+            .map(|account_info| *account_info.key)
+            .collect();
+
+        let mut program_indexes: Vec<u8> = self
+            .message
+            .instructions
+            .iter()
+            .map(|instruction| instruction.program_id_index)
+            .collect();
+        program_indexes.sort_unstable();
+        program_indexes.dedup();
+
+        let writable_indexes = (0..accounts.len())
+            .filter(|index| self.is_writable_index(*index))
+            .map(|index| index as u8)
+            .collect();
+
+        VaultTransactionSummary {
+            vault: vault_pubkey,
+            accounts,
+            program_indexes,
+            writable_indexes,
+        }
+    }
+
+    /// Executes all instructions in the message via CPI calls and returns
+    /// the return data set by each of them, in instruction order.
     /// # Arguments
     /// * `vault_seeds` - Seeds for the vault PDA.
-    pub fn execute_message(self, vault_seeds: &[&[u8]]) -> Result<()> {
+    /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs.
+    pub fn execute_message(
+        self,
+        vault_seeds: &[&[u8]],
+        ephemeral_signer_seeds: &[Vec<Vec<u8>>],
+    ) -> Result<Vec<Option<InstructionReturnData>>> {
+        let ephemeral_signer_seed_slices: Vec<Vec<&[u8]>> = ephemeral_signer_seeds
+            .iter()
+            .map(|seeds| seeds.iter().map(Vec::as_slice).collect())
+            .collect();
+        let signer_seeds: Vec<&[&[u8]]> = std::iter::once(vault_seeds)
+            .chain(ephemeral_signer_seed_slices.iter().map(Vec::as_slice))
+            .collect();
+
         // NOTE: `self.to_instructions_and_accounts()` calls `take()` on
         // `self.message.instructions`, therefore after this point no more
         // references or usages of `self.message` should be made to avoid
         // faulty behavior.
+        let mut return_data = Vec::new();
         for (ix, account_infos) in self.to_instructions_and_accounts().iter() {
-            invoke_signed(&ix, &account_infos, &[vault_seeds])?;
+            invoke_signed(ix, account_infos, &signer_seeds)?;
+            return_data.push(
+                get_return_data().map(|(program_id, data)| InstructionReturnData { program_id, data }),
+            );
         }
-        Ok(())
+        Ok(return_data)
     }
 
     /// Account indices are resolved in the following order:
@@ -220,6 +277,7 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         index < self.loaded_writable_accounts.len()
     }
 
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_instructions_and_accounts(mut self) -> Vec<(Instruction, Vec<AccountInfo<'info>>)> {
         let mut executable_instructions = vec![];
 
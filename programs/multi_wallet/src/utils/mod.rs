@@ -1,2 +1,17 @@
 pub mod executable_transaction_message;
 pub use executable_transaction_message::*;
+
+pub mod vault_transaction;
+pub use vault_transaction::*;
+
+pub mod ephemeral_signers;
+pub use ephemeral_signers::*;
+
+pub mod token_extensions;
+pub use token_extensions::*;
+
+pub mod ed25519;
+pub use ed25519::*;
+
+pub mod program_account;
+pub use program_account::*;
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        confidential_transfer::ConfidentialTransferMint,
+        default_account_state::DefaultAccountState,
+        mint_close_authority::MintCloseAuthority,
+        non_transferable::NonTransferable,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as TokenAccountState, AccountState, Mint as MintState},
+};
+
+use crate::MultisigError;
+
+/// Rejects Token-2022 mints whose extensions escrows cannot settle.
+pub fn validate_escrow_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    if let Ok(transfer_hook) = mint_state.get_extension::<TransferHook>() {
+        require!(
+            Option::<Pubkey>::from(transfer_hook.program_id).is_none(),
+            MultisigError::UnsupportedMintTransferHook
+        );
+    }
+    require!(
+        mint_state.get_extension::<NonTransferable>().is_err(),
+        MultisigError::UnsupportedMintNonTransferable
+    );
+    require!(
+        mint_state.get_extension::<ConfidentialTransferMint>().is_err(),
+        MultisigError::UnsupportedMintConfidentialTransfer
+    );
+    // A permanent delegate can move the tokens out of the escrow vault at any time.
+    if let Ok(permanent_delegate) = mint_state.get_extension::<PermanentDelegate>() {
+        require!(
+            Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+            MultisigError::UnsupportedMintPermanentDelegate
+        );
+    }
+    // A closable mint can be re-created at the same address with different decimals or extensions.
+    if let Ok(mint_close_authority) = mint_state.get_extension::<MintCloseAuthority>() {
+        require!(
+            Option::<Pubkey>::from(mint_close_authority.close_authority).is_none(),
+            MultisigError::UnsupportedMintCloseAuthority
+        );
+    }
+    // Escrow token vaults created frozen could not receive or release funds.
+    if let Ok(default_account_state) = mint_state.get_extension::<DefaultAccountState>() {
+        require!(
+            default_account_state.state != u8::from(AccountState::Frozen),
+            MultisigError::UnsupportedMintDefaultFrozen
+        );
+    }
+    Ok(())
+}
+
+/// Returns the transfer fee config of `mint`, if it is a Token-2022 mint with the TransferFee extension.
+pub fn get_transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != anchor_spl::token_2022::ID {
+        return Ok(None);
+    }
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(mint_state.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Returns the balance and the withheld transfer fees of a token account, read from its current data.
+pub fn get_token_account_balances(token_account: &AccountInfo) -> Result<(u64, u64)> {
+    let data = token_account.try_borrow_data()?;
+    let account_state = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+    let withheld_amount = account_state
+        .get_extension::<TransferFeeAmount>()
+        .map_or(0, |x| u64::from(x.withheld_amount));
+    Ok((account_state.base.amount, withheld_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+        solana_program::program_option::COption,
+    };
+
+    fn mint_data(extension: ExtensionType, init: impl FnOnce(&mut StateWithExtensionsMut<MintState>)) -> Vec<u8> {
+        let mut data = vec![0; ExtensionType::try_calculate_account_len::<MintState>(&[extension]).unwrap()];
+        let mut mint = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        mint.base = MintState {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        init(&mut mint);
+        data
+    }
+
+    fn validate(mut data: Vec<u8>) -> Result<()> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let owner = anchor_spl::token_2022::ID;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        validate_escrow_mint(&mint)
+    }
+
+    #[test]
+    fn rejects_mints_that_can_take_escrowed_tokens() {
+        let permanent_delegate = mint_data(ExtensionType::PermanentDelegate, |mint| {
+            mint.init_extension::<PermanentDelegate>(true).unwrap().delegate =
+                Some(Pubkey::new_unique()).try_into().unwrap();
+        });
+        assert_eq!(
+            validate(permanent_delegate).unwrap_err(),
+            MultisigError::UnsupportedMintPermanentDelegate.into()
+        );
+
+        let mint_close_authority = mint_data(ExtensionType::MintCloseAuthority, |mint| {
+            mint.init_extension::<MintCloseAuthority>(true).unwrap().close_authority =
+                Some(Pubkey::new_unique()).try_into().unwrap();
+        });
+        assert_eq!(
+            validate(mint_close_authority).unwrap_err(),
+            MultisigError::UnsupportedMintCloseAuthority.into()
+        );
+
+        let default_frozen = mint_data(ExtensionType::DefaultAccountState, |mint| {
+            mint.init_extension::<DefaultAccountState>(true).unwrap().state = AccountState::Frozen.into();
+        });
+        assert_eq!(
+            validate(default_frozen).unwrap_err(),
+            MultisigError::UnsupportedMintDefaultFrozen.into()
+        );
+    }
+
+    #[test]
+    fn accepts_mints_with_unset_extensions() {
+        let default_initialized = mint_data(ExtensionType::DefaultAccountState, |mint| {
+            mint.init_extension::<DefaultAccountState>(true).unwrap().state = AccountState::Initialized.into();
+        });
+        assert!(validate(default_initialized).is_ok());
+
+        let no_close_authority = mint_data(ExtensionType::MintCloseAuthority, |mint| {
+            mint.init_extension::<MintCloseAuthority>(true).unwrap();
+        });
+        assert!(validate(no_close_authority).is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{MultiWallet, SEED_MULTISIG, SEED_VAULT},
+    ExecutableTransactionMessage, MultisigError, Permission, VaultTransactionMessage,
+};
+
+/// Vault PDA a vault transaction is executed as.
+pub struct VaultSigner {
+    multi_wallet: Pubkey,
+    vault_index: [u8; 2],
+    bump: [u8; 1],
+    pub pubkey: Pubkey,
+}
+
+impl VaultSigner {
+    /// # Arguments
+    /// `multi_wallet` - The multi wallet owning the vault.
+    /// `vault_index` - The index of the vault.
+    /// `program_id` - The program the vault PDA is derived from.
+    pub fn derive(multi_wallet: Pubkey, vault_index: u16, program_id: &Pubkey) -> Self {
+        let vault_index = vault_index.to_le_bytes();
+        let (pubkey, bump) = Pubkey::find_program_address(
+            &[SEED_MULTISIG, multi_wallet.as_ref(), SEED_VAULT, vault_index.as_ref()],
+            program_id,
+        );
+        Self {
+            multi_wallet,
+            vault_index,
+            bump: [bump],
+            pubkey,
+        }
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 5] {
+        [
+            SEED_MULTISIG,
+            self.multi_wallet.as_ref(),
+            SEED_VAULT,
+            self.vault_index.as_ref(),
+            self.bump.as_ref(),
+        ]
+    }
+}
+
+/// Requires the signers among `account_infos` to meet the threshold, to include an executor and
+/// the multi wallet to accept vault transactions.
+pub fn validate_vault_transaction_signers(
+    multi_wallet: &MultiWallet,
+    account_infos: &[AccountInfo],
+) -> Result<()> {
+    let unique_signers = multi_wallet.get_unique_signers(account_infos)?;
+
+    require!(
+        multi_wallet.threshold as usize
+            <= unique_signers
+                .iter()
+                .filter(|x| x.permissions.is_some()
+                    && x.permissions.unwrap().has(Permission::VoteTransaction))
+                .count(),
+        MultisigError::NotEnoughSigners
+    );
+
+    require!(
+        unique_signers
+            .iter()
+            .filter(|x| x.permissions.is_some()
+                && x.permissions.unwrap().has(Permission::ExecuteTransaction))
+            .count()
+            >= 1,
+        MultisigError::InsufficientSignerWithExecutePermission
+    );
+
+    require!(
+        multi_wallet.listing_state().allows_vault_transaction(),
+        MultisigError::MultisigIsCurrentlyLocked
+    );
+
+    Ok(())
+}
+
+/// Splits `remaining_accounts` into the lookup tables and the accounts referenced by `message`,
+/// and validates them against it.
+///
+/// # Arguments
+/// `message` - The vault transaction message to execute.
+/// `remaining_accounts` - The lookup tables of the message, followed by the accounts it references.
+/// `vault_pubkey` - The vault PDA that is expected to sign the message.
+/// `ephemeral_signer_pdas` - The ephemeral signer PDAs that are expected to sign the message.
+pub fn load_vault_transaction<'a, 'info>(
+    message: VaultTransactionMessage,
+    remaining_accounts: &'a [AccountInfo<'info>],
+    vault_pubkey: &'a Pubkey,
+    ephemeral_signer_pdas: &'a [Pubkey],
+) -> Result<ExecutableTransactionMessage<'a, 'info>> {
+    let num_lookups = message.address_table_lookups.len();
+    let message_end_index = num_lookups + message.num_all_account_keys();
+
+    let message_account_infos = remaining_accounts
+        .get(num_lookups..message_end_index)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+
+    let address_lookup_table_account_infos = remaining_accounts
+        .get(..num_lookups)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+
+    ExecutableTransactionMessage::new_validated(
+        message,
+        message_account_infos,
+        address_lookup_table_account_infos,
+        vault_pubkey,
+        ephemeral_signer_pdas,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vault_signer_seeds_recreate_the_vault_pda() {
+        let multi_wallet = Pubkey::new_unique();
+        let vault_signer = VaultSigner::derive(multi_wallet, 3, &crate::id());
+
+        assert_eq!(
+            Pubkey::create_program_address(&vault_signer.signer_seeds(), &crate::id()).unwrap(),
+            vault_signer.pubkey
+        );
+    }
+}
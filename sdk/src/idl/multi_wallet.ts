@@ -8,24 +8,24 @@ export const MultiWalletIdl = {
   },
   instructions: [
     {
-      name: "cancel_escrow_as_non_owner",
+      name: "accept_escrow_counter_offer",
       docs: [
-        "Cancels an escrow as a proposer. This function returns the locked funds in the escrow",
-        "vault back to the proposer and invalidates the escrow, preventing further execution.",
+        "Accepts the owners' counter-offer as the proposer. The escrow vault is topped up by the proposer",
+        "or partially refunded to match the new amount, and fully refunded and re-funded if the mint changes.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for accepting the counter-offer.",
+        "When the mint changes, the accounts of the new mint use `new_token_program`.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
-        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "- `Ok(())`: If the escrow terms are updated to the counter-offer.",
+        "- `Err`: If there is no counter-offer or the transfer operation encounters an issue.",
         "",
       ],
-      discriminator: [219, 234, 45, 214, 232, 65, 209, 96],
+      discriminator: [69, 239, 38, 111, 140, 217, 99, 137],
       accounts: [
         {
           name: "multi_wallet",
-          writable: true,
           pda: {
             seeds: [
               {
@@ -150,6 +150,69 @@ export const MultiWalletIdl = {
         },
         {
           name: "mint",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "new_escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "new_token_program",
+              },
+              {
+                kind: "account",
+                path: "new_mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "new_proposer_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "proposer",
+              },
+              {
+                kind: "account",
+                path: "new_token_program",
+              },
+              {
+                kind: "account",
+                path: "new_mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "new_mint",
           optional: true,
         },
         {
@@ -161,6 +224,13 @@ export const MultiWalletIdl = {
           name: "token_program",
           optional: true,
         },
+        {
+          name: "new_token_program",
+          docs: [
+            "Token program of the counter-offer mint, which can differ from that of the current mint.",
+          ],
+          optional: true,
+        },
         {
           name: "system_program",
           address: "11111111111111111111111111111111",
@@ -190,20 +260,22 @@ export const MultiWalletIdl = {
       args: [],
     },
     {
-      name: "cancel_escrow_as_owner",
+      name: "accept_signed_offer",
       docs: [
-        "Cancels an escrow as an owner. This function unlocks the multi-wallet and invalidates the escrow,",
-        "preventing it from being executed.",
+        "Accepts an offer signed off-chain by a buyer, without the buyer funding an escrow first.",
+        "The buyer's signature is checked against an ed25519 program instruction earlier in the transaction,",
+        "and the amount is spent from the buyer's token account through the delegate approved for the offer.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for accepting the offer.",
+        "- `offer`: The offer signed by the buyer.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully canceled and the multi-wallet is unlocked.",
-        "- `Err`: If validation fails or the accounts provided are invalid.",
+        "- `Ok(())`: If the buyer is paid out and the multi-wallet is handed over to the new members.",
+        "- `Err`: If the signature is missing, the offer expired or was already accepted, or the owners did not approve it.",
         "",
       ],
-      discriminator: [22, 155, 132, 143, 51, 185, 186, 247],
+      discriminator: [47, 91, 244, 168, 41, 209, 71, 141],
       accounts: [
         {
           name: "multi_wallet",
@@ -218,15 +290,14 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "escrow.create_key",
-                account: "Escrow",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
               },
             ],
           },
         },
         {
-          name: "escrow",
-          writable: true,
+          name: "offer_delegate",
           pda: {
             seeds: [
               {
@@ -235,53 +306,55 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "escrow.create_key",
-                account: "Escrow",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
               },
               {
-                kind: "account",
-                path: "escrow.identifier",
-                account: "Escrow",
+                kind: "arg",
+                path: "offer.nonce",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
               },
             ],
           },
         },
         {
-          name: "escrow_vault",
+          name: "offer_nonce",
           writable: true,
-          optional: true,
           pda: {
             seeds: [
               {
                 kind: "const",
-                value: [101, 115, 99, 114, 111, 119],
-              },
-              {
-                kind: "account",
-                path: "escrow.create_key",
-                account: "Escrow",
+                value: [
+                  115, 105, 103, 110, 101, 100, 95, 111, 102, 102, 101, 114,
+                ],
               },
               {
                 kind: "account",
-                path: "escrow.identifier",
-                account: "Escrow",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
               },
               {
-                kind: "const",
-                value: [118, 97, 117, 108, 116],
+                kind: "arg",
+                path: "offer.nonce",
               },
             ],
           },
         },
         {
-          name: "escrow_token_vault",
+          name: "buyer",
+        },
+        {
+          name: "buyer_token_account",
           writable: true,
           optional: true,
           pda: {
             seeds: [
               {
                 kind: "account",
-                path: "escrow_vault",
+                path: "buyer",
               },
               {
                 kind: "account",
@@ -303,14 +376,14 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "proposer_token_account",
+          name: "recipient_token_account",
           writable: true,
           optional: true,
           pda: {
             seeds: [
               {
                 kind: "account",
-                path: "proposer",
+                path: "recipient",
               },
               {
                 kind: "account",
@@ -332,70 +405,119 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "proposer",
-          writable: true,
-        },
-        {
-          name: "instruction_sysvar",
-          address: "Sysvar1nstructions1111111111111111111111111",
+          name: "protocol_config",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
         },
         {
-          name: "mint",
+          name: "protocol_fee_recipient",
+          writable: true,
           optional: true,
         },
         {
-          name: "token_program",
+          name: "protocol_fee_token_account",
+          writable: true,
           optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "protocol_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
         },
         {
-          name: "system_program",
-          address: "11111111111111111111111111111111",
-        },
-        {
-          name: "associated_token_program",
-          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+          name: "marketplace_fee_recipient",
+          writable: true,
+          optional: true,
         },
         {
-          name: "event_authority",
+          name: "marketplace_fee_token_account",
+          writable: true,
+          optional: true,
           pda: {
             seeds: [
               {
-                kind: "const",
-                value: [
-                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
-                  114, 105, 116, 121,
-                ],
+                kind: "account",
+                path: "marketplace_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
               },
             ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
           },
         },
         {
-          name: "program",
+          name: "mint",
+          optional: true,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "change_config",
-      docs: [
-        "# Parameters",
-        "- `ctx`: The context of the multi-action execution.",
-        "- `config_actions`: The list of actions to be executed.",
-        "",
-        "# Returns",
-        "- `Result<()>`: The result of the multi-action execution.",
-      ],
-      discriminator: [24, 158, 114, 115, 94, 210, 244, 233],
-      accounts: [
         {
-          name: "multi_wallet",
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "recipient",
+          writable: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "receipt",
           writable: true,
+          optional: true,
           pda: {
             seeds: [
               {
                 kind: "const",
                 value: [
-                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
                 ],
               },
               {
@@ -403,23 +525,20 @@ export const MultiWalletIdl = {
                 path: "multi_wallet.create_key",
                 account: "MultiWallet",
               },
+              {
+                kind: "arg",
+                path: "offer.nonce",
+              },
             ],
           },
         },
-        {
-          name: "payer",
-          writable: true,
-          signer: true,
-          optional: true,
-        },
         {
           name: "system_program",
-          optional: true,
           address: "11111111111111111111111111111111",
         },
         {
-          name: "instruction_sysvar",
-          address: "Sysvar1nstructions1111111111111111111111111",
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
         },
         {
           name: "event_authority",
@@ -441,38 +560,31 @@ export const MultiWalletIdl = {
       ],
       args: [
         {
-          name: "config_actions",
+          name: "offer",
           type: {
-            vec: {
-              defined: {
-                name: "ConfigAction",
-              },
+            defined: {
+              name: "SignedOffer",
             },
           },
         },
       ],
     },
     {
-      name: "create",
+      name: "cancel_escrow_as_non_owner",
       docs: [
-        "Creates a new multi-wallet.",
+        "Cancels an escrow as a proposer. This function returns the locked funds in the escrow",
+        "vault back to the proposer and invalidates the escrow, preventing further execution.",
         "",
         "# Parameters",
-        "- `ctx`: The context of the multi-wallet creation.",
-        "- `create_key`: The member key used to create the multi-wallet.",
-        "- `metadata`: An optional metadata for the multi-wallet.",
-        "- `label`: An optional label for the multi-wallet.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Result<()>`: The result of the multi-wallet creation.",
+        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
+        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "",
       ],
-      discriminator: [24, 30, 200, 40, 5, 28, 7, 119],
+      discriminator: [219, 234, 45, 214, 232, 65, 209, 96],
       accounts: [
-        {
-          name: "payer",
-          writable: true,
-          signer: true,
-        },
         {
           name: "multi_wallet",
           writable: true,
@@ -485,90 +597,37 @@ export const MultiWalletIdl = {
                 ],
               },
               {
-                kind: "arg",
-                path: "create_key.pubkey",
-              },
-            ],
-          },
-        },
-        {
-          name: "system_program",
-          address: "11111111111111111111111111111111",
-        },
-        {
-          name: "event_authority",
-          pda: {
-            seeds: [
-              {
-                kind: "const",
-                value: [
-                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
-                  114, 105, 116, 121,
-                ],
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
             ],
           },
         },
         {
-          name: "program",
-        },
-      ],
-      args: [
-        {
-          name: "create_key",
-          type: {
-            defined: {
-              name: "Member",
-            },
-          },
-        },
-        {
-          name: "metadata",
-          type: {
-            option: "pubkey",
-          },
-        },
-      ],
-    },
-    {
-      name: "execute_escrow_as_non_owner",
-      docs: [
-        "Executes an escrow. This function transfers funds from the escrow vault",
-        "to the recipient and updates the members of the multi-wallet as specified in the escrow.",
-        "",
-        "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
-        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
-        "- `threshold`: Number of signatures required for the multisig transaction to be approved.",
-        "",
-        "# Returns",
-        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
-        "- `Err`: If any validation fails, the escrow is not locked, or the transfer operation encounters an issue.",
-        "",
-      ],
-      discriminator: [63, 186, 102, 94, 23, 177, 217, 130],
-      accounts: [
-        {
-          name: "multi_wallet",
+          name: "escrow",
           writable: true,
           pda: {
             seeds: [
               {
                 kind: "const",
-                value: [
-                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
-                ],
+                value: [101, 115, 99, 114, 111, 119],
               },
               {
                 kind: "account",
                 path: "escrow.create_key",
                 account: "Escrow",
               },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
             ],
           },
         },
         {
-          name: "escrow",
+          name: "escrow_vault",
           writable: true,
           pda: {
             seeds: [
@@ -586,18 +645,22 @@ export const MultiWalletIdl = {
                 path: "escrow.identifier",
                 account: "Escrow",
               },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
             ],
           },
         },
         {
-          name: "recipient_token_account",
+          name: "escrow_token_vault",
           writable: true,
           optional: true,
           pda: {
             seeds: [
               {
                 kind: "account",
-                path: "recipient",
+                path: "escrow_vault",
               },
               {
                 kind: "account",
@@ -619,14 +682,14 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "payer_token_account",
+          name: "proposer_token_account",
           writable: true,
           optional: true,
           pda: {
             seeds: [
               {
                 kind: "account",
-                path: "payer",
+                path: "proposer",
               },
               {
                 kind: "account",
@@ -649,21 +712,53 @@ export const MultiWalletIdl = {
         },
         {
           name: "mint",
+          writable: true,
           optional: true,
         },
         {
-          name: "recipient",
+          name: "proposer",
           writable: true,
+          signer: true,
         },
         {
-          name: "payer",
+          name: "rent_payer",
           writable: true,
-          signer: true,
         },
         {
           name: "token_program",
           optional: true,
         },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "rent_collector",
+          writable: true,
+          optional: true,
+        },
         {
           name: "system_program",
           address: "11111111111111111111111111111111",
@@ -690,39 +785,23 @@ export const MultiWalletIdl = {
           name: "program",
         },
       ],
-      args: [
-        {
-          name: "new_members",
-          type: {
-            vec: {
-              defined: {
-                name: "Member",
-              },
-            },
-          },
-        },
-        {
-          name: "threshold",
-          type: "u8",
-        },
-      ],
+      args: [],
     },
     {
-      name: "execute_escrow_as_owner",
+      name: "cancel_escrow_as_owner",
       docs: [
-        "Executes an escrow as an owner. This function transfers funds from the escrow vault",
-        "to the recipient, updates the multi-wallet's members as specified in the escrow, and",
-        "ensures the multi-wallet remains valid.",
+        "Cancels an escrow as an owner. This function unlocks the multi-wallet and invalidates the escrow,",
+        "preventing it from being executed.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
-        "- `Err`: If validation fails, the escrow is not properly initialized, or the transfer encounters an issue.",
+        "- `Ok(())`: If the escrow is successfully canceled and the multi-wallet is unlocked.",
+        "- `Err`: If validation fails or the accounts provided are invalid.",
         "",
       ],
-      discriminator: [119, 121, 203, 21, 159, 211, 44, 59],
+      discriminator: [22, 155, 132, 143, 51, 185, 186, 247],
       accounts: [
         {
           name: "multi_wallet",
@@ -768,6 +847,7 @@ export const MultiWalletIdl = {
         {
           name: "escrow_vault",
           writable: true,
+          optional: true,
           pda: {
             seeds: [
               {
@@ -821,14 +901,14 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "recipient_token_account",
+          name: "proposer_token_account",
           writable: true,
           optional: true,
           pda: {
             seeds: [
               {
                 kind: "account",
-                path: "recipient",
+                path: "proposer",
               },
               {
                 kind: "account",
@@ -850,24 +930,62 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "mint",
-          optional: true,
+          name: "proposer",
+          writable: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
         },
         {
           name: "instruction_sysvar",
           address: "Sysvar1nstructions1111111111111111111111111",
         },
         {
-          name: "payer",
+          name: "mint",
           writable: true,
-          signer: true,
+          optional: true,
         },
         {
-          name: "recipient",
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "receipt_payer",
+          docs: ["Pays for the receipt, if one is created."],
           writable: true,
+          signer: true,
+          optional: true,
         },
         {
-          name: "token_program",
+          name: "rent_collector",
+          writable: true,
           optional: true,
         },
         {
@@ -899,23 +1017,21 @@ export const MultiWalletIdl = {
       args: [],
     },
     {
-      name: "initiate_escrow_as_non_owner",
+      name: "cancel_expired_escrow",
       docs: [
-        "Initializes an escrow. This function locks funds into an escrow vault",
-        "and sets up the necessary metadata for the escrow.",
+        "Cancels an expired escrow. Anyone can crank this once the escrow's expiry has passed.",
+        "Locked funds are refunded to the proposer, the escrow rent to the account that paid it, and the offer",
+        "is removed from the multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
-        "- `identifier`: A unique identifier for the escrow, used to distinguish it from others.",
-        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
-        "- `amount`: The amount to be transferred to the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
-        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
+        "- `Err`: If the escrow has not expired or the accounts provided are invalid.",
         "",
       ],
-      discriminator: [207, 60, 39, 74, 34, 156, 127, 180],
+      discriminator: [8, 72, 226, 3, 6, 98, 149, 91],
       accounts: [
         {
           name: "multi_wallet",
@@ -930,8 +1046,8 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "multi_wallet.create_key",
-                account: "MultiWallet",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
             ],
           },
@@ -947,12 +1063,13 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "multi_wallet.create_key",
-                account: "MultiWallet",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
               {
-                kind: "arg",
-                path: "identifier",
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
               },
             ],
           },
@@ -960,6 +1077,7 @@ export const MultiWalletIdl = {
         {
           name: "escrow_vault",
           writable: true,
+          optional: true,
           pda: {
             seeds: [
               {
@@ -968,12 +1086,13 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "multi_wallet.create_key",
-                account: "MultiWallet",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
               {
-                kind: "arg",
-                path: "identifier",
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
               },
               {
                 kind: "const",
@@ -1041,22 +1160,55 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "mint",
-          optional: true,
+          name: "proposer",
+          writable: true,
         },
         {
-          name: "proposer",
+          name: "rent_payer",
           writable: true,
-          signer: true,
         },
         {
-          name: "member",
-          signer: true,
+          name: "mint",
+          writable: true,
+          optional: true,
         },
         {
           name: "token_program",
           optional: true,
         },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "receipt_payer",
+          docs: ["Pays for the receipt, if one is created."],
+          writable: true,
+          signer: true,
+          optional: true,
+        },
         {
           name: "system_program",
           address: "11111111111111111111111111111111",
@@ -1083,50 +1235,19 @@ export const MultiWalletIdl = {
           name: "program",
         },
       ],
-      args: [
-        {
-          name: "identifier",
-          type: "u64",
-        },
-        {
-          name: "new_members",
-          type: {
-            vec: {
-              defined: {
-                name: "Member",
-              },
-            },
-          },
-        },
-        {
-          name: "amount",
-          type: "u64",
-        },
-        {
-          name: "threshold",
-          type: "u8",
-        },
-      ],
+      args: [],
     },
     {
-      name: "initiate_escrow_as_owner",
+      name: "change_config",
       docs: [
-        "Initializes an escrow as an owner. This function locks the multi-wallet",
-        "and prepares the escrow account with the specified metadata and recipient details.",
-        "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for initializing the escrow.",
-        "- `identifier`: A unique identifier for the escrow, used to distinguish it from others.",
-        "- `recipient`: The recipient's account,",
-        "- `amount`: The amount to be transferred.",
-        "- `mint`: Token mint that needs to be transferred(if any)",
+        "- `ctx`: The context of the multi-action execution.",
+        "- `config_actions`: The list of actions to be executed.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully initialized and the multi-wallet is locked.",
-        "- `Err`: If any validation fails or the multi-wallet does not meet the required threshold.",
-        "",
+        "- `Result<()>`: The result of the multi-action execution.",
       ],
-      discriminator: [255, 237, 252, 101, 179, 193, 143, 27],
+      discriminator: [24, 158, 114, 115, 94, 210, 244, 233],
       accounts: [
         {
           name: "multi_wallet",
@@ -1148,39 +1269,51 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "escrow",
+          name: "payer",
+          writable: true,
+          signer: true,
+          optional: true,
+        },
+        {
+          name: "wallet_metadata",
           writable: true,
+          optional: true,
           pda: {
             seeds: [
               {
                 kind: "const",
-                value: [101, 115, 99, 114, 111, 119],
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
               },
               {
                 kind: "account",
-                path: "multi_wallet.create_key",
-                account: "MultiWallet",
+                path: "multi_wallet",
               },
               {
-                kind: "arg",
-                path: "identifier",
+                kind: "const",
+                value: [
+                  119, 97, 108, 108, 101, 116, 95, 109, 101, 116, 97, 100, 97,
+                  116, 97,
+                ],
               },
             ],
           },
         },
         {
-          name: "instruction_sysvar",
-          address: "Sysvar1nstructions1111111111111111111111111",
-        },
-        {
-          name: "payer",
+          name: "rent_collector",
           writable: true,
-          signer: true,
+          optional: true,
         },
         {
           name: "system_program",
+          optional: true,
           address: "11111111111111111111111111111111",
         },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
         {
           name: "event_authority",
           pda: {
@@ -1201,45 +1334,34 @@ export const MultiWalletIdl = {
       ],
       args: [
         {
-          name: "identifier",
-          type: "u64",
-        },
-        {
-          name: "recipient",
-          type: "pubkey",
-        },
-        {
-          name: "amount",
-          type: "u64",
-        },
-        {
-          name: "mint",
+          name: "config_actions",
           type: {
-            option: "pubkey",
+            vec: {
+              defined: {
+                name: "ConfigAction",
+              },
+            },
           },
         },
       ],
     },
     {
-      name: "transaction_buffer_close",
+      name: "close_escrow_receipt",
       docs: [
-        "Closes an existing transaction buffer.",
+        "Closes the receipt of a settled escrow, returning its rent to a member of the multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
+        "- `ctx`: The context containing the receipt and the signing members.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully closed.",
-        "- `Err`: If validation fails or the accounts are invalid.",
+        "- `Ok(())`: If the receipt is closed.",
+        "- `Err`: If the threshold is not met or the rent collector is not a signing member.",
+        "",
       ],
-      discriminator: [17, 182, 208, 228, 136, 24, 178, 102],
+      discriminator: [83, 241, 204, 62, 83, 144, 53, 91],
       accounts: [
         {
           name: "multi_wallet",
-        },
-        {
-          name: "transaction_buffer",
-          writable: true,
           pda: {
             seeds: [
               {
@@ -1250,55 +1372,74 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "multi_wallet",
+                path: "receipt.create_key",
+                account: "EscrowReceipt",
               },
+            ],
+          },
+        },
+        {
+          name: "receipt",
+          writable: true,
+          pda: {
+            seeds: [
               {
                 kind: "const",
                 value: [
-                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
-                  117, 102, 102, 101, 114,
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
                 ],
               },
               {
                 kind: "account",
-                path: "creator",
+                path: "receipt.create_key",
+                account: "EscrowReceipt",
               },
               {
                 kind: "account",
-                path: "transaction_buffer.buffer_index",
-                account: "TransactionBuffer",
+                path: "receipt.identifier",
+                account: "EscrowReceipt",
               },
             ],
           },
         },
         {
-          name: "creator",
-          signer: true,
-        },
-        {
-          name: "rent_payer",
+          name: "rent_collector",
+          docs: [
+            "Member of the multi-wallet receiving the rent of the receipt.",
+          ],
           writable: true,
+          signer: true,
         },
       ],
       args: [],
     },
     {
-      name: "transaction_buffer_create",
+      name: "close_multi_wallet",
       docs: [
-        "Creates a new transaction buffer.",
+        "Closes a multi-wallet with no pending offers or open transaction buffers, returning its rent",
+        "and the rent of its wallet metadata account to a signing member and emitting a final `ConfigEvent`",
+        "without members. The multi-wallet can be re-created by anyone with the same create key, so every",
+        "vault holding assets should be swept.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
-        "- `args`: Arguments for the transaction buffer creation.",
+        "- `ctx`: The context containing the multi-wallet, its wallet metadata PDA and the signing members.",
+        "The vaults to sweep are passed as the first remaining accounts, followed by",
+        "`[token account, mint, destination token account, token program]` for each token account to sweep.",
+        "- `vault_indices`: Indices of the vaults whose lamports are swept to the sweep recipient.",
+        "- `token_vault_indices`: Vault index owning each token account to sweep. The token accounts are",
+        "emptied into their destination and closed to the sweep recipient.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully created.",
-        "- `Err`: If validation fails or the provided arguments are invalid.",
+        "- `Ok(())`: If the multi-wallet is closed.",
+        "- `Err`: If the threshold is not met, offers or buffers are still open, or a vault or token account is invalid.",
+        "",
       ],
-      discriminator: [245, 201, 113, 108, 37, 63, 29, 89],
+      discriminator: [94, 11, 206, 168, 76, 131, 194, 122],
       accounts: [
         {
           name: "multi_wallet",
+          writable: true,
           pda: {
             seeds: [
               {
@@ -1316,7 +1457,7 @@ export const MultiWalletIdl = {
           },
         },
         {
-          name: "transaction_buffer",
+          name: "wallet_metadata",
           writable: true,
           pda: {
             seeds: [
@@ -1333,63 +1474,90 @@ export const MultiWalletIdl = {
               {
                 kind: "const",
                 value: [
-                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
-                  117, 102, 102, 101, 114,
+                  119, 97, 108, 108, 101, 116, 95, 109, 101, 116, 97, 100, 97,
+                  116, 97,
                 ],
               },
-              {
-                kind: "account",
-                path: "creator",
-              },
-              {
-                kind: "arg",
-                path: "args.buffer_index",
-              },
             ],
           },
         },
         {
-          name: "creator",
+          name: "rent_collector",
+          docs: [
+            "Member of the multi-wallet receiving the rent of the multi-wallet.",
+          ],
+          writable: true,
           signer: true,
         },
         {
-          name: "rent_payer",
+          name: "sweep_recipient",
           writable: true,
-          signer: true,
+          optional: true,
         },
         {
           name: "system_program",
           address: "11111111111111111111111111111111",
         },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
       ],
       args: [
         {
-          name: "args",
+          name: "vault_indices",
           type: {
-            defined: {
-              name: "TransactionBufferCreateArgs",
-            },
+            vec: "u16",
+          },
+        },
+        {
+          name: "token_vault_indices",
+          type: {
+            vec: "u16",
           },
         },
       ],
     },
     {
-      name: "transaction_buffer_extend",
+      name: "create",
       docs: [
-        "Extends an existing transaction buffer.",
+        "Creates a new multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
-        "- `args`: Arguments for extending the transaction buffer.",
+        "- `ctx`: The context of the multi-wallet creation.",
+        "- `create_key`: The member key used to create the multi-wallet.",
+        "- `metadata`: An optional metadata for the multi-wallet.",
+        "- `label`: An optional label for the multi-wallet.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully extended.",
-        "- `Err`: If validation fails or the provided arguments are invalid.",
+        "- `Result<()>`: The result of the multi-wallet creation.",
       ],
-      discriminator: [230, 157, 67, 56, 5, 238, 245, 146],
+      discriminator: [24, 30, 200, 40, 5, 28, 7, 119],
       accounts: [
         {
-          name: "transaction_buffer",
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "multi_wallet",
           writable: true,
           pda: {
             seeds: [
@@ -1400,63 +1568,70 @@ export const MultiWalletIdl = {
                 ],
               },
               {
-                kind: "account",
-                path: "transaction_buffer.multi_wallet",
-                account: "TransactionBuffer",
-              },
-              {
-                kind: "const",
-                value: [
-                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
-                  117, 102, 102, 101, 114,
-                ],
-              },
-              {
-                kind: "account",
-                path: "creator",
-              },
-              {
-                kind: "account",
-                path: "transaction_buffer.buffer_index",
-                account: "TransactionBuffer",
+                kind: "arg",
+                path: "create_key.pubkey",
               },
             ],
           },
         },
         {
-          name: "creator",
-          signer: true,
+          name: "system_program",
+          address: "11111111111111111111111111111111",
         },
-      ],
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
       args: [
         {
-          name: "args",
+          name: "create_key",
           type: {
             defined: {
-              name: "TransactionBufferExtendArgs",
+              name: "Member",
             },
           },
         },
+        {
+          name: "metadata",
+          type: {
+            option: "pubkey",
+          },
+        },
       ],
     },
     {
-      name: "vault_transaction_execute",
+      name: "escrow_approve",
       docs: [
-        "Executes a vault transaction.",
+        "Records the approval of an escrow by a member with the `VoteEscrow` permission. Recorded approvals",
+        "count towards the threshold of `execute_escrow_as_owner` along with the signers of the execution.",
+        "Room for the votes is reserved when the escrow is created, so voting costs no rent.",
         "",
         "# Parameters",
-        "- `ctx`: The context of the vault transaction execution.",
-        "- `vault_index`: The index of the vault.",
-        "- `transaction_message`: The transaction message to be executed.",
+        "- `ctx`: The context containing the escrow and the voting member.",
         "",
         "# Returns",
-        "- `Result<()>`: The result of the vault transaction execution.",
+        "- `Ok(())`: If the vote is recorded, replacing any previous vote of the member.",
+        "- `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.",
+        "",
       ],
-      discriminator: [194, 8, 161, 87, 153, 164, 25, 171],
+      discriminator: [6, 225, 57, 211, 235, 164, 41, 104],
       accounts: [
         {
-          name: "transaction_buffer",
-          writable: true,
+          name: "multi_wallet",
           pda: {
             seeds: [
               {
@@ -1467,73 +1642,2820 @@ export const MultiWalletIdl = {
               },
               {
                 kind: "account",
-                path: "transaction_buffer.multi_wallet",
-                account: "TransactionBuffer",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
               {
                 kind: "const",
-                value: [
-                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
-                  117, 102, 102, 101, 114,
-                ],
+                value: [101, 115, 99, 114, 111, 119],
               },
               {
                 kind: "account",
-                path: "transaction_buffer.creator",
-                account: "TransactionBuffer",
+                path: "escrow.create_key",
+                account: "Escrow",
               },
               {
                 kind: "account",
-                path: "transaction_buffer.buffer_index",
-                account: "TransactionBuffer",
+                path: "escrow.identifier",
+                account: "Escrow",
               },
             ],
           },
         },
+        {
+          name: "member",
+          signer: true,
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "escrow_counter_offer",
+      docs: [
+        "Proposes new terms on a non-owner escrow as an owner. The proposer can accept them",
+        "through `accept_escrow_counter_offer`, after which the owners can execute the escrow.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for the counter-offer.",
+        "- `amount`: The amount the owners ask for.",
+        "- `mint`: Token mint the amount is denominated in (if any).",
+        "- `threshold`: The threshold of the multi-wallet once the escrow is executed.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the counter-offer is recorded on the escrow.",
+        "- `Err`: If validation fails or the escrow is not a pending non-owner escrow.",
+        "",
+      ],
+      discriminator: [106, 34, 157, 83, 114, 41, 235, 32],
+      accounts: [
         {
           name: "multi_wallet",
-          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
         },
         {
-          name: "rent_payer",
+          name: "escrow",
           writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
         },
         {
           name: "instruction_sysvar",
           address: "Sysvar1nstructions1111111111111111111111111",
         },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
       ],
       args: [
         {
-          name: "vault_index",
-          type: "u16",
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "mint",
+          type: {
+            option: "pubkey",
+          },
+        },
+        {
+          name: "threshold",
+          type: "u8",
         },
       ],
     },
-  ],
-  accounts: [
-    {
-      name: "Escrow",
-      discriminator: [31, 213, 123, 187, 186, 22, 218, 155],
-    },
-    {
-      name: "MultiWallet",
-      discriminator: [100, 242, 252, 66, 54, 82, 77, 90],
-    },
-    {
-      name: "TransactionBuffer",
-      discriminator: [90, 36, 35, 219, 93, 225, 110, 96],
-    },
-  ],
-  events: [
     {
-      name: "ConfigEvent",
-      discriminator: [162, 6, 172, 68, 201, 128, 119, 230],
+      name: "escrow_reject",
+      docs: [
+        "Records the rejection of an escrow by a member with the `VoteEscrow` permission. Recorded rejections",
+        "count towards the threshold of `cancel_escrow_as_owner` along with the signers of the cancellation.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the escrow and the voting member.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the vote is recorded, replacing any previous vote of the member.",
+        "- `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.",
+        "",
+      ],
+      discriminator: [51, 223, 199, 222, 61, 137, 12, 158],
+      accounts: [
+        {
+          name: "multi_wallet",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "member",
+          signer: true,
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [],
     },
     {
-      name: "EscrowEvent",
-      discriminator: [241, 51, 61, 3, 5, 32, 113, 144],
-    },
+      name: "execute_escrow_as_non_owner",
+      docs: [
+        "Executes an escrow. This function transfers funds from the escrow vault",
+        "to the recipient and updates the members of the multi-wallet as specified in the escrow.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
+        "- `threshold`: Number of signatures required for the multisig transaction to be approved.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
+        "- `Err`: If any validation fails, the escrow is not locked, or the transfer operation encounters an issue.",
+        "",
+      ],
+      discriminator: [63, 186, 102, 94, 23, 177, 217, 130],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "recipient_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "payer_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "payer",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "protocol_config",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "protocol_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "protocol_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "protocol_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "marketplace_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "marketplace_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "marketplace_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          optional: true,
+        },
+        {
+          name: "recipient",
+          writable: true,
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "rent_collector",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "new_members",
+          type: {
+            vec: {
+              defined: {
+                name: "Member",
+              },
+            },
+          },
+        },
+        {
+          name: "threshold",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "execute_escrow_as_owner",
+      docs: [
+        "Executes an escrow as an owner. This function transfers funds from the escrow vault",
+        "to the recipient, updates the multi-wallet's members as specified in the escrow, and",
+        "ensures the multi-wallet remains valid.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "The escrow rent payer receives the escrow rent and the rent of its emptied vault token accounts.",
+        "- `marketplace_fee`: The marketplace fee the owners agree to pay out of the proceeds. Must match the escrow.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
+        "- `Err`: If validation fails, the escrow is not properly initialized, or the transfer encounters an issue.",
+        "",
+      ],
+      discriminator: [119, 121, 203, 21, 159, 211, 44, 59],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "recipient_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "protocol_config",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "protocol_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "protocol_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "protocol_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "marketplace_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "marketplace_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "marketplace_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "recipient",
+          writable: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+        {
+          name: "rent_collector",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "marketplace_fee",
+          type: {
+            option: {
+              defined: {
+                name: "MarketplaceFee",
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "initialize_protocol_config",
+      docs: [
+        "Initializes the protocol config. Only the program upgrade authority can do this.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the protocol config.",
+        "- `fee_recipient`: The account receiving the protocol fee on escrow settlements.",
+        "- `fee_bps`: The protocol fee in basis points.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the protocol config is successfully initialized.",
+        "- `Err`: If the signer is not the upgrade authority or the fee is invalid.",
+        "",
+      ],
+      discriminator: [28, 50, 43, 233, 244, 98, 123, 118],
+      accounts: [
+        {
+          name: "protocol_config",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+          address: "mu1LDWh4VGHhnZHB85s92HNBapj3b9s5DgzTkiAyeKY",
+        },
+        {
+          name: "program_data",
+        },
+        {
+          name: "authority",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+      ],
+      args: [
+        {
+          name: "fee_recipient",
+          type: "pubkey",
+        },
+        {
+          name: "fee_bps",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "initiate_auction",
+      docs: [
+        "Initializes an auction as an owner. This function locks the multi-wallet and lists it",
+        "either as an English auction, won by the highest bid at the deadline, or as a Dutch auction,",
+        "whose price decreases linearly until it is bought through `execute_escrow_as_non_owner`.",
+        "A Dutch auction expires at `ends_at` and can then be cancelled through `cancel_expired_escrow`.",
+        "The escrow identifier is allocated from the escrow counter of the multi-wallet.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the auction.",
+        "- `recipient`: The account receiving the proceeds of the sale.",
+        "- `mint`: Token mint the auction is priced in (if any).",
+        "- `kind`: The auction kind and its pricing parameters.",
+        "- `ends_at`: Unix timestamp at which the auction ends.",
+        "- `marketplace_fee`: Optional fee paid to the marketplace the auction was created through.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the auction is successfully initialized and the multi-wallet is locked.",
+        "- `Err`: If any validation fails or the auction parameters are invalid.",
+        "",
+      ],
+      discriminator: [126, 249, 108, 77, 47, 189, 128, 12],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.escrow_counter",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.escrow_counter",
+                account: "MultiWallet",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "recipient",
+          type: "pubkey",
+        },
+        {
+          name: "mint",
+          type: {
+            option: "pubkey",
+          },
+        },
+        {
+          name: "kind",
+          type: {
+            defined: {
+              name: "AuctionKind",
+            },
+          },
+        },
+        {
+          name: "ends_at",
+          type: "i64",
+        },
+        {
+          name: "marketplace_fee",
+          type: {
+            option: {
+              defined: {
+                name: "MarketplaceFee",
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "initiate_escrow_as_non_owner",
+      docs: [
+        "Initializes an escrow. This function locks funds into an escrow vault",
+        "and sets up the necessary metadata for the escrow. The escrow identifier is",
+        "allocated from the escrow counter of the multi-wallet.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
+        "The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.",
+        "- `args`: Arguments for the escrow initialization.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
+        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "",
+      ],
+      discriminator: [207, 60, 39, 74, 34, 156, 127, 180],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.escrow_counter",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.escrow_counter",
+                account: "MultiWallet",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "proposer_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "proposer",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          optional: true,
+        },
+        {
+          name: "proposer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "member",
+          signer: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: {
+              name: "InitializeEscrowAsNonOwnerArgs",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "initiate_escrow_as_non_owner_with_identifier",
+      docs: [
+        "Initializes an escrow under a client-chosen identifier, as `initiate_escrow_as_non_owner` does",
+        "with an identifier allocated on-chain. Client-chosen identifiers start at `CLIENT_ESCROW_IDENTIFIER_START`.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
+        "The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.",
+        "- `identifier`: The identifier of the escrow.",
+        "- `args`: Arguments for the escrow initialization.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
+        "- `Err`: If the identifier is out of range or already in use, or any validation or transfer fails.",
+        "",
+      ],
+      discriminator: [85, 227, 1, 232, 102, 67, 196, 180],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "arg",
+                path: "identifier",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "arg",
+                path: "identifier",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "proposer_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "proposer",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          optional: true,
+        },
+        {
+          name: "proposer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "member",
+          signer: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "identifier",
+          type: "u64",
+        },
+        {
+          name: "args",
+          type: {
+            defined: {
+              name: "InitializeEscrowAsNonOwnerArgs",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "initiate_escrow_as_owner",
+      docs: [
+        "Initializes an escrow as an owner. This function locks the multi-wallet",
+        "and prepares the escrow account with the specified metadata and recipient details.",
+        "The escrow identifier is allocated from the escrow counter of the multi-wallet.",
+        "Votes are recorded on an existing escrow, so the threshold of `VoteEscrow` members must sign",
+        "this instruction itself.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the escrow.",
+        "- `args`: Arguments for the escrow initialization, including the buyers allowed to execute it.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and the multi-wallet is locked.",
+        "- `Err`: If any validation fails or the multi-wallet does not meet the required threshold.",
+        "",
+      ],
+      discriminator: [255, 237, 252, 101, 179, 193, 143, 27],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.escrow_counter",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: {
+              name: "InitializeEscrowAsOwnerArgs",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "migrate_escrow",
+      docs: [
+        "Upgrades an escrow created before escrows were versioned to the current layout. Escrows in the old",
+        "layout are rejected by every other escrow instruction until they are migrated. Anyone can migrate an",
+        "escrow once its multi-wallet is migrated. Owner listings refund their rent to the default vault.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet, the escrow and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is upgraded in place, or grown towards its upgraded size.",
+        "- `Err`: If the account is not an escrow of the multi-wallet or is already up to date.",
+        "",
+      ],
+      discriminator: [65, 111, 186, 119, 58, 11, 81, 209],
+      accounts: [
+        {
+          name: "multi_wallet",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrate_multi_wallet",
+      docs: [
+        "Upgrades a multi-wallet created with an older account layout to the current version.",
+        "Anyone can migrate a multi-wallet; its configuration is left unchanged. Accounts growing by more than",
+        "10240 bytes are grown over several calls, and the upgrade is written by the last one.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the multi-wallet is upgraded in place, or grown towards its upgraded size.",
+        "- `Err`: If the account is not a multi-wallet or is already up to date.",
+        "",
+      ],
+      discriminator: [66, 38, 197, 14, 218, 89, 208, 179],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrate_transaction_buffer",
+      docs: [
+        "Upgrades a transaction buffer created before buffers were versioned to the current layout, giving",
+        "it a transaction nonce and counting it as an open buffer of the multi-wallet. Buffers in the old",
+        "layout are rejected by every other buffer instruction until they are migrated. Buffers too large",
+        "for the current layout are closed and their rent refunded.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet, the buffer, its rent payer and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the buffer is upgraded in place or closed.",
+        "- `Err`: If the account is not a buffer of the multi-wallet or is already up to date.",
+        "",
+      ],
+      discriminator: [211, 138, 113, 197, 247, 161, 124, 152],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "transaction_buffer",
+          writable: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "place_auction_bid",
+      docs: [
+        "Places a bid on an English auction. The bid is locked in the escrow vault and the",
+        "previous highest bid is refunded to its bidder.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for placing the bid.",
+        "- `amount`: The amount bid.",
+        "- `new_members`: The members of the multi-wallet if the bid wins.",
+        "- `threshold`: The threshold of the multi-wallet if the bid wins.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the bid is placed and the previous bid is refunded.",
+        "- `Err`: If the auction has ended or the bid is too low.",
+        "",
+      ],
+      discriminator: [38, 170, 233, 87, 52, 65, 23, 128],
+      accounts: [
+        {
+          name: "multi_wallet",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "bidder_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "bidder",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "previous_bidder",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "previous_bidder_token_account",
+          docs: [
+            "Created if the outbid bidder closed it, so that the refund cannot be blocked.",
+          ],
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "previous_bidder",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "bidder",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "new_members",
+          type: {
+            vec: {
+              defined: {
+                name: "Member",
+              },
+            },
+          },
+        },
+        {
+          name: "threshold",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "settle_auction",
+      docs: [
+        "Settles an English auction once it has ended. Anyone can crank this. The highest bid",
+        "is paid to the recipient and the members of the multi-wallet are handed over to the winner.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for settling the auction.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the auction is settled, or lapses without a bid.",
+        "- `Err`: If the auction has not ended or the accounts provided are invalid.",
+        "",
+      ],
+      discriminator: [246, 196, 183, 98, 222, 139, 46, 133],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_vault",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [101, 115, 99, 114, 111, 119],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+              {
+                kind: "const",
+                value: [118, 97, 117, 108, 116],
+              },
+            ],
+          },
+        },
+        {
+          name: "escrow_token_vault",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "escrow_vault",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "recipient_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "protocol_config",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "protocol_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "protocol_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "protocol_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "marketplace_fee_recipient",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "marketplace_fee_token_account",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "account",
+                path: "marketplace_fee_recipient",
+              },
+              {
+                kind: "account",
+                path: "token_program",
+              },
+              {
+                kind: "account",
+                path: "mint",
+              },
+            ],
+            program: {
+              kind: "const",
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89,
+              ],
+            },
+          },
+        },
+        {
+          name: "mint",
+          writable: true,
+          optional: true,
+        },
+        {
+          name: "payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+        {
+          name: "recipient",
+          writable: true,
+        },
+        {
+          name: "token_program",
+          optional: true,
+        },
+        {
+          name: "receipt",
+          writable: true,
+          optional: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  101, 115, 99, 114, 111, 119, 95, 114, 101, 99, 101, 105, 112,
+                  116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "escrow.create_key",
+                account: "Escrow",
+              },
+              {
+                kind: "account",
+                path: "escrow.identifier",
+                account: "Escrow",
+              },
+            ],
+          },
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+        {
+          name: "associated_token_program",
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "transaction_buffer_close",
+      docs: [
+        "Closes an existing transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully closed.",
+        "- `Err`: If validation fails or the accounts are invalid.",
+      ],
+      discriminator: [17, 182, 208, 228, 136, 24, 178, 102],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+        },
+        {
+          name: "transaction_buffer",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet",
+              },
+              {
+                kind: "const",
+                value: [
+                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
+                  117, 102, 102, 101, 114,
+                ],
+              },
+              {
+                kind: "account",
+                path: "creator",
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.buffer_index",
+                account: "TransactionBuffer",
+              },
+            ],
+          },
+        },
+        {
+          name: "creator",
+          signer: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "transaction_buffer_create",
+      docs: [
+        "Creates a new transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "- `args`: Arguments for the transaction buffer creation.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully created.",
+        "- `Err`: If validation fails or the provided arguments are invalid.",
+      ],
+      discriminator: [245, 201, 113, 108, 37, 63, 29, 89],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "transaction_buffer",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet",
+              },
+              {
+                kind: "const",
+                value: [
+                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
+                  117, 102, 102, 101, 114,
+                ],
+              },
+              {
+                kind: "account",
+                path: "creator",
+              },
+              {
+                kind: "arg",
+                path: "args.buffer_index",
+              },
+            ],
+          },
+        },
+        {
+          name: "creator",
+          signer: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+          signer: true,
+        },
+        {
+          name: "system_program",
+          address: "11111111111111111111111111111111",
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: {
+              name: "TransactionBufferCreateArgs",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "transaction_buffer_extend",
+      docs: [
+        "Extends an existing transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "- `args`: Arguments for extending the transaction buffer.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully extended.",
+        "- `Err`: If validation fails, the multi-wallet is listed or the provided arguments are invalid.",
+      ],
+      discriminator: [230, 157, 67, 56, 5, 238, 245, 146],
+      accounts: [
+        {
+          name: "multi_wallet",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "transaction_buffer",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.multi_wallet",
+                account: "TransactionBuffer",
+              },
+              {
+                kind: "const",
+                value: [
+                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
+                  117, 102, 102, 101, 114,
+                ],
+              },
+              {
+                kind: "account",
+                path: "creator",
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.buffer_index",
+                account: "TransactionBuffer",
+              },
+            ],
+          },
+        },
+        {
+          name: "creator",
+          signer: true,
+        },
+      ],
+      args: [
+        {
+          name: "args",
+          type: {
+            defined: {
+              name: "TransactionBufferExtendArgs",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "update_protocol_config",
+      docs: [
+        "Updates the protocol config.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for updating the protocol config.",
+        "- `authority`: The new authority of the protocol config.",
+        "- `fee_recipient`: The account receiving the protocol fee on escrow settlements.",
+        "- `fee_bps`: The protocol fee in basis points.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the protocol config is successfully updated.",
+        "- `Err`: If the signer is not the protocol config authority or the fee is invalid.",
+        "",
+      ],
+      discriminator: [197, 97, 123, 54, 221, 168, 11, 135],
+      accounts: [
+        {
+          name: "protocol_config",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  112, 114, 111, 116, 111, 99, 111, 108, 95, 99, 111, 110, 102,
+                  105, 103,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "authority",
+          signer: true,
+        },
+      ],
+      args: [
+        {
+          name: "authority",
+          type: "pubkey",
+        },
+        {
+          name: "fee_recipient",
+          type: "pubkey",
+        },
+        {
+          name: "fee_bps",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "vault_transaction_execute",
+      docs: [
+        "Executes a vault transaction.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction execution.",
+        "- `vault_index`: The index of the vault.",
+        "- `forward_return_data`: Whether to forward the return data of the last executed instruction.",
+        "",
+        "# Returns",
+        "- `Result<()>`: The result of the vault transaction execution.",
+      ],
+      discriminator: [194, 8, 161, 87, 153, 164, 25, 171],
+      accounts: [
+        {
+          name: "transaction_buffer",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.multi_wallet",
+                account: "TransactionBuffer",
+              },
+              {
+                kind: "const",
+                value: [
+                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
+                  117, 102, 102, 101, 114,
+                ],
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.creator",
+                account: "TransactionBuffer",
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.buffer_index",
+                account: "TransactionBuffer",
+              },
+            ],
+          },
+        },
+        {
+          name: "multi_wallet",
+          writable: true,
+        },
+        {
+          name: "rent_payer",
+          writable: true,
+        },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "vault_index",
+          type: "u16",
+        },
+        {
+          name: "forward_return_data",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "vault_transaction_execute_inline",
+      docs: [
+        "Executes a vault transaction whose message is passed directly as instruction data,",
+        "skipping the transaction buffer for messages small enough to fit in a single transaction.",
+        "Balance assertions are stored on transaction buffers, so inline execution does not check any;",
+        "use a transaction buffer when the vault balances must be asserted after execution.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction execution.",
+        "- `vault_index`: The index of the vault.",
+        "- `transaction_message`: The transaction message to be executed.",
+        "- `forward_return_data`: Whether to forward the return data of the last executed instruction.",
+        "",
+        "# Returns",
+        "- `Result<()>`: The result of the vault transaction execution.",
+      ],
+      discriminator: [221, 143, 99, 229, 205, 205, 164, 198],
+      accounts: [
+        {
+          name: "multi_wallet",
+          writable: true,
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "multi_wallet.create_key",
+                account: "MultiWallet",
+              },
+            ],
+          },
+        },
+        {
+          name: "instruction_sysvar",
+          address: "Sysvar1nstructions1111111111111111111111111",
+        },
+        {
+          name: "event_authority",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121,
+                ],
+              },
+            ],
+          },
+        },
+        {
+          name: "program",
+        },
+      ],
+      args: [
+        {
+          name: "vault_index",
+          type: "u16",
+        },
+        {
+          name: "transaction_message",
+          type: {
+            defined: {
+              name: "TransactionMessage",
+            },
+          },
+        },
+        {
+          name: "forward_return_data",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "vault_transaction_validate",
+      docs: [
+        "Validates a buffered vault transaction against the supplied accounts without executing it.",
+        "No member signatures are required, so backends can check a buffer before collecting them.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction validation.",
+        "- `vault_index`: The index of the vault.",
+        "",
+        "# Returns",
+        "- `Ok(VaultTransactionSummary)`: The accounts, programs and writable set of the transaction, set as return data.",
+        "- `Err`: If the buffer does not decode or the accounts do not match the message.",
+      ],
+      discriminator: [173, 97, 248, 164, 41, 254, 227, 231],
+      accounts: [
+        {
+          name: "transaction_buffer",
+          pda: {
+            seeds: [
+              {
+                kind: "const",
+                value: [
+                  109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
+                ],
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.multi_wallet",
+                account: "TransactionBuffer",
+              },
+              {
+                kind: "const",
+                value: [
+                  116, 114, 97, 110, 115, 97, 99, 116, 105, 111, 110, 95, 98,
+                  117, 102, 102, 101, 114,
+                ],
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.creator",
+                account: "TransactionBuffer",
+              },
+              {
+                kind: "account",
+                path: "transaction_buffer.buffer_index",
+                account: "TransactionBuffer",
+              },
+            ],
+          },
+        },
+        {
+          name: "multi_wallet",
+        },
+      ],
+      args: [
+        {
+          name: "vault_index",
+          type: "u16",
+        },
+      ],
+      returns: {
+        defined: {
+          name: "VaultTransactionSummary",
+        },
+      },
+    },
+  ],
+  accounts: [
+    {
+      name: "Escrow",
+      discriminator: [31, 213, 123, 187, 186, 22, 218, 155],
+    },
+    {
+      name: "EscrowReceipt",
+      discriminator: [5, 104, 57, 114, 177, 192, 135, 10],
+    },
+    {
+      name: "MultiWallet",
+      discriminator: [100, 242, 252, 66, 54, 82, 77, 90],
+    },
+    {
+      name: "ProtocolConfig",
+      discriminator: [207, 91, 250, 28, 152, 179, 215, 209],
+    },
+    {
+      name: "TransactionBuffer",
+      discriminator: [90, 36, 35, 219, 93, 225, 110, 96],
+    },
+  ],
+  events: [
+    {
+      name: "ConfigEvent",
+      discriminator: [162, 6, 172, 68, 201, 128, 119, 230],
+    },
+    {
+      name: "EscrowEvent",
+      discriminator: [241, 51, 61, 3, 5, 32, 113, 144],
+    },
+    {
+      name: "EscrowVoteEvent",
+      discriminator: [253, 40, 255, 152, 159, 73, 11, 27],
+    },
+    {
+      name: "VaultTransactionEvent",
+      discriminator: [143, 5, 230, 245, 90, 196, 197, 129],
+    },
   ],
   errors: [
     {
@@ -1637,109 +4559,1013 @@ export const MultiWalletIdl = {
       msg: "Require threshold to be lesser than or equal to the number of members with vote permission.",
     },
     {
-      code: 6020,
-      name: "UnauthorisedToAcceptEscrowOffer",
-      msg: "You do not have permission to accept this offer.",
+      code: 6020,
+      name: "UnauthorisedToAcceptEscrowOffer",
+      msg: "You do not have permission to accept this offer.",
+    },
+    {
+      code: 6021,
+      name: "UnauthorisedToModifyBuffer",
+      msg: "Only the creator of the transaction buffer have permission to modify the buffer.",
+    },
+    {
+      code: 6022,
+      name: "FinalBufferHashMismatch",
+      msg: "Final message buffer hash doesnt match the expected hash",
+    },
+    {
+      code: 6023,
+      name: "FinalBufferSizeExceeded",
+      msg: "Final buffer size cannot exceed 4000 bytes",
+    },
+    {
+      code: 6024,
+      name: "FinalBufferSizeMismatch",
+      msg: "Final buffer size mismatch",
+    },
+    {
+      code: 6025,
+      name: "TooManyEphemeralSigners",
+      msg: "The transaction buffer declares too many ephemeral signers.",
+    },
+    {
+      code: 6026,
+      name: "TransactionBufferAlreadyMigrated",
+      msg: "The transaction buffer already uses the current account layout.",
+    },
+    {
+      code: 6027,
+      name: "TransactionBufferNotMigrated",
+      msg: "The transaction buffer uses an old account layout and must be migrated first.",
+    },
+    {
+      code: 6028,
+      name: "BalanceAssertionFailed",
+      msg: "A balance assertion on the transaction buffer was violated after execution.",
+    },
+    {
+      code: 6029,
+      name: "TooManyBalanceAssertions",
+      msg: "The transaction buffer has too many balance assertions.",
+    },
+    {
+      code: 6030,
+      name: "InvalidEscrowExpiry",
+      msg: "The escrow expiry must be in the future.",
+    },
+    {
+      code: 6031,
+      name: "EscrowExpired",
+      msg: "The escrow has expired.",
+    },
+    {
+      code: 6032,
+      name: "EscrowNotExpired",
+      msg: "The escrow has not expired yet.",
+    },
+    {
+      code: 6033,
+      name: "EscrowAlreadyMigrated",
+      msg: "The escrow already uses the current account layout.",
+    },
+    {
+      code: 6034,
+      name: "EscrowNotMigrated",
+      msg: "The escrow uses an old account layout and must be migrated first.",
+    },
+    {
+      code: 6035,
+      name: "TooManyPendingOffers",
+      msg: "Too many pending offers on the multi-wallet. Wait for existing offers to be settled or cancelled.",
+    },
+    {
+      code: 6036,
+      name: "InvalidAuction",
+      msg: "Invalid auction parameters or the escrow is not an auction of the expected kind.",
+    },
+    {
+      code: 6037,
+      name: "AuctionEnded",
+      msg: "The auction has already ended.",
+    },
+    {
+      code: 6038,
+      name: "AuctionNotEnded",
+      msg: "The auction has not ended yet.",
+    },
+    {
+      code: 6039,
+      name: "AuctionBidTooLow",
+      msg: "The bid must meet the reserve price and exceed the highest bid by the minimum increment.",
+    },
+    {
+      code: 6040,
+      name: "InvalidFeeBasisPoints",
+      msg: "Fees cannot exceed 10,000 basis points in total.",
+    },
+    {
+      code: 6041,
+      name: "InvalidFeeRecipient",
+      msg: "The fee recipient must match the account stated in the protocol config or the escrow.",
+    },
+    {
+      code: 6042,
+      name: "UnauthorisedToUpdateProtocolConfig",
+      msg: "Only the protocol config authority can update the protocol config.",
+    },
+    {
+      code: 6043,
+      name: "MarketplaceFeeNotApproved",
+      msg: "The owners did not approve the marketplace fee of the escrow.",
+    },
+    {
+      code: 6044,
+      name: "MissingCounterOffer",
+      msg: "The escrow has no pending counter-offer.",
+    },
+    {
+      code: 6045,
+      name: "AssetManifestTooLong",
+      msg: "The escrow asset manifest cannot have more than 16 entries.",
+    },
+    {
+      code: 6046,
+      name: "AssetManifestMismatch",
+      msg: "The multi-wallet vaults no longer hold the assets listed in the escrow asset manifest.",
+    },
+    {
+      code: 6047,
+      name: "PendingOffersExist",
+      msg: "The multi-wallet cannot be listed while offers are pending. Cancel them first.",
+    },
+    {
+      code: 6048,
+      name: "UnsupportedMintTransferHook",
+      msg: "Escrows do not support mints with a transfer hook.",
+    },
+    {
+      code: 6049,
+      name: "UnsupportedMintNonTransferable",
+      msg: "Escrows do not support non-transferable mints.",
+    },
+    {
+      code: 6050,
+      name: "UnsupportedMintConfidentialTransfer",
+      msg: "Escrows do not support mints with confidential transfers.",
+    },
+    {
+      code: 6051,
+      name: "TransferFeeCalculationFailed",
+      msg: "Failed to calculate the transfer fee of the mint.",
+    },
+    {
+      code: 6052,
+      name: "UnsupportedMintPermanentDelegate",
+      msg: "Escrows do not support mints with a permanent delegate.",
+    },
+    {
+      code: 6053,
+      name: "UnsupportedMintCloseAuthority",
+      msg: "Escrows do not support mints with a close authority.",
+    },
+    {
+      code: 6054,
+      name: "UnsupportedMintDefaultFrozen",
+      msg: "Escrows do not support mints whose token accounts are frozen by default.",
+    },
+    {
+      code: 6055,
+      name: "InvalidPaymentLegs",
+      msg: "Escrow payment legs must be non-zero, pay in distinct assets and not exceed the maximum count.",
+    },
+    {
+      code: 6056,
+      name: "InvalidSeat",
+      msg: "The seat must belong to a current member and be taken by a non-member.",
+    },
+    {
+      code: 6057,
+      name: "TooManyEscrowVotes",
+      msg: "The escrow has no room left to record another vote.",
+    },
+    {
+      code: 6058,
+      name: "TooManyAllowedBuyers",
+      msg: "Too many allowed buyers on the escrow listing.",
+    },
+    {
+      code: 6059,
+      name: "BuyerNotAllowed",
+      msg: "The buyer is not allowed to execute this escrow listing.",
+    },
+    {
+      code: 6060,
+      name: "InvalidOfferSignature",
+      msg: "No ed25519 instruction verifies the buyer's signature of the offer.",
+    },
+    {
+      code: 6061,
+      name: "SignedOfferAlreadyAccepted",
+      msg: "The signed offer has already been accepted.",
+    },
+    {
+      code: 6062,
+      name: "EscrowIdentifierInUse",
+      msg: "An escrow with this identifier already exists.",
+    },
+    {
+      code: 6063,
+      name: "InvalidEscrowIdentifier",
+      msg: "The escrow identifier is outside of the allowed range.",
+    },
+    {
+      code: 6064,
+      name: "TooManyOpenBuffers",
+      msg: "The multi-wallet has too many open transaction buffers.",
+    },
+    {
+      code: 6065,
+      name: "OpenBuffersExist",
+      msg: "The multi-wallet still has open transaction buffers.",
+    },
+    {
+      code: 6066,
+      name: "VaultHoldsData",
+      msg: "A vault holds account data and cannot be swept.",
+    },
+    {
+      code: 6067,
+      name: "MultiWalletAlreadyMigrated",
+      msg: "The multi-wallet already uses the current account layout.",
+    },
+    {
+      code: 6068,
+      name: "MultiWalletNotMigrated",
+      msg: "The multi-wallet uses an old account layout and must be migrated first.",
+    },
+    {
+      code: 6069,
+      name: "WalletMetadataTooLarge",
+      msg: "The wallet metadata exceeds its size limits.",
+    },
+    {
+      code: 6070,
+      name: "MemberLabelTooLong",
+      msg: "A member label is longer than allowed.",
+    },
+  ],
+  types: [
+    {
+      name: "Auction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "kind",
+            type: {
+              defined: {
+                name: "AuctionKind",
+              },
+            },
+          },
+          {
+            name: "starts_at",
+            type: "i64",
+          },
+          {
+            name: "ends_at",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AuctionKind",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "English",
+            fields: [
+              {
+                name: "reserve_price",
+                type: "u64",
+              },
+              {
+                name: "min_increment",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "Dutch",
+            fields: [
+              {
+                name: "start_price",
+                type: "u64",
+              },
+              {
+                name: "end_price",
+                type: "u64",
+              },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: "BalanceAssertion",
+      docs: [
+        "A condition on an account balance that must hold after a vault transaction is executed.",
+      ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "MaxLamportsDecrease",
+            fields: [
+              {
+                name: "account",
+                type: "pubkey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "MaxTokenDecrease",
+            fields: [
+              {
+                name: "token_account",
+                type: "pubkey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "MinTokenBalance",
+            fields: [
+              {
+                name: "token_account",
+                type: "pubkey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: "CompiledInstruction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "program_id_index",
+            type: "u8",
+          },
+          {
+            name: "account_indexes",
+            docs: [
+              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction.",
+            ],
+            type: "bytes",
+          },
+          {
+            name: "data",
+            docs: ["Instruction data."],
+            type: "bytes",
+          },
+        ],
+      },
     },
     {
-      code: 6021,
-      name: "UnauthorisedToModifyBuffer",
-      msg: "Only the creator of the transaction buffer have permission to modify the buffer.",
+      name: "ConfigAction",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "SetMembers",
+            fields: [
+              {
+                vec: {
+                  defined: {
+                    name: "Member",
+                  },
+                },
+              },
+            ],
+          },
+          {
+            name: "AddMembers",
+            fields: [
+              {
+                vec: {
+                  defined: {
+                    name: "Member",
+                  },
+                },
+              },
+            ],
+          },
+          {
+            name: "RemoveMembers",
+            fields: [
+              {
+                vec: "pubkey",
+              },
+            ],
+          },
+          {
+            name: "SetThreshold",
+            fields: ["u8"],
+          },
+          {
+            name: "SetMetadata",
+            fields: [
+              {
+                option: "pubkey",
+              },
+            ],
+          },
+          {
+            name: "UpdateMetadata",
+            fields: [
+              {
+                defined: {
+                  name: "WalletMetadataUpdate",
+                },
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      code: 6022,
-      name: "FinalBufferHashMismatch",
-      msg: "Final message buffer hash doesnt match the expected hash",
+      name: "ConfigEvent",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "members",
+            type: {
+              vec: {
+                defined: {
+                  name: "Member",
+                },
+              },
+            },
+          },
+          {
+            name: "threshold",
+            type: "u8",
+          },
+          {
+            name: "metadata",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "wallet_metadata",
+            docs: [
+              "Content written to the wallet metadata account by this instruction, if any.",
+            ],
+            type: {
+              option: {
+                defined: {
+                  name: "WalletMetadataUpdate",
+                },
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6023,
-      name: "FinalBufferSizeExceeded",
-      msg: "Final buffer size cannot exceed 4000 bytes",
+      name: "CounterOffer",
+      docs: ["Terms proposed by the owners in response to a non-owner escrow."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "mint",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "threshold",
+            type: "u8",
+          },
+        ],
+      },
     },
     {
-      code: 6024,
-      name: "FinalBufferSizeMismatch",
-      msg: "Final buffer size mismatch",
+      name: "Escrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "proposer",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "vault_bump",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "recipient",
+            type: {
+              defined: {
+                name: "Recipient",
+              },
+            },
+          },
+          {
+            name: "new_members",
+            type: {
+              option: {
+                vec: {
+                  defined: {
+                    name: "Member",
+                  },
+                },
+              },
+            },
+          },
+          {
+            name: "threshold",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "expires_at",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "auction",
+            type: {
+              option: {
+                defined: {
+                  name: "Auction",
+                },
+              },
+            },
+          },
+          {
+            name: "marketplace_fee",
+            type: {
+              option: {
+                defined: {
+                  name: "MarketplaceFee",
+                },
+              },
+            },
+          },
+          {
+            name: "counter_offer",
+            type: {
+              option: {
+                defined: {
+                  name: "CounterOffer",
+                },
+              },
+            },
+          },
+          {
+            name: "asset_manifest",
+            docs: [
+              "Vault balances the proposer expects to receive along with the multi-wallet.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "VaultAssetExpectation",
+                },
+              },
+            },
+          },
+          {
+            name: "additional_legs",
+            docs: ["Assets paid in addition to the recipient amount."],
+            type: {
+              vec: {
+                defined: {
+                  name: "PaymentLeg",
+                },
+              },
+            },
+          },
+          {
+            name: "seat",
+            docs: [
+              "Member whose seat is sold to the proposer. The other members and the threshold are kept.",
+            ],
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "votes",
+            docs: [
+              "Votes recorded by the owners ahead of executing or cancelling the escrow. Room for one vote per",
+              "member with `Permission::VoteEscrow` is reserved when the escrow is created.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "EscrowVote",
+                },
+              },
+            },
+          },
+          {
+            name: "allowed_buyers",
+            docs: [
+              "Buyers allowed to execute an owner listing. Anyone can buy it if empty.",
+            ],
+            type: {
+              vec: "pubkey",
+            },
+          },
+          {
+            name: "created_at",
+            type: "i64",
+          },
+          {
+            name: "rent_payer",
+            docs: [
+              "Account that paid the escrow rent, refunded when an expired escrow is cancelled.",
+            ],
+            type: "pubkey",
+          },
+          {
+            name: "version",
+            docs: [
+              "Layout version of the account. Escrows created before versioning are upgraded by `migrate_escrow`",
+              "and cannot be used until then.",
+            ],
+            type: "u8",
+          },
+        ],
+      },
     },
-  ],
-  types: [
     {
-      name: "ConfigAction",
+      name: "EscrowEvent",
       type: {
-        kind: "enum",
-        variants: [
+        kind: "struct",
+        fields: [
           {
-            name: "SetMembers",
-            fields: [
-              {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "is_pending",
+            type: "bool",
+          },
+          {
+            name: "is_rejected",
+            type: "bool",
+          },
+          {
+            name: "proposer",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "approver",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "recipient",
+            type: {
+              defined: {
+                name: "Recipient",
+              },
+            },
+          },
+          {
+            name: "new_members",
+            type: {
+              option: {
                 vec: {
                   defined: {
                     name: "Member",
                   },
                 },
               },
-            ],
+            },
           },
           {
-            name: "AddMembers",
-            fields: [
-              {
-                vec: {
-                  defined: {
-                    name: "Member",
-                  },
+            name: "additional_legs",
+            type: {
+              vec: {
+                defined: {
+                  name: "PaymentLeg",
                 },
               },
-            ],
+            },
           },
           {
-            name: "RemoveMembers",
-            fields: [
-              {
-                vec: "pubkey",
+            name: "seat",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "threshold",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "fees",
+            type: {
+              defined: {
+                name: "EscrowFees",
               },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "EscrowFees",
+      docs: ["Fees deducted from the settled amount of an escrow."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "protocol_fee",
+            type: "u64",
+          },
+          {
+            name: "marketplace_fee",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "EscrowOutcome",
+      docs: ["How an escrow was settled."],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Executed",
+          },
+          {
+            name: "Cancelled",
+          },
+          {
+            name: "Rejected",
+          },
+          {
+            name: "Expired",
+          },
+        ],
+      },
+    },
+    {
+      name: "EscrowReceipt",
+      docs: [
+        "Compact on-chain record of a settled escrow, kept after the escrow account is closed.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "mint",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "seller_members_hash",
+            docs: [
+              "Hash of the members of the multi-wallet before settlement.",
             ],
+            type: {
+              array: ["u8", 32],
+            },
           },
           {
-            name: "SetThreshold",
-            fields: ["u8"],
+            name: "buyer_members_hash",
+            docs: [
+              "Hash of the members after settlement, or of the members the buyer proposed if the escrow",
+              "was not executed. Zeroed if there are none.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
           },
           {
-            name: "SetMetadata",
-            fields: [
-              {
-                option: "pubkey",
+            name: "created_at",
+            type: "i64",
+          },
+          {
+            name: "settled_at",
+            type: "i64",
+          },
+          {
+            name: "outcome",
+            type: {
+              defined: {
+                name: "EscrowOutcome",
               },
-            ],
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
           },
         ],
       },
     },
     {
-      name: "ConfigEvent",
+      name: "EscrowVote",
+      docs: [
+        "Vote recorded on an escrow by a member with `Permission::VoteEscrow`.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "member",
+            type: "pubkey",
+          },
+          {
+            name: "approve",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "EscrowVoteEvent",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "member",
+            type: "pubkey",
+          },
+          {
+            name: "approve",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitializeEscrowAsNonOwnerArgs",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "create_key",
-            type: "pubkey",
+            name: "new_members",
+            docs: [
+              "The members of the multi-wallet after the escrow is executed.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "Member",
+                },
+              },
+            },
+          },
+          {
+            name: "amount",
+            docs: ["The amount locked in the escrow vault."],
+            type: "u64",
+          },
+          {
+            name: "threshold",
+            docs: [
+              "The threshold of the multi-wallet after the escrow is executed.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "expires_at",
+            docs: [
+              "Optional unix timestamp after which the escrow can no longer be executed.",
+            ],
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "members",
+            name: "marketplace_fee",
+            docs: [
+              "Optional fee paid to the marketplace the escrow was created through.",
+            ],
+            type: {
+              option: {
+                defined: {
+                  name: "MarketplaceFee",
+                },
+              },
+            },
+          },
+          {
+            name: "asset_manifest",
+            docs: [
+              "Vault balances that must still be present when the owners execute the escrow.",
+            ],
             type: {
               vec: {
                 defined: {
-                  name: "Member",
+                  name: "VaultAssetExpectation",
                 },
               },
             },
           },
           {
-            name: "threshold",
-            type: "u8",
+            name: "additional_legs",
+            docs: [
+              "Assets deposited in addition to `amount`. Their mints, token programs and token accounts",
+              "are passed as remaining accounts.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "PaymentLeg",
+                },
+              },
+            },
           },
           {
-            name: "metadata",
+            name: "seat",
+            docs: [
+              "Member whose seat is bought instead of the whole multi-wallet. `new_members` must then be",
+              "empty and `threshold` is ignored.",
+            ],
             type: {
               option: "pubkey",
             },
@@ -1748,143 +5574,180 @@ export const MultiWalletIdl = {
       },
     },
     {
-      name: "Escrow",
+      name: "InitializeEscrowAsOwnerArgs",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "create_key",
+            name: "recipient",
+            docs: ["The account receiving the payment."],
             type: "pubkey",
           },
           {
-            name: "identifier",
+            name: "amount",
+            docs: ["The amount asked for the multi-wallet."],
             type: "u64",
           },
           {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "proposer",
+            name: "mint",
+            docs: ["Token mint the amount is denominated in (if any)."],
             type: {
               option: "pubkey",
             },
           },
           {
-            name: "vault_bump",
-            type: {
-              option: "u8",
-            },
-          },
-          {
-            name: "recipient",
+            name: "expires_at",
+            docs: [
+              "Optional unix timestamp after which the escrow can no longer be executed.",
+            ],
             type: {
-              defined: {
-                name: "Recipient",
-              },
+              option: "i64",
             },
           },
           {
-            name: "new_members",
+            name: "marketplace_fee",
+            docs: [
+              "Optional fee paid to the marketplace the escrow was created through.",
+            ],
             type: {
               option: {
-                vec: {
-                  defined: {
-                    name: "Member",
-                  },
+                defined: {
+                  name: "MarketplaceFee",
                 },
               },
             },
           },
           {
-            name: "threshold",
+            name: "allowed_buyers",
+            docs: [
+              "Buyers allowed to execute the listing. Anyone can buy it if empty.",
+            ],
             type: {
-              option: "u8",
+              vec: "pubkey",
             },
           },
         ],
       },
     },
     {
-      name: "EscrowEvent",
+      name: "InstructionReturnData",
+      docs: [
+        "Return data set by a single instruction of an executed vault transaction.",
+      ],
       type: {
         kind: "struct",
         fields: [
           {
-            name: "create_key",
+            name: "program_id",
             type: "pubkey",
           },
           {
-            name: "identifier",
-            type: "u64",
+            name: "data",
+            type: "bytes",
           },
+        ],
+      },
+    },
+    {
+      name: "MarketplaceFee",
+      docs: ["Fee charged by the marketplace an escrow was created through."],
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "is_pending",
-            type: "bool",
+            name: "recipient",
+            type: "pubkey",
           },
           {
-            name: "is_rejected",
-            type: "bool",
+            name: "fee_bps",
+            type: "u16",
           },
+        ],
+      },
+    },
+    {
+      name: "Member",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "proposer",
-            type: {
-              option: "pubkey",
-            },
+            name: "pubkey",
+            type: "pubkey",
           },
           {
-            name: "approver",
+            name: "permissions",
             type: {
-              option: "pubkey",
+              option: {
+                defined: {
+                  name: "Permissions",
+                },
+              },
             },
           },
           {
-            name: "recipient",
+            name: "label",
+            docs: ["Optional short label shown in place of the public key."],
             type: {
-              defined: {
-                name: "Recipient",
-              },
+              option: "string",
             },
           },
           {
-            name: "new_members",
-            type: {
-              option: {
-                vec: {
-                  defined: {
-                    name: "Member",
-                  },
-                },
-              },
-            },
+            name: "added_at",
+            docs: [
+              "Unix timestamp at which the member was added, set by the program. Zero if unknown.",
+            ],
+            type: "i64",
           },
           {
-            name: "threshold",
+            name: "added_by",
+            docs: ["Signer that added the member, set by the program."],
             type: {
-              option: "u8",
+              option: "pubkey",
             },
           },
         ],
       },
     },
     {
-      name: "Member",
+      name: "MessageAddressTableLookup",
+      docs: [
+        "Address table lookups describe an on-chain address lookup table to use",
+        "for loading more readonly and writable accounts in a single tx.",
+      ],
       type: {
         kind: "struct",
         fields: [
           {
-            name: "pubkey",
+            name: "account_key",
+            docs: ["Address lookup table account key"],
             type: "pubkey",
           },
           {
-            name: "permissions",
-            type: {
-              option: {
-                defined: {
-                  name: "Permissions",
-                },
-              },
-            },
+            name: "writable_indexes",
+            docs: ["List of indexes used to load writable account addresses"],
+            type: "bytes",
+          },
+          {
+            name: "readonly_indexes",
+            docs: ["List of indexes used to load readonly account addresses"],
+            type: "bytes",
+          },
+        ],
+      },
+    },
+    {
+      name: "MetadataEntry",
+      docs: ["Free-form key/value pair of the wallet metadata."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "key",
+            type: "string",
+          },
+          {
+            name: "value",
+            type: "string",
           },
         ],
       },
@@ -1917,13 +5780,137 @@ export const MultiWalletIdl = {
             },
           },
           {
-            name: "pending_offers",
+            name: "pending_offers",
+            type: {
+              vec: "pubkey",
+            },
+          },
+          {
+            name: "metadata",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "transaction_counter",
+            docs: [
+              "Nonce given to the next transaction buffer, so that buffers re-created at a closed buffer's",
+              "address derive new ephemeral signers.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "listing",
+            docs: [
+              "Owner-created sale listing, if any. The multi-wallet is locked while it is set.",
+            ],
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "escrow_counter",
+            docs: [
+              "Next escrow identifier allocated on-chain. Always greater than any identifier in use.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "open_buffers",
+            docs: [
+              "Number of transaction buffers of the multi-wallet that are not closed yet.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "version",
+            docs: [
+              "Layout version of the account. Accounts created before versioning are upgraded by",
+              "`migrate_multi_wallet`.",
+            ],
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "PaymentLeg",
+      docs: [
+        "An asset paid into the escrow in addition to the recipient amount.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: {
+              option: "pubkey",
+            },
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Permissions",
+      docs: ["Bitmask for permissions."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mask",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "ProtocolConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            docs: ["Authority allowed to update the protocol config."],
+            type: "pubkey",
+          },
+          {
+            name: "fee_recipient",
+            docs: ["Account receiving the protocol fee on escrow settlements."],
+            type: "pubkey",
+          },
+          {
+            name: "fee_bps",
+            docs: ["Protocol fee in basis points of the settled amount."],
+            type: "u16",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "Recipient",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "pubkey",
             type: {
-              vec: "pubkey",
+              option: "pubkey",
             },
           },
           {
-            name: "metadata",
+            name: "mint",
             type: {
               option: "pubkey",
             },
@@ -1932,37 +5919,65 @@ export const MultiWalletIdl = {
       },
     },
     {
-      name: "Permissions",
-      docs: ["Bitmask for permissions."],
+      name: "SignedOffer",
+      docs: [
+        "Offer signed off-chain by a buyer, accepted by the owners through `accept_signed_offer`.",
+        "",
+        "The buyer approves the escrow vault PDA of `nonce` as delegate of `amount` on their token account,",
+        "which the program spends from when the owners accept the offer.",
+      ],
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mask",
-            type: "u8",
+            name: "multi_wallet",
+            type: "pubkey",
+          },
+          {
+            name: "nonce",
+            docs: [
+              "Identifies the offer and its escrow vault. Must be at least `CLIENT_ESCROW_IDENTIFIER_START`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "buyer",
+            type: "pubkey",
           },
-        ],
-      },
-    },
-    {
-      name: "Recipient",
-      type: {
-        kind: "struct",
-        fields: [
           {
             name: "amount",
             type: "u64",
           },
           {
-            name: "pubkey",
+            name: "mint",
+            type: "pubkey",
+          },
+          {
+            name: "new_members",
             type: {
-              option: "pubkey",
+              vec: {
+                defined: {
+                  name: "Member",
+                },
+              },
             },
           },
           {
-            name: "mint",
+            name: "threshold",
+            type: "u8",
+          },
+          {
+            name: "expires_at",
+            type: "i64",
+          },
+          {
+            name: "marketplace_fee",
             type: {
-              option: "pubkey",
+              option: {
+                defined: {
+                  name: "MarketplaceFee",
+                },
+              },
             },
           },
         ],
@@ -2003,9 +6018,26 @@ export const MultiWalletIdl = {
             docs: ["Vault index of the transaction this buffer belongs to."],
             type: "u8",
           },
+          {
+            name: "num_ephemeral_signers",
+            docs: [
+              "Number of ephemeral signer PDAs the transaction message expects the program to sign for.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "transaction_nonce",
+            docs: [
+              "Nonce of the transaction, taken from the multi-wallet transaction counter. Mixed into the",
+              "ephemeral signer seeds, since the buffer address is reused once the buffer is closed.",
+            ],
+            type: "u64",
+          },
           {
             name: "final_buffer_hash",
-            docs: ["Hash of the final assembled transaction message."],
+            docs: [
+              "Hash of the final assembled transaction message, followed by the Borsh-encoded assertions if there are any.",
+            ],
             type: {
               array: ["u8", 32],
             },
@@ -2015,11 +6047,32 @@ export const MultiWalletIdl = {
             docs: ["The size of the final assembled transaction message."],
             type: "u16",
           },
+          {
+            name: "assertions",
+            docs: [
+              "Balance assertions that must hold after the transaction is executed.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "BalanceAssertion",
+                },
+              },
+            },
+          },
           {
             name: "buffer",
             docs: ["The buffer of the transaction message."],
             type: "bytes",
           },
+          {
+            name: "version",
+            docs: [
+              "Layout version of the account. Buffers created before versioning are upgraded by",
+              "`migrate_transaction_buffer` and cannot be used until then.",
+            ],
+            type: "u8",
+          },
         ],
       },
     },
@@ -2040,9 +6093,19 @@ export const MultiWalletIdl = {
             docs: ["Index of the vault this transaction belongs to."],
             type: "u8",
           },
+          {
+            name: "num_ephemeral_signers",
+            docs: [
+              "Number of ephemeral signer PDAs, derived from the buffer key, the transaction nonce and an index,",
+              "the program signs with when executing the transaction. At most `MAX_EPHEMERAL_SIGNERS`.",
+            ],
+            type: "u8",
+          },
           {
             name: "final_buffer_hash",
-            docs: ["Hash of the final assembled transaction message."],
+            docs: [
+              "Hash of the final assembled transaction message, followed by the Borsh-encoded `assertions` if there are any.",
+            ],
             type: {
               array: ["u8", 32],
             },
@@ -2052,6 +6115,19 @@ export const MultiWalletIdl = {
             docs: ["Final size of the buffer."],
             type: "u16",
           },
+          {
+            name: "assertions",
+            docs: [
+              "Balance assertions checked after the transaction is executed, at most `MAX_BALANCE_ASSERTIONS`.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "BalanceAssertion",
+                },
+              },
+            },
+          },
           {
             name: "buffer",
             docs: ["Initial slice of the buffer."],
@@ -2072,5 +6148,214 @@ export const MultiWalletIdl = {
         ],
       },
     },
+    {
+      name: "TransactionMessage",
+      docs: ["Unvalidated instruction data, must be treated as untrusted."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "num_signers",
+            docs: ["The number of signer pubkeys in the account_keys vec."],
+            type: "u8",
+          },
+          {
+            name: "num_writable_signers",
+            docs: [
+              "The number of writable signer pubkeys in the account_keys vec.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "num_writable_non_signers",
+            docs: [
+              "The number of writable non-signer pubkeys in the account_keys vec.",
+            ],
+            type: "u8",
+          },
+          {
+            name: "account_keys",
+            docs: [
+              "The list of unique account public keys (including program IDs) that will be used in the provided instructions.",
+            ],
+            type: {
+              vec: "pubkey",
+            },
+          },
+          {
+            name: "instructions",
+            docs: ["The list of instructions to execute."],
+            type: {
+              vec: {
+                defined: {
+                  name: "CompiledInstruction",
+                },
+              },
+            },
+          },
+          {
+            name: "address_table_lookups",
+            docs: [
+              "List of address table lookups used to load additional accounts",
+              "for this transaction.",
+            ],
+            type: {
+              vec: {
+                defined: {
+                  name: "MessageAddressTableLookup",
+                },
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "VaultAssetExpectation",
+      docs: [
+        "A vault balance the buyer expects to still be there when the escrow is executed.",
+      ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Lamports",
+            fields: [
+              {
+                name: "vault_index",
+                type: "u16",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "Token",
+            fields: [
+              {
+                name: "vault_index",
+                type: "u16",
+              },
+              {
+                name: "mint",
+                type: "pubkey",
+              },
+              {
+                name: "amount",
+                type: "u64",
+              },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: "VaultTransactionEvent",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "create_key",
+            type: "pubkey",
+          },
+          {
+            name: "vault_index",
+            type: "u16",
+          },
+          {
+            name: "return_data",
+            type: {
+              vec: {
+                option: {
+                  defined: {
+                    name: "InstructionReturnData",
+                  },
+                },
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "VaultTransactionSummary",
+      docs: [
+        "Summary of a validated vault transaction, returned by `vault_transaction_validate`.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "vault",
+            docs: ["The vault PDA that signs the transaction."],
+            type: "pubkey",
+          },
+          {
+            name: "accounts",
+            docs: [
+              "All accounts of the message, static keys first, then loaded writable and loaded readonly keys.",
+            ],
+            type: {
+              vec: "pubkey",
+            },
+          },
+          {
+            name: "program_indexes",
+            docs: [
+              "Indexes into `accounts` of the programs invoked by the message.",
+            ],
+            type: "bytes",
+          },
+          {
+            name: "writable_indexes",
+            docs: [
+              "Indexes into `accounts` of the accounts the message requests as writable.",
+            ],
+            type: "bytes",
+          },
+        ],
+      },
+    },
+    {
+      name: "WalletMetadataUpdate",
+      docs: [
+        "Content of the wallet metadata, replaced as a whole by `ConfigAction::UpdateMetadata`.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "description",
+            type: "string",
+          },
+          {
+            name: "image_uri",
+            type: "string",
+          },
+          {
+            name: "tags",
+            type: {
+              vec: "string",
+            },
+          },
+          {
+            name: "entries",
+            type: {
+              vec: {
+                defined: {
+                  name: "MetadataEntry",
+                },
+              },
+            },
+          },
+        ],
+      },
+    },
   ],
 };
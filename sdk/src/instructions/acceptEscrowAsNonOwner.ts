@@ -1,7 +1,13 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import type { Member } from "../types/index.js";
-import { getEscrow, program } from "../utils/index.js";
+import {
+  getEscrow,
+  getEscrowFeeAccounts,
+  program,
+  toMember,
+} from "../utils/index.js";
 
 export async function acceptEscrowAsNonOwner({
   recipient,
@@ -15,7 +21,7 @@ export async function acceptEscrowAsNonOwner({
 }: {
   recipient: PublicKey;
   feePayer: PublicKey;
-  identifier: number;
+  identifier: BN;
   walletAddress: PublicKey;
   threshold: number;
   newMembers: Member[];
@@ -39,16 +45,26 @@ export async function acceptEscrowAsNonOwner({
       tokenProgram
     );
   }
+  const escrowData = await program().account.escrow.fetch(escrow);
+  const feeAccounts = await getEscrowFeeAccounts({
+    marketplaceFee: escrowData.marketplaceFee,
+    mint,
+    tokenProgram,
+  });
   return await program()
-    .methods.executeEscrowAsNonOwner(newMembers, threshold)
+    .methods.executeEscrowAsNonOwner(newMembers.map(toMember), threshold)
     .accountsPartial({
       escrow,
       payer: feePayer,
       recipient,
       recipientTokenAccount,
       payerTokenAccount,
+      ...feeAccounts,
       mint,
       tokenProgram,
+      receipt: null,
+      rentPayer: escrowData.rentPayer,
+      rentCollector: null,
     })
     .instruction();
 }
@@ -1,6 +1,12 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
-import { getEscrow, getEscrowNativeVault, program } from "../utils/index.js";
+import BN from "bn.js";
+import {
+  getEscrow,
+  getEscrowFeeAccounts,
+  getEscrowNativeVault,
+  program,
+} from "../utils/index.js";
 
 export async function acceptEscrowAsOwner({
   signers,
@@ -14,7 +20,7 @@ export async function acceptEscrowAsOwner({
   recipient: PublicKey;
   feePayer: PublicKey;
   signers: PublicKey[];
-  identifier: number;
+  identifier: BN;
   walletAddress: PublicKey;
   mint?: PublicKey | null;
   tokenProgram?: PublicKey | null;
@@ -37,8 +43,14 @@ export async function acceptEscrowAsOwner({
       tokenProgram
     );
   }
+  const escrowData = await program().account.escrow.fetch(escrow);
+  const feeAccounts = await getEscrowFeeAccounts({
+    marketplaceFee: escrowData.marketplaceFee,
+    mint,
+    tokenProgram,
+  });
   return await program()
-    .methods.executeEscrowAsOwner()
+    .methods.executeEscrowAsOwner(escrowData.marketplaceFee)
     .accountsPartial({
       payer: feePayer,
       escrow,
@@ -46,8 +58,12 @@ export async function acceptEscrowAsOwner({
       escrowVault,
       escrowTokenVault,
       recipientTokenAccount,
+      ...feeAccounts,
       mint,
       tokenProgram,
+      receipt: null,
+      rentPayer: escrowData.rentPayer,
+      rentCollector: null,
     })
     .remainingAccounts([
       ...signers.map((signer) => ({
@@ -1,5 +1,7 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { fetchEscrowData } from "../methods/fetchEscrowData.js";
 import { getEscrow, getEscrowNativeVault, program } from "../utils/index.js";
 
 export async function cancelEscrowAsNonOwner({
@@ -10,12 +12,13 @@ export async function cancelEscrowAsNonOwner({
   tokenProgram = null,
 }: {
   proposer: PublicKey;
-  identifier: number;
+  identifier: BN;
   walletAddress: PublicKey;
   mint?: PublicKey | null;
   tokenProgram?: PublicKey | null;
 }) {
   const escrow = getEscrow(walletAddress, identifier);
+  const escrowData = await fetchEscrowData(walletAddress, identifier);
   const escrowVault = getEscrowNativeVault(walletAddress, identifier);
   let escrowTokenVault = null;
   let proposerTokenAccount = null;
@@ -43,6 +46,9 @@ export async function cancelEscrowAsNonOwner({
       proposerTokenAccount,
       mint,
       tokenProgram,
+      receipt: null,
+      rentPayer: escrowData.rentPayer,
+      rentCollector: null,
     })
     .instruction();
 }
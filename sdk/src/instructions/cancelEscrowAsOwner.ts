@@ -1,5 +1,6 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import { fetchEscrowData } from "../methods/fetchEscrowData.js";
 import { getEscrow, getEscrowNativeVault, program } from "../utils/index.js";
 
@@ -13,7 +14,7 @@ export async function cancelEscrowAsOwner({
 }: {
   rentCollector: PublicKey;
   signers: PublicKey[];
-  identifier: number;
+  identifier: BN;
   walletAddress: PublicKey;
   mint?: PublicKey | null;
   tokenProgram?: PublicKey | null;
@@ -52,6 +53,10 @@ export async function cancelEscrowAsOwner({
       proposerTokenAccount,
       mint,
       tokenProgram,
+      receipt: null,
+      receiptPayer: null,
+      rentPayer: escrowData.rentPayer,
+      rentCollector: null,
     })
     .remainingAccounts([
       ...signers.map((signer) => ({
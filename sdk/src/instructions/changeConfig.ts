@@ -1,6 +1,11 @@
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import type { ConfigAction } from "../types/index.js";
-import { getMultiSigFromAddress, program } from "../utils/index.js";
+import {
+  getMultiSigFromAddress,
+  getWalletMetadata,
+  program,
+  toMember,
+} from "../utils/index.js";
 
 export async function changeConfig({
   signers,
@@ -18,13 +23,13 @@ export async function changeConfig({
   for (const action of configActions) {
     switch (action.type) {
       case "addMembers":
-        config.push({ addMembers: [action.members] });
+        config.push({ addMembers: [action.members.map(toMember)] });
         break;
       case "removeMembers":
         config.push({ removeMembers: [action.members] });
         break;
       case "setMembers":
-        config.push({ setMembers: [action.members] });
+        config.push({ setMembers: [action.members.map(toMember)] });
         break;
       case "setThreshold":
         config.push({ setThreshold: [action.threshold] });
@@ -32,6 +37,9 @@ export async function changeConfig({
       case "setMetadata":
         config.push({ setMetadata: [action.metadata] });
         break;
+      case "updateMetadata":
+        config.push({ updateMetadata: [action.metadata] });
+        break;
     }
   }
 
@@ -40,6 +48,11 @@ export async function changeConfig({
     .accountsPartial({
       multiWallet: multisigPda,
       payer: feePayer,
+      walletMetadata: configActions.some((x) => x.type === "updateMetadata")
+        ? getWalletMetadata(walletAddress)
+        : null,
+      rentCollector: null,
+      systemProgram: SystemProgram.programId,
     })
     .remainingAccounts(
       signers.map((x) => ({
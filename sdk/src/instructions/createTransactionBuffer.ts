@@ -5,7 +5,10 @@ import {
   TransactionInstruction,
   TransactionMessage,
 } from "@solana/web3.js";
-import { transactionMessageBeet } from "../types/index.js";
+import {
+  type BalanceAssertion,
+  transactionMessageBeet,
+} from "../types/index.js";
 import {
   getMultiSigFromAddress,
   getTransactionBuffer,
@@ -20,12 +23,14 @@ export async function createTransactionBuffer({
   walletAddress,
   creator,
   lookUpTables,
+  assertions = [],
 }: {
   feePayer: PublicKey;
   instructions: TransactionInstruction[];
   walletAddress: PublicKey;
   creator: PublicKey;
   lookUpTables?: AddressLookupTableAccount[];
+  assertions?: BalanceAssertion[];
 }) {
   const multisigPda = getMultiSigFromAddress(walletAddress);
 
@@ -42,7 +47,16 @@ export async function createTransactionBuffer({
 
   const transactionMessageBytes = transactionMessageSerialize(compiledMessage);
 
-  const hash = sha256(transactionMessageBytes);
+  // Members approve the assertions along with the message, so they are hashed together.
+  const hash =
+    assertions.length > 0
+      ? sha256(
+          Buffer.concat([
+            transactionMessageBytes,
+            encodeAssertions(assertions),
+          ])
+        )
+      : sha256(transactionMessageBytes);
 
   const bufferIndex = Math.round(Math.random() * 255);
   const transactionBuffer = getTransactionBuffer(
@@ -62,8 +76,10 @@ export async function createTransactionBuffer({
     .methods.transactionBufferCreate({
       bufferIndex,
       vaultIndex: 0,
+      numEphemeralSigners: 0,
       finalBufferHash: Array.from(hash),
       finalBufferSize: transactionMessageBytes.length,
+      assertions,
       buffer: messageBytePart1,
     })
     .accountsPartial({
@@ -78,6 +94,7 @@ export async function createTransactionBuffer({
     transactionBufferExtendIx = await program()
       .methods.transactionBufferExtend({ buffer: messageBytePart2 })
       .accountsPartial({
+        multiWallet: multisigPda,
         transactionBuffer,
         creator,
       })
@@ -94,3 +111,14 @@ export async function createTransactionBuffer({
     bufferIndex,
   };
 }
+
+function encodeAssertions(assertions: BalanceAssertion[]) {
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(assertions.length);
+  return Buffer.concat([
+    length,
+    ...assertions.map((assertion) =>
+      program().coder.types.encode("BalanceAssertion", assertion)
+    ),
+  ]);
+}
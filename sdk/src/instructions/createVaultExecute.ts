@@ -44,7 +44,7 @@ export async function createVaultExecute({
     });
 
   const vaultTransactionExecuteIx = await program()
-    .methods.vaultTransactionExecute(0)
+    .methods.vaultTransactionExecute(0, false)
     .accountsPartial({
       multiWallet: multisigPda,
       transactionBuffer,
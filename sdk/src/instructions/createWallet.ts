@@ -1,6 +1,6 @@
 import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
-import { type Member, Permissions } from "../types/index.js";
-import { getVaultFromAddress, program } from "../utils/index.js";
+import { Permissions } from "../types/index.js";
+import { getVaultFromAddress, program, toMember } from "../utils/index.js";
 
 export async function createWallet({
   feePayer,
@@ -14,10 +14,10 @@ export async function createWallet({
   const vaultPda = getVaultFromAddress(walletAddress);
   const createWalletIx = await program()
     .methods.create(
-      {
+      toMember({
         pubkey: new PublicKey(walletAddress),
         permissions: Permissions.all(),
-      } as Member,
+      }),
       metadata
     )
     .accountsPartial({
@@ -1,15 +1,21 @@
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
-import { type Member } from "../types/index.js";
+import { type MarketplaceFee, type Member } from "../types/index.js";
 import {
+  getEscrow,
   getEscrowNativeVault,
   getMultiSigFromAddress,
   program,
+  toMember,
 } from "../utils/index.js";
 
+/**
+ * Without an `identifier`, the escrow is created at the next identifier allocated by the
+ * multi-wallet. Client chosen identifiers must be at least `CLIENT_ESCROW_IDENTIFIER_START`.
+ */
 export async function initiateEscrowAsNonOwner({
-  identifier,
+  identifier = null,
   walletAddress,
   amount,
   member,
@@ -18,8 +24,10 @@ export async function initiateEscrowAsNonOwner({
   threshold,
   mint = null,
   tokenProgram = null,
+  expiresAt = null,
+  marketplaceFee = null,
 }: {
-  identifier: number;
+  identifier?: BN | null;
   newOwners: Member[];
   member: PublicKey;
   walletAddress: PublicKey;
@@ -28,9 +36,15 @@ export async function initiateEscrowAsNonOwner({
   threshold: number;
   mint?: PublicKey | null;
   tokenProgram?: PublicKey | null;
+  expiresAt?: number | null;
+  marketplaceFee?: MarketplaceFee | null;
 }) {
   const multisigPda = getMultiSigFromAddress(walletAddress);
-  const escrowVault = getEscrowNativeVault(walletAddress, identifier);
+  const escrowIdentifier =
+    identifier ??
+    (await program().account.multiWallet.fetch(multisigPda)).escrowCounter;
+  const escrow = getEscrow(walletAddress, escrowIdentifier);
+  const escrowVault = getEscrowNativeVault(walletAddress, escrowIdentifier);
   let escrowTokenVault = null;
   let proposerTokenAccount = null;
   if (mint && tokenProgram) {
@@ -48,16 +62,28 @@ export async function initiateEscrowAsNonOwner({
     );
   }
 
-  return await program()
-    .methods.initiateEscrowAsNonOwner(
-      new BN(identifier),
-      newOwners,
-      new BN(amount),
-      threshold
-    )
+  const args = {
+    newMembers: newOwners.map(toMember),
+    amount: new BN(amount),
+    threshold,
+    expiresAt: expiresAt === null ? null : new BN(expiresAt),
+    marketplaceFee,
+    assetManifest: [],
+    additionalLegs: [],
+    seat: null,
+  };
+  const methods = identifier
+    ? program().methods.initiateEscrowAsNonOwnerWithIdentifier(
+        identifier,
+        args
+      )
+    : program().methods.initiateEscrowAsNonOwner(args);
+
+  const initiateEscrowAsNonOwnerIx = await methods
     .accountsPartial({
       member,
       multiWallet: multisigPda,
+      escrow,
       proposer,
       escrowVault,
       escrowTokenVault,
@@ -66,4 +92,6 @@ export async function initiateEscrowAsNonOwner({
       tokenProgram,
     })
     .instruction();
+
+  return { initiateEscrowAsNonOwnerIx, identifier: escrowIdentifier };
 }
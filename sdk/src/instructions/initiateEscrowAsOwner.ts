@@ -1,35 +1,45 @@
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
-import { getMultiSigFromAddress, program } from "../utils/index.js";
+import type { MarketplaceFee } from "../types/index.js";
+import { getEscrow, getMultiSigFromAddress, program } from "../utils/index.js";
 
 export async function initiateEscrowAsOwner({
   signers,
-  identifier,
   walletAddress,
   amount,
   recipient,
   feePayer,
   mint = null,
+  expiresAt = null,
+  marketplaceFee = null,
+  allowedBuyers = [],
 }: {
   signers: PublicKey[];
   feePayer: PublicKey;
-  identifier: number;
   walletAddress: PublicKey;
   amount: number;
   recipient: PublicKey;
   mint?: PublicKey | null;
+  expiresAt?: number | null;
+  marketplaceFee?: MarketplaceFee | null;
+  allowedBuyers?: PublicKey[];
 }) {
   const multisigPda = getMultiSigFromAddress(walletAddress);
+  const { escrowCounter: identifier } =
+    await program().account.multiWallet.fetch(multisigPda);
 
-  return await program()
-    .methods.initiateEscrowAsOwner(
-      new BN(identifier),
+  const initiateEscrowAsOwnerIx = await program()
+    .methods.initiateEscrowAsOwner({
       recipient,
-      new BN(amount),
-      mint
-    )
+      amount: new BN(amount),
+      mint,
+      expiresAt: expiresAt === null ? null : new BN(expiresAt),
+      marketplaceFee,
+      allowedBuyers,
+    })
     .accountsPartial({
       multiWallet: multisigPda,
+      escrow: getEscrow(walletAddress, identifier),
       payer: feePayer,
     })
     .remainingAccounts([
@@ -40,4 +50,6 @@ export async function initiateEscrowAsOwner({
       })),
     ])
     .instruction();
+
+  return { initiateEscrowAsOwnerIx, identifier };
 }
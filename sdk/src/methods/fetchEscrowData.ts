@@ -1,9 +1,10 @@
 import type { PublicKey } from "@solana/web3.js";
+import type BN from "bn.js";
 import { getEscrow, program } from "../utils/index.js";
 
 export async function fetchEscrowData(
   walletAddress: PublicKey,
-  identifier: number
+  identifier: BN | number
 ) {
   const escrow = getEscrow(walletAddress, identifier);
   return program().account.escrow.fetch(escrow);
@@ -1,9 +1,19 @@
+import type { IdlTypes } from "@coral-xyz/anchor";
 import type { PublicKey } from "@solana/web3.js";
+import type { MultiWallet } from "./multi_wallet.js";
 import type { Member } from "./permissions.js";
 
+export type WalletMetadataUpdate =
+  IdlTypes<MultiWallet>["walletMetadataUpdate"];
+
+export type MarketplaceFee = IdlTypes<MultiWallet>["marketplaceFee"];
+
+export type BalanceAssertion = IdlTypes<MultiWallet>["balanceAssertion"];
+
 export type ConfigAction =
   | { type: "addMembers"; members: Member[] }
   | { type: "removeMembers"; members: PublicKey[] }
   | { type: "setMembers"; members: Member[] }
   | { type: "setThreshold"; threshold: number }
-  | { type: "setMetadata"; metadata: PublicKey | null };
+  | { type: "setMetadata"; metadata: PublicKey | null }
+  | { type: "updateMetadata"; metadata: WalletMetadataUpdate };
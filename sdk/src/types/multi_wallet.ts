@@ -14,24 +14,24 @@ export type MultiWallet = {
   };
   instructions: [
     {
-      name: "cancelEscrowAsNonOwner";
+      name: "acceptEscrowCounterOffer";
       docs: [
-        "Cancels an escrow as a proposer. This function returns the locked funds in the escrow",
-        "vault back to the proposer and invalidates the escrow, preventing further execution.",
+        "Accepts the owners' counter-offer as the proposer. The escrow vault is topped up by the proposer",
+        "or partially refunded to match the new amount, and fully refunded and re-funded if the mint changes.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for accepting the counter-offer.",
+        "When the mint changes, the accounts of the new mint use `new_token_program`.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
-        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "- `Ok(())`: If the escrow terms are updated to the counter-offer.",
+        "- `Err`: If there is no counter-offer or the transfer operation encounters an issue.",
         ""
       ];
-      discriminator: [219, 234, 45, 214, 232, 65, 209, 96];
+      discriminator: [69, 239, 38, 111, 140, 217, 99, 137];
       accounts: [
         {
           name: "multiWallet";
-          writable: true;
           pda: {
             seeds: [
               {
@@ -225,6 +225,127 @@ export type MultiWallet = {
         },
         {
           name: "mint";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "newEscrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "newTokenProgram";
+              },
+              {
+                kind: "account";
+                path: "newMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "newProposerTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "proposer";
+              },
+              {
+                kind: "account";
+                path: "newTokenProgram";
+              },
+              {
+                kind: "account";
+                path: "newMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "newMint";
           optional: true;
         },
         {
@@ -236,6 +357,13 @@ export type MultiWallet = {
           name: "tokenProgram";
           optional: true;
         },
+        {
+          name: "newTokenProgram";
+          docs: [
+            "Token program of the counter-offer mint, which can differ from that of the current mint."
+          ];
+          optional: true;
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
@@ -280,20 +408,22 @@ export type MultiWallet = {
       args: [];
     },
     {
-      name: "cancelEscrowAsOwner";
+      name: "acceptSignedOffer";
       docs: [
-        "Cancels an escrow as an owner. This function unlocks the multi-wallet and invalidates the escrow,",
-        "preventing it from being executed.",
+        "Accepts an offer signed off-chain by a buyer, without the buyer funding an escrow first.",
+        "The buyer's signature is checked against an ed25519 program instruction earlier in the transaction,",
+        "and the amount is spent from the buyer's token account through the delegate approved for the offer.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for accepting the offer.",
+        "- `offer`: The offer signed by the buyer.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully canceled and the multi-wallet is unlocked.",
-        "- `Err`: If validation fails or the accounts provided are invalid.",
+        "- `Ok(())`: If the buyer is paid out and the multi-wallet is handed over to the new members.",
+        "- `Err`: If the signature is missing, the offer expired or was already accepted, or the owners did not approve it.",
         ""
       ];
-      discriminator: [22, 155, 132, 143, 51, 185, 186, 247];
+      discriminator: [47, 91, 244, 168, 41, 209, 71, 141];
       accounts: [
         {
           name: "multiWallet";
@@ -319,15 +449,14 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "escrow.create_key";
-                account: "escrow";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
               }
             ];
           };
         },
         {
-          name: "escrow";
-          writable: true;
+          name: "offerDelegate";
           pda: {
             seeds: [
               {
@@ -336,53 +465,66 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "escrow.create_key";
-                account: "escrow";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
               },
               {
-                kind: "account";
-                path: "escrow.identifier";
-                account: "escrow";
+                kind: "arg";
+                path: "offer.nonce";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
               }
             ];
           };
         },
         {
-          name: "escrowVault";
+          name: "offerNonce";
           writable: true;
-          optional: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [101, 115, 99, 114, 111, 119];
-              },
-              {
-                kind: "account";
-                path: "escrow.create_key";
-                account: "escrow";
+                value: [
+                  115,
+                  105,
+                  103,
+                  110,
+                  101,
+                  100,
+                  95,
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
               },
               {
                 kind: "account";
-                path: "escrow.identifier";
-                account: "escrow";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
               },
               {
-                kind: "const";
-                value: [118, 97, 117, 108, 116];
+                kind: "arg";
+                path: "offer.nonce";
               }
             ];
           };
         },
         {
-          name: "escrowTokenVault";
+          name: "buyer";
+        },
+        {
+          name: "buyerTokenAccount";
           writable: true;
           optional: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "escrowVault";
+                path: "buyer";
               },
               {
                 kind: "account";
@@ -433,14 +575,14 @@ export type MultiWallet = {
           };
         },
         {
-          name: "proposerTokenAccount";
+          name: "recipientTokenAccount";
           writable: true;
           optional: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "proposer";
+                path: "recipient";
               },
               {
                 kind: "account";
@@ -491,213 +633,212 @@ export type MultiWallet = {
           };
         },
         {
-          name: "proposer";
-          writable: true;
-        },
-        {
-          name: "instructionSysvar";
-          address: "Sysvar1nstructions1111111111111111111111111";
-        },
-        {
-          name: "mint";
-          optional: true;
-        },
-        {
-          name: "tokenProgram";
-          optional: true;
-        },
-        {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        },
-        {
-          name: "associatedTokenProgram";
-          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
-        },
-        {
-          name: "eventAuthority";
+          name: "protocolConfig";
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
+                  112,
+                  114,
+                  111,
                   116,
+                  111,
+                  99,
+                  111,
+                  108,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  99,
                   111,
-                  114,
+                  110,
+                  102,
                   105,
-                  116,
-                  121
+                  103
                 ];
               }
             ];
           };
         },
         {
-          name: "program";
-        }
-      ];
-      args: [];
-    },
-    {
-      name: "changeConfig";
-      docs: [
-        "# Parameters",
-        "- `ctx`: The context of the multi-action execution.",
-        "- `config_actions`: The list of actions to be executed.",
-        "",
-        "# Returns",
-        "- `Result<()>`: The result of the multi-action execution."
-      ];
-      discriminator: [24, 158, 114, 115, 94, 210, 244, 233];
-      accounts: [
+          name: "protocolFeeRecipient";
+          writable: true;
+          optional: true;
+        },
         {
-          name: "multiWallet";
+          name: "protocolFeeTokenAccount";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [
-                  109,
-                  117,
-                  108,
-                  116,
-                  105,
-                  95,
-                  119,
-                  97,
-                  108,
-                  108,
-                  101,
-                  116
-                ];
+                kind: "account";
+                path: "protocolFeeRecipient";
               },
               {
                 kind: "account";
-                path: "multi_wallet.create_key";
-                account: "multiWallet";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
-          name: "payer";
+          name: "marketplaceFeeRecipient";
           writable: true;
-          signer: true;
           optional: true;
         },
         {
-          name: "systemProgram";
+          name: "marketplaceFeeTokenAccount";
+          writable: true;
           optional: true;
-          address: "11111111111111111111111111111111";
-        },
-        {
-          name: "instructionSysvar";
-          address: "Sysvar1nstructions1111111111111111111111111";
-        },
-        {
-          name: "eventAuthority";
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ];
+                kind: "account";
+                path: "marketplaceFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
           };
         },
         {
-          name: "program";
-        }
-      ];
-      args: [
+          name: "mint";
+          optional: true;
+        },
         {
-          name: "configActions";
-          type: {
-            vec: {
-              defined: {
-                name: "configAction";
-              };
-            };
-          };
-        }
-      ];
-    },
-    {
-      name: "create";
-      docs: [
-        "Creates a new multi-wallet.",
-        "",
-        "# Parameters",
-        "- `ctx`: The context of the multi-wallet creation.",
-        "- `create_key`: The member key used to create the multi-wallet.",
-        "- `metadata`: An optional metadata for the multi-wallet.",
-        "- `label`: An optional label for the multi-wallet.",
-        "",
-        "# Returns",
-        "- `Result<()>`: The result of the multi-wallet creation."
-      ];
-      discriminator: [24, 30, 200, 40, 5, 28, 7, 119];
-      accounts: [
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
         {
           name: "payer";
           writable: true;
           signer: true;
         },
         {
-          name: "multiWallet";
+          name: "recipient";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "receipt";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
                 kind: "const";
                 value: [
-                  109,
-                  117,
-                  108,
-                  116,
-                  105,
-                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
                   119,
-                  97,
-                  108,
-                  108,
+                  95,
+                  114,
+                  101,
+                  99,
                   101,
+                  105,
+                  112,
                   116
                 ];
               },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
               {
                 kind: "arg";
-                path: "create_key.pubkey";
+                path: "offer.nonce";
               }
             ];
           };
@@ -706,6 +847,10 @@ export type MultiWallet = {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
         },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
         {
           name: "eventAuthority";
           pda: {
@@ -741,38 +886,30 @@ export type MultiWallet = {
       ];
       args: [
         {
-          name: "createKey";
+          name: "offer";
           type: {
             defined: {
-              name: "member";
+              name: "signedOffer";
             };
           };
-        },
-        {
-          name: "metadata";
-          type: {
-            option: "pubkey";
-          };
         }
       ];
     },
     {
-      name: "executeEscrowAsNonOwner";
+      name: "cancelEscrowAsNonOwner";
       docs: [
-        "Executes an escrow. This function transfers funds from the escrow vault",
-        "to the recipient and updates the members of the multi-wallet as specified in the escrow.",
+        "Cancels an escrow as a proposer. This function returns the locked funds in the escrow",
+        "vault back to the proposer and invalidates the escrow, preventing further execution.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
-        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
-        "- `threshold`: Number of signatures required for the multisig transaction to be approved.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
-        "- `Err`: If any validation fails, the escrow is not locked, or the transfer operation encounters an issue.",
+        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
+        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
         ""
       ];
-      discriminator: [63, 186, 102, 94, 23, 177, 217, 130];
+      discriminator: [219, 234, 45, 214, 232, 65, 209, 96];
       accounts: [
         {
           name: "multiWallet";
@@ -827,27 +964,53 @@ export type MultiWallet = {
           };
         },
         {
-          name: "recipientTokenAccount";
+          name: "escrowVault";
           writable: true;
-          optional: true;
           pda: {
             seeds: [
               {
-                kind: "account";
-                path: "recipient";
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
               },
               {
                 kind: "account";
-                path: "tokenProgram";
+                path: "escrow.create_key";
+                account: "escrow";
               },
               {
                 kind: "account";
-                path: "mint";
+                path: "escrow.identifier";
+                account: "escrow";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
               }
             ];
-            program: {
-              kind: "const";
-              value: [
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
                 140,
                 151,
                 37,
@@ -885,14 +1048,14 @@ export type MultiWallet = {
           };
         },
         {
-          name: "payerTokenAccount";
+          name: "proposerTokenAccount";
           writable: true;
           optional: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "payer";
+                path: "proposer";
               },
               {
                 kind: "account";
@@ -944,21 +1107,65 @@ export type MultiWallet = {
         },
         {
           name: "mint";
+          writable: true;
           optional: true;
         },
         {
-          name: "recipient";
+          name: "proposer";
           writable: true;
+          signer: true;
         },
         {
-          name: "payer";
+          name: "rentPayer";
           writable: true;
-          signer: true;
         },
         {
           name: "tokenProgram";
           optional: true;
         },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "rentCollector";
+          writable: true;
+          optional: true;
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
@@ -1000,39 +1207,23 @@ export type MultiWallet = {
           name: "program";
         }
       ];
-      args: [
-        {
-          name: "newMembers";
-          type: {
-            vec: {
-              defined: {
-                name: "member";
-              };
-            };
-          };
-        },
-        {
-          name: "threshold";
-          type: "u8";
-        }
-      ];
+      args: [];
     },
     {
-      name: "executeEscrowAsOwner";
+      name: "cancelEscrowAsOwner";
       docs: [
-        "Executes an escrow as an owner. This function transfers funds from the escrow vault",
-        "to the recipient, updates the multi-wallet's members as specified in the escrow, and",
-        "ensures the multi-wallet remains valid.",
+        "Cancels an escrow as an owner. This function unlocks the multi-wallet and invalidates the escrow,",
+        "preventing it from being executed.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
-        "- `Err`: If validation fails, the escrow is not properly initialized, or the transfer encounters an issue.",
+        "- `Ok(())`: If the escrow is successfully canceled and the multi-wallet is unlocked.",
+        "- `Err`: If validation fails or the accounts provided are invalid.",
         ""
       ];
-      discriminator: [119, 121, 203, 21, 159, 211, 44, 59];
+      discriminator: [22, 155, 132, 143, 51, 185, 186, 247];
       accounts: [
         {
           name: "multiWallet";
@@ -1089,6 +1280,7 @@ export type MultiWallet = {
         {
           name: "escrowVault";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
@@ -1171,14 +1363,14 @@ export type MultiWallet = {
           };
         },
         {
-          name: "recipientTokenAccount";
+          name: "proposerTokenAccount";
           writable: true;
           optional: true;
           pda: {
             seeds: [
               {
                 kind: "account";
-                path: "recipient";
+                path: "proposer";
               },
               {
                 kind: "account";
@@ -1229,24 +1421,74 @@ export type MultiWallet = {
           };
         },
         {
-          name: "mint";
-          optional: true;
+          name: "proposer";
+          writable: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
         },
         {
           name: "instructionSysvar";
           address: "Sysvar1nstructions1111111111111111111111111";
         },
         {
-          name: "payer";
+          name: "mint";
           writable: true;
-          signer: true;
+          optional: true;
         },
         {
-          name: "recipient";
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "receiptPayer";
+          docs: ["Pays for the receipt, if one is created."];
           writable: true;
+          signer: true;
+          optional: true;
         },
         {
-          name: "tokenProgram";
+          name: "rentCollector";
+          writable: true;
           optional: true;
         },
         {
@@ -1293,23 +1535,21 @@ export type MultiWallet = {
       args: [];
     },
     {
-      name: "initiateEscrowAsNonOwner";
+      name: "cancelExpiredEscrow";
       docs: [
-        "Initializes an escrow. This function locks funds into an escrow vault",
-        "and sets up the necessary metadata for the escrow.",
+        "Cancels an expired escrow. Anyone can crank this once the escrow's expiry has passed.",
+        "Locked funds are refunded to the proposer, the escrow rent to the account that paid it, and the offer",
+        "is removed from the multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
-        "- `identifier`: A unique identifier for the escrow, used to distinguish it from others.",
-        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
-        "- `amount`: The amount to be transferred to the escrow.",
+        "- `ctx`: The context containing all relevant accounts required for canceling the escrow.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
-        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        "- `Ok(())`: If the escrow is successfully canceled and funds are returned to the proposer.",
+        "- `Err`: If the escrow has not expired or the accounts provided are invalid.",
         ""
       ];
-      discriminator: [207, 60, 39, 74, 34, 156, 127, 180];
+      discriminator: [8, 72, 226, 3, 6, 98, 149, 91];
       accounts: [
         {
           name: "multiWallet";
@@ -1335,8 +1575,8 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "multi_wallet.create_key";
-                account: "multiWallet";
+                path: "escrow.create_key";
+                account: "escrow";
               }
             ];
           };
@@ -1352,12 +1592,13 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "multi_wallet.create_key";
-                account: "multiWallet";
+                path: "escrow.create_key";
+                account: "escrow";
               },
               {
-                kind: "arg";
-                path: "identifier";
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
               }
             ];
           };
@@ -1365,6 +1606,7 @@ export type MultiWallet = {
         {
           name: "escrowVault";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
@@ -1373,12 +1615,13 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "multi_wallet.create_key";
-                account: "multiWallet";
+                path: "escrow.create_key";
+                account: "escrow";
               },
               {
-                kind: "arg";
-                path: "identifier";
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
               },
               {
                 kind: "const";
@@ -1504,22 +1747,67 @@ export type MultiWallet = {
           };
         },
         {
-          name: "mint";
-          optional: true;
+          name: "proposer";
+          writable: true;
         },
         {
-          name: "proposer";
+          name: "rentPayer";
           writable: true;
-          signer: true;
         },
         {
-          name: "member";
-          signer: true;
+          name: "mint";
+          writable: true;
+          optional: true;
         },
         {
           name: "tokenProgram";
           optional: true;
         },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "receiptPayer";
+          docs: ["Pays for the receipt, if one is created."];
+          writable: true;
+          signer: true;
+          optional: true;
+        },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
@@ -1561,50 +1849,19 @@ export type MultiWallet = {
           name: "program";
         }
       ];
-      args: [
-        {
-          name: "identifier";
-          type: "u64";
-        },
-        {
-          name: "newMembers";
-          type: {
-            vec: {
-              defined: {
-                name: "member";
-              };
-            };
-          };
-        },
-        {
-          name: "amount";
-          type: "u64";
-        },
-        {
-          name: "threshold";
-          type: "u8";
-        }
-      ];
+      args: [];
     },
     {
-      name: "initiateEscrowAsOwner";
+      name: "changeConfig";
       docs: [
-        "Initializes an escrow as an owner. This function locks the multi-wallet",
-        "and prepares the escrow account with the specified metadata and recipient details.",
-        "",
         "# Parameters",
-        "- `ctx`: The context containing all relevant accounts required for initializing the escrow.",
-        "- `identifier`: A unique identifier for the escrow, used to distinguish it from others.",
-        "- `recipient`: The recipient's account,",
-        "- `amount`: The amount to be transferred.",
-        "- `mint`: Token mint that needs to be transferred(if any)",
+        "- `ctx`: The context of the multi-action execution.",
+        "- `config_actions`: The list of actions to be executed.",
         "",
         "# Returns",
-        "- `Ok(())`: If the escrow is successfully initialized and the multi-wallet is locked.",
-        "- `Err`: If any validation fails or the multi-wallet does not meet the required threshold.",
-        ""
+        "- `Result<()>`: The result of the multi-action execution."
       ];
-      discriminator: [255, 237, 252, 101, 179, 193, 143, 27];
+      discriminator: [24, 158, 114, 115, 94, 210, 244, 233];
       accounts: [
         {
           name: "multiWallet";
@@ -1637,39 +1894,75 @@ export type MultiWallet = {
           };
         },
         {
-          name: "escrow";
+          name: "payer";
+          writable: true;
+          signer: true;
+          optional: true;
+        },
+        {
+          name: "walletMetadata";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [101, 115, 99, 114, 111, 119];
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
               },
               {
                 kind: "account";
-                path: "multi_wallet.create_key";
-                account: "multiWallet";
+                path: "multiWallet";
               },
               {
-                kind: "arg";
-                path: "identifier";
+                kind: "const";
+                value: [
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116,
+                  95,
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ];
               }
             ];
           };
         },
         {
-          name: "instructionSysvar";
-          address: "Sysvar1nstructions1111111111111111111111111";
-        },
-        {
-          name: "payer";
+          name: "rentCollector";
           writable: true;
-          signer: true;
+          optional: true;
         },
         {
           name: "systemProgram";
+          optional: true;
           address: "11111111111111111111111111111111";
         },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
         {
           name: "eventAuthority";
           pda: {
@@ -1705,45 +1998,34 @@ export type MultiWallet = {
       ];
       args: [
         {
-          name: "identifier";
-          type: "u64";
-        },
-        {
-          name: "recipient";
-          type: "pubkey";
-        },
-        {
-          name: "amount";
-          type: "u64";
-        },
-        {
-          name: "mint";
+          name: "configActions";
           type: {
-            option: "pubkey";
+            vec: {
+              defined: {
+                name: "configAction";
+              };
+            };
           };
         }
       ];
     },
     {
-      name: "transactionBufferClose";
+      name: "closeEscrowReceipt";
       docs: [
-        "Closes an existing transaction buffer.",
+        "Closes the receipt of a settled escrow, returning its rent to a member of the multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
+        "- `ctx`: The context containing the receipt and the signing members.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully closed.",
-        "- `Err`: If validation fails or the accounts are invalid."
+        "- `Ok(())`: If the receipt is closed.",
+        "- `Err`: If the threshold is not met or the rent collector is not a signing member.",
+        ""
       ];
-      discriminator: [17, 182, 208, 228, 136, 24, 178, 102];
+      discriminator: [83, 241, 204, 62, 83, 144, 53, 91];
       accounts: [
         {
           name: "multiWallet";
-        },
-        {
-          name: "transactionBuffer";
-          writable: true;
           pda: {
             seeds: [
               {
@@ -1765,71 +2047,86 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "multiWallet";
-              },
+                path: "receipt.create_key";
+                account: "escrowReceipt";
+              }
+            ];
+          };
+        },
+        {
+          name: "receipt";
+          writable: true;
+          pda: {
+            seeds: [
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  97,
-                  110,
+                  101,
                   115,
-                  97,
                   99,
-                  116,
-                  105,
+                  114,
                   111,
-                  110,
+                  119,
                   95,
-                  98,
-                  117,
-                  102,
-                  102,
+                  114,
                   101,
-                  114
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
                 ];
               },
               {
                 kind: "account";
-                path: "creator";
+                path: "receipt.create_key";
+                account: "escrowReceipt";
               },
               {
                 kind: "account";
-                path: "transaction_buffer.buffer_index";
-                account: "transactionBuffer";
+                path: "receipt.identifier";
+                account: "escrowReceipt";
               }
             ];
           };
         },
         {
-          name: "creator";
-          signer: true;
-        },
-        {
-          name: "rentPayer";
+          name: "rentCollector";
+          docs: [
+            "Member of the multi-wallet receiving the rent of the receipt."
+          ];
           writable: true;
+          signer: true;
         }
       ];
       args: [];
     },
     {
-      name: "transactionBufferCreate";
+      name: "closeMultiWallet";
       docs: [
-        "Creates a new transaction buffer.",
+        "Closes a multi-wallet with no pending offers or open transaction buffers, returning its rent",
+        "and the rent of its wallet metadata account to a signing member and emitting a final `ConfigEvent`",
+        "without members. The multi-wallet can be re-created by anyone with the same create key, so every",
+        "vault holding assets should be swept.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
-        "- `args`: Arguments for the transaction buffer creation.",
+        "- `ctx`: The context containing the multi-wallet, its wallet metadata PDA and the signing members.",
+        "The vaults to sweep are passed as the first remaining accounts, followed by",
+        "`[token account, mint, destination token account, token program]` for each token account to sweep.",
+        "- `vault_indices`: Indices of the vaults whose lamports are swept to the sweep recipient.",
+        "- `token_vault_indices`: Vault index owning each token account to sweep. The token accounts are",
+        "emptied into their destination and closed to the sweep recipient.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully created.",
-        "- `Err`: If validation fails or the provided arguments are invalid."
+        "- `Ok(())`: If the multi-wallet is closed.",
+        "- `Err`: If the threshold is not met, offers or buffers are still open, or a vault or token account is invalid.",
+        ""
       ];
-      discriminator: [245, 201, 113, 108, 37, 63, 29, 89];
+      discriminator: [94, 11, 206, 168, 76, 131, 194, 122];
       accounts: [
         {
           name: "multiWallet";
+          writable: true;
           pda: {
             seeds: [
               {
@@ -1858,7 +2155,7 @@ export type MultiWallet = {
           };
         },
         {
-          name: "transactionBuffer";
+          name: "walletMetadata";
           writable: true;
           pda: {
             seeds: [
@@ -1886,79 +2183,118 @@ export type MultiWallet = {
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  97,
-                  110,
-                  115,
+                  119,
                   97,
-                  99,
+                  108,
+                  108,
+                  101,
                   116,
-                  105,
-                  111,
-                  110,
                   95,
-                  98,
-                  117,
-                  102,
-                  102,
+                  109,
                   101,
-                  114
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
                 ];
-              },
-              {
-                kind: "account";
-                path: "creator";
-              },
-              {
-                kind: "arg";
-                path: "args.buffer_index";
               }
             ];
           };
         },
         {
-          name: "creator";
+          name: "rentCollector";
+          docs: [
+            "Member of the multi-wallet receiving the rent of the multi-wallet."
+          ];
+          writable: true;
           signer: true;
         },
         {
-          name: "rentPayer";
+          name: "sweepRecipient";
           writable: true;
-          signer: true;
+          optional: true;
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
         }
       ];
       args: [
         {
-          name: "args";
+          name: "vaultIndices";
           type: {
-            defined: {
-              name: "transactionBufferCreateArgs";
-            };
+            vec: "u16";
+          };
+        },
+        {
+          name: "tokenVaultIndices";
+          type: {
+            vec: "u16";
           };
         }
       ];
     },
     {
-      name: "transactionBufferExtend";
+      name: "create";
       docs: [
-        "Extends an existing transaction buffer.",
+        "Creates a new multi-wallet.",
         "",
         "# Parameters",
-        "- `ctx`: Context containing all necessary accounts.",
-        "- `args`: Arguments for extending the transaction buffer.",
+        "- `ctx`: The context of the multi-wallet creation.",
+        "- `create_key`: The member key used to create the multi-wallet.",
+        "- `metadata`: An optional metadata for the multi-wallet.",
+        "- `label`: An optional label for the multi-wallet.",
         "",
         "# Returns",
-        "- `Ok(())`: If the transaction buffer is successfully extended.",
-        "- `Err`: If validation fails or the provided arguments are invalid."
+        "- `Result<()>`: The result of the multi-wallet creation."
       ];
-      discriminator: [230, 157, 67, 56, 5, 238, 245, 146];
+      discriminator: [24, 30, 200, 40, 5, 28, 7, 119];
       accounts: [
         {
-          name: "transactionBuffer";
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "multiWallet";
           writable: true;
           pda: {
             seeds: [
@@ -1980,79 +2316,85 @@ export type MultiWallet = {
                 ];
               },
               {
-                kind: "account";
-                path: "transaction_buffer.multi_wallet";
-                account: "transactionBuffer";
-              },
+                kind: "arg";
+                path: "create_key.pubkey";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  97,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
                   110,
-                  115,
-                  97,
-                  99,
                   116,
-                  105,
-                  111,
-                  110,
                   95,
-                  98,
+                  97,
                   117,
-                  102,
-                  102,
-                  101,
-                  114
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ];
-              },
-              {
-                kind: "account";
-                path: "creator";
-              },
-              {
-                kind: "account";
-                path: "transaction_buffer.buffer_index";
-                account: "transactionBuffer";
               }
             ];
           };
         },
         {
-          name: "creator";
-          signer: true;
+          name: "program";
         }
       ];
       args: [
         {
-          name: "args";
+          name: "createKey";
           type: {
             defined: {
-              name: "transactionBufferExtendArgs";
+              name: "member";
             };
           };
+        },
+        {
+          name: "metadata";
+          type: {
+            option: "pubkey";
+          };
         }
       ];
     },
     {
-      name: "vaultTransactionExecute";
+      name: "escrowApprove";
       docs: [
-        "Executes a vault transaction.",
+        "Records the approval of an escrow by a member with the `VoteEscrow` permission. Recorded approvals",
+        "count towards the threshold of `execute_escrow_as_owner` along with the signers of the execution.",
+        "Room for the votes is reserved when the escrow is created, so voting costs no rent.",
         "",
         "# Parameters",
-        "- `ctx`: The context of the vault transaction execution.",
-        "- `vault_index`: The index of the vault.",
-        "- `transaction_message`: The transaction message to be executed.",
+        "- `ctx`: The context containing the escrow and the voting member.",
         "",
         "# Returns",
-        "- `Result<()>`: The result of the vault transaction execution."
+        "- `Ok(())`: If the vote is recorded, replacing any previous vote of the member.",
+        "- `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.",
+        ""
       ];
-      discriminator: [194, 8, 161, 87, 153, 164, 25, 171];
+      discriminator: [6, 225, 57, 211, 235, 164, 41, 104];
       accounts: [
         {
-          name: "transactionBuffer";
-          writable: true;
+          name: "multiWallet";
           pda: {
             seeds: [
               {
@@ -2074,295 +2416,5077 @@ export type MultiWallet = {
               },
               {
                 kind: "account";
-                path: "transaction_buffer.multi_wallet";
-                account: "transactionBuffer";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
               },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "member";
+          signer: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
               {
                 kind: "const";
                 value: [
-                  116,
-                  114,
-                  97,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
                   110,
-                  115,
+                  116,
+                  95,
                   97,
-                  99,
+                  117,
                   116,
-                  105,
+                  104,
                   111,
-                  110,
-                  95,
-                  98,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "escrowCounterOffer";
+      docs: [
+        "Proposes new terms on a non-owner escrow as an owner. The proposer can accept them",
+        "through `accept_escrow_counter_offer`, after which the owners can execute the escrow.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for the counter-offer.",
+        "- `amount`: The amount the owners ask for.",
+        "- `mint`: Token mint the amount is denominated in (if any).",
+        "- `threshold`: The threshold of the multi-wallet once the escrow is executed.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the counter-offer is recorded on the escrow.",
+        "- `Err`: If validation fails or the escrow is not a pending non-owner escrow.",
+        ""
+      ];
+      discriminator: [106, 34, 157, 83, 114, 41, 235, 32];
+      accounts: [
+        {
+          name: "multiWallet";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
                   117,
-                  102,
-                  102,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
                   101,
-                  114
+                  116
                 ];
               },
               {
                 kind: "account";
-                path: "transaction_buffer.creator";
-                account: "transactionBuffer";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
               },
               {
                 kind: "account";
-                path: "transaction_buffer.buffer_index";
-                account: "transactionBuffer";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
               }
             ];
           };
         },
         {
-          name: "multiWallet";
-          writable: true;
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
         },
         {
-          name: "rentPayer";
-          writable: true;
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
         },
         {
-          name: "instructionSysvar";
-          address: "Sysvar1nstructions1111111111111111111111111";
+          name: "program";
         }
       ];
       args: [
         {
-          name: "vaultIndex";
-          type: "u16";
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "mint";
+          type: {
+            option: "pubkey";
+          };
+        },
+        {
+          name: "threshold";
+          type: "u8";
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "escrow";
-      discriminator: [31, 213, 123, 187, 186, 22, 218, 155];
-    },
-    {
-      name: "multiWallet";
-      discriminator: [100, 242, 252, 66, 54, 82, 77, 90];
-    },
-    {
-      name: "transactionBuffer";
-      discriminator: [90, 36, 35, 219, 93, 225, 110, 96];
-    }
-  ];
-  events: [
-    {
-      name: "configEvent";
-      discriminator: [162, 6, 172, 68, 201, 128, 119, 230];
-    },
-    {
-      name: "escrowEvent";
-      discriminator: [241, 51, 61, 3, 5, 32, 113, 144];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "durableNonceDetected";
-      msg: "Durable nonce detected. Durable nonce is not allowed for this transaction.";
-    },
-    {
-      code: 6001;
-      name: "duplicateMember";
-      msg: "Duplicate public keys found in the members array. Each member must have a unique public key.";
-    },
-    {
-      code: 6002;
-      name: "emptyMembers";
-      msg: "The members array cannot be empty. Add at least one member.";
-    },
-    {
-      code: 6003;
-      name: "tooManyMembers";
-      msg: "Too many members specified. A maximum of 65,535 members is allowed.";
-    },
-    {
-      code: 6004;
-      name: "invalidThreshold";
-      msg: "Invalid threshold specified. The threshold must be between 1 and the total number of members.";
-    },
-    {
-      code: 6005;
-      name: "thresholdTooHigh";
-      msg: "Threshold exceeds the maximum allowed limit of 2. Please choose a lower value.";
-    },
-    {
-      code: 6006;
-      name: "invalidTransactionMessage";
-      msg: "The provided TransactionMessage is malformed or improperly formatted.";
-    },
-    {
-      code: 6007;
-      name: "notEnoughSigners";
-      msg: "Insufficient signers. The number of signers must meet or exceed the minimum threshold.";
-    },
-    {
-      code: 6008;
-      name: "invalidNumberOfAccounts";
-      msg: "Incorrect number of accounts provided. Verify the account count matches the expected number.";
-    },
-    {
-      code: 6009;
-      name: "invalidAccount";
-      msg: "One or more accounts provided are invalid. Ensure all accounts meet the requirements.";
-    },
-    {
-      code: 6010;
-      name: "missingAccount";
-      msg: "Required account is missing. Ensure all necessary accounts are included.";
-    },
-    {
-      code: 6011;
-      name: "illegalAccountOwner";
-      msg: "Account is not owned by the Multisig program. Only accounts under the Multisig program can be used.";
-    },
-    {
-      code: 6012;
-      name: "multisigIsCurrentlyLocked";
-      msg: "The Multisig must be unlocked before performing this operation. Unlock it and try again.";
-    },
-    {
-      code: 6013;
-      name: "escrowDoesNotExist";
-      msg: "The escrow account doesn't exist.";
-    },
-    {
-      code: 6014;
-      name: "missingOwner";
-      msg: "The members array cannot have a length of one. Add an additional member.";
-    },
-    {
-      code: 6015;
-      name: "invalidEscrowProposer";
-      msg: "The proposer must match the account stated in the escrow.";
-    },
-    {
-      code: 6016;
-      name: "invalidEscrowRecipient";
-      msg: "The recipient nust match the account stated in the escrow.";
-    },
-    {
-      code: 6017;
-      name: "insufficientSignerWithExecutePermission";
-      msg: "Require at least one signer to have the execute permission.";
-    },
-    {
-      code: 6018;
-      name: "insufficientSignerWithInitiatePermission";
-      msg: "Require at least one signer to have the initiate permission.";
-    },
-    {
-      code: 6019;
-      name: "insufficientSignersWithVotePermission";
-      msg: "Require threshold to be lesser than or equal to the number of members with vote permission.";
-    },
-    {
-      code: 6020;
-      name: "unauthorisedToAcceptEscrowOffer";
-      msg: "You do not have permission to accept this offer.";
-    },
-    {
-      code: 6021;
-      name: "unauthorisedToModifyBuffer";
-      msg: "Only the creator of the transaction buffer have permission to modify the buffer.";
     },
     {
-      code: 6022;
-      name: "finalBufferHashMismatch";
-      msg: "Final message buffer hash doesnt match the expected hash";
+      name: "escrowReject";
+      docs: [
+        "Records the rejection of an escrow by a member with the `VoteEscrow` permission. Recorded rejections",
+        "count towards the threshold of `cancel_escrow_as_owner` along with the signers of the cancellation.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the escrow and the voting member.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the vote is recorded, replacing any previous vote of the member.",
+        "- `Err`: If the member cannot vote, the escrow is not pending or has no room left for the vote.",
+        ""
+      ];
+      discriminator: [51, 223, 199, 222, 61, 137, 12, 158];
+      accounts: [
+        {
+          name: "multiWallet";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "member";
+          signer: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
     },
     {
-      code: 6023;
-      name: "finalBufferSizeExceeded";
-      msg: "Final buffer size cannot exceed 4000 bytes";
+      name: "executeEscrowAsNonOwner";
+      docs: [
+        "Executes an escrow. This function transfers funds from the escrow vault",
+        "to the recipient and updates the members of the multi-wallet as specified in the escrow.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "- `new_members`: A vector of new members to be added to the multi-wallet after the escrow is executed.",
+        "- `threshold`: Number of signatures required for the multisig transaction to be approved.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
+        "- `Err`: If any validation fails, the escrow is not locked, or the transfer operation encounters an issue.",
+        ""
+      ];
+      discriminator: [63, 186, 102, 94, 23, 177, 217, 130];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "recipientTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "recipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "payerTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "payer";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "protocolConfig";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "protocolFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "protocolFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "protocolFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "marketplaceFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "marketplaceFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "marketplaceFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "recipient";
+          writable: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "rentCollector";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "newMembers";
+          type: {
+            vec: {
+              defined: {
+                name: "member";
+              };
+            };
+          };
+        },
+        {
+          name: "threshold";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "executeEscrowAsOwner";
+      docs: [
+        "Executes an escrow as an owner. This function transfers funds from the escrow vault",
+        "to the recipient, updates the multi-wallet's members as specified in the escrow, and",
+        "ensures the multi-wallet remains valid.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for executing the escrow.",
+        "The escrow rent payer receives the escrow rent and the rent of its emptied vault token accounts.",
+        "- `marketplace_fee`: The marketplace fee the owners agree to pay out of the proceeds. Must match the escrow.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully executed, funds are transferred, and the multi-wallet is updated.",
+        "- `Err`: If validation fails, the escrow is not properly initialized, or the transfer encounters an issue.",
+        ""
+      ];
+      discriminator: [119, 121, 203, 21, 159, 211, 44, 59];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "recipientTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "recipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "protocolConfig";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "protocolFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "protocolFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "protocolFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "marketplaceFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "marketplaceFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "marketplaceFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "recipient";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        },
+        {
+          name: "rentCollector";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "marketplaceFee";
+          type: {
+            option: {
+              defined: {
+                name: "marketplaceFee";
+              };
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initializeProtocolConfig";
+      docs: [
+        "Initializes the protocol config. Only the program upgrade authority can do this.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the protocol config.",
+        "- `fee_recipient`: The account receiving the protocol fee on escrow settlements.",
+        "- `fee_bps`: The protocol fee in basis points.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the protocol config is successfully initialized.",
+        "- `Err`: If the signer is not the upgrade authority or the fee is invalid.",
+        ""
+      ];
+      discriminator: [28, 50, 43, 233, 244, 98, 123, 118];
+      accounts: [
+        {
+          name: "protocolConfig";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+          address: "mu1LDWh4VGHhnZHB85s92HNBapj3b9s5DgzTkiAyeKY";
+        },
+        {
+          name: "programData";
+        },
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "feeRecipient";
+          type: "pubkey";
+        },
+        {
+          name: "feeBps";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "initiateAuction";
+      docs: [
+        "Initializes an auction as an owner. This function locks the multi-wallet and lists it",
+        "either as an English auction, won by the highest bid at the deadline, or as a Dutch auction,",
+        "whose price decreases linearly until it is bought through `execute_escrow_as_non_owner`.",
+        "A Dutch auction expires at `ends_at` and can then be cancelled through `cancel_expired_escrow`.",
+        "The escrow identifier is allocated from the escrow counter of the multi-wallet.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the auction.",
+        "- `recipient`: The account receiving the proceeds of the sale.",
+        "- `mint`: Token mint the auction is priced in (if any).",
+        "- `kind`: The auction kind and its pricing parameters.",
+        "- `ends_at`: Unix timestamp at which the auction ends.",
+        "- `marketplace_fee`: Optional fee paid to the marketplace the auction was created through.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the auction is successfully initialized and the multi-wallet is locked.",
+        "- `Err`: If any validation fails or the auction parameters are invalid.",
+        ""
+      ];
+      discriminator: [126, 249, 108, 77, 47, 189, 128, 12];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.escrow_counter";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.escrow_counter";
+                account: "multiWallet";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "recipient";
+          type: "pubkey";
+        },
+        {
+          name: "mint";
+          type: {
+            option: "pubkey";
+          };
+        },
+        {
+          name: "kind";
+          type: {
+            defined: {
+              name: "auctionKind";
+            };
+          };
+        },
+        {
+          name: "endsAt";
+          type: "i64";
+        },
+        {
+          name: "marketplaceFee";
+          type: {
+            option: {
+              defined: {
+                name: "marketplaceFee";
+              };
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initiateEscrowAsNonOwner";
+      docs: [
+        "Initializes an escrow. This function locks funds into an escrow vault",
+        "and sets up the necessary metadata for the escrow. The escrow identifier is",
+        "allocated from the escrow counter of the multi-wallet.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
+        "The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.",
+        "- `args`: Arguments for the escrow initialization.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
+        "- `Err`: If any validation fails or the transfer operation encounters an issue.",
+        ""
+      ];
+      discriminator: [207, 60, 39, 74, 34, 156, 127, 180];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.escrow_counter";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.escrow_counter";
+                account: "multiWallet";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "proposerTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "proposer";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "proposer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "member";
+          signer: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "initializeEscrowAsNonOwnerArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initiateEscrowAsNonOwnerWithIdentifier";
+      docs: [
+        "Initializes an escrow under a client-chosen identifier, as `initiate_escrow_as_non_owner` does",
+        "with an identifier allocated on-chain. Client-chosen identifiers start at `CLIENT_ESCROW_IDENTIFIER_START`.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts for initializing the escrow.",
+        "The mints, token programs and token accounts of the additional payment legs are passed as remaining accounts.",
+        "- `identifier`: The identifier of the escrow.",
+        "- `args`: Arguments for the escrow initialization.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and funds are transferred to the escrow vault.",
+        "- `Err`: If the identifier is out of range or already in use, or any validation or transfer fails.",
+        ""
+      ];
+      discriminator: [85, 227, 1, 232, 102, 67, 196, 180];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "arg";
+                path: "identifier";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "arg";
+                path: "identifier";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "proposerTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "proposer";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "proposer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "member";
+          signer: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "identifier";
+          type: "u64";
+        },
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "initializeEscrowAsNonOwnerArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initiateEscrowAsOwner";
+      docs: [
+        "Initializes an escrow as an owner. This function locks the multi-wallet",
+        "and prepares the escrow account with the specified metadata and recipient details.",
+        "The escrow identifier is allocated from the escrow counter of the multi-wallet.",
+        "Votes are recorded on an existing escrow, so the threshold of `VoteEscrow` members must sign",
+        "this instruction itself.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for initializing the escrow.",
+        "- `args`: Arguments for the escrow initialization, including the buyers allowed to execute it.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is successfully initialized and the multi-wallet is locked.",
+        "- `Err`: If any validation fails or the multi-wallet does not meet the required threshold.",
+        ""
+      ];
+      discriminator: [255, 237, 252, 101, 179, 193, 143, 27];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.escrow_counter";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "initializeEscrowAsOwnerArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "migrateEscrow";
+      docs: [
+        "Upgrades an escrow created before escrows were versioned to the current layout. Escrows in the old",
+        "layout are rejected by every other escrow instruction until they are migrated. Anyone can migrate an",
+        "escrow once its multi-wallet is migrated. Owner listings refund their rent to the default vault.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet, the escrow and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the escrow is upgraded in place, or grown towards its upgraded size.",
+        "- `Err`: If the account is not an escrow of the multi-wallet or is already up to date.",
+        ""
+      ];
+      discriminator: [65, 111, 186, 119, 58, 11, 81, 209];
+      accounts: [
+        {
+          name: "multiWallet";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateMultiWallet";
+      docs: [
+        "Upgrades a multi-wallet created with an older account layout to the current version.",
+        "Anyone can migrate a multi-wallet; its configuration is left unchanged. Accounts growing by more than",
+        "10240 bytes are grown over several calls, and the upgrade is written by the last one.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the multi-wallet is upgraded in place, or grown towards its upgraded size.",
+        "- `Err`: If the account is not a multi-wallet or is already up to date.",
+        ""
+      ];
+      discriminator: [66, 38, 197, 14, 218, 89, 208, 179];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateTransactionBuffer";
+      docs: [
+        "Upgrades a transaction buffer created before buffers were versioned to the current layout, giving",
+        "it a transaction nonce and counting it as an open buffer of the multi-wallet. Buffers in the old",
+        "layout are rejected by every other buffer instruction until they are migrated. Buffers too large",
+        "for the current layout are closed and their rent refunded.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing the multi-wallet, the buffer, its rent payer and the payer of any extra rent.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the buffer is upgraded in place or closed.",
+        "- `Err`: If the account is not a buffer of the multi-wallet or is already up to date.",
+        ""
+      ];
+      discriminator: [211, 138, 113, 197, 247, 161, 124, 152];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "transactionBuffer";
+          writable: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "placeAuctionBid";
+      docs: [
+        "Places a bid on an English auction. The bid is locked in the escrow vault and the",
+        "previous highest bid is refunded to its bidder.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for placing the bid.",
+        "- `amount`: The amount bid.",
+        "- `new_members`: The members of the multi-wallet if the bid wins.",
+        "- `threshold`: The threshold of the multi-wallet if the bid wins.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the bid is placed and the previous bid is refunded.",
+        "- `Err`: If the auction has ended or the bid is too low.",
+        ""
+      ];
+      discriminator: [38, 170, 233, 87, 52, 65, 23, 128];
+      accounts: [
+        {
+          name: "multiWallet";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "bidderTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "bidder";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "previousBidder";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "previousBidderTokenAccount";
+          docs: [
+            "Created if the outbid bidder closed it, so that the refund cannot be blocked."
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "previousBidder";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "bidder";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "newMembers";
+          type: {
+            vec: {
+              defined: {
+                name: "member";
+              };
+            };
+          };
+        },
+        {
+          name: "threshold";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "settleAuction";
+      docs: [
+        "Settles an English auction once it has ended. Anyone can crank this. The highest bid",
+        "is paid to the recipient and the members of the multi-wallet are handed over to the winner.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for settling the auction.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the auction is settled, or lapses without a bid.",
+        "- `Err`: If the auction has not ended or the accounts provided are invalid.",
+        ""
+      ];
+      discriminator: [246, 196, 183, 98, 222, 139, 46, 133];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrow";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [101, 115, 99, 114, 111, 119];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              },
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              }
+            ];
+          };
+        },
+        {
+          name: "escrowTokenVault";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "escrowVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "recipientTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "recipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "protocolConfig";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "protocolFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "protocolFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "protocolFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "marketplaceFeeRecipient";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "marketplaceFeeTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "marketplaceFeeRecipient";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ];
+            };
+          };
+        },
+        {
+          name: "mint";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        },
+        {
+          name: "recipient";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "receipt";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "escrow.create_key";
+                account: "escrow";
+              },
+              {
+                kind: "account";
+                path: "escrow.identifier";
+                account: "escrow";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "associatedTokenProgram";
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "transactionBufferClose";
+      docs: [
+        "Closes an existing transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully closed.",
+        "- `Err`: If validation fails or the accounts are invalid."
+      ];
+      discriminator: [17, 182, 208, 228, 136, 24, 178, 102];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+        },
+        {
+          name: "transactionBuffer";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multiWallet";
+              },
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: "account";
+                path: "creator";
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.buffer_index";
+                account: "transactionBuffer";
+              }
+            ];
+          };
+        },
+        {
+          name: "creator";
+          signer: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "transactionBufferCreate";
+      docs: [
+        "Creates a new transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "- `args`: Arguments for the transaction buffer creation.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully created.",
+        "- `Err`: If validation fails or the provided arguments are invalid."
+      ];
+      discriminator: [245, 201, 113, 108, 37, 63, 29, 89];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "transactionBuffer";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multiWallet";
+              },
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: "account";
+                path: "creator";
+              },
+              {
+                kind: "arg";
+                path: "args.buffer_index";
+              }
+            ];
+          };
+        },
+        {
+          name: "creator";
+          signer: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "transactionBufferCreateArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "transactionBufferExtend";
+      docs: [
+        "Extends an existing transaction buffer.",
+        "",
+        "# Parameters",
+        "- `ctx`: Context containing all necessary accounts.",
+        "- `args`: Arguments for extending the transaction buffer.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the transaction buffer is successfully extended.",
+        "- `Err`: If validation fails, the multi-wallet is listed or the provided arguments are invalid."
+      ];
+      discriminator: [230, 157, 67, 56, 5, 238, 245, 146];
+      accounts: [
+        {
+          name: "multiWallet";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "transactionBuffer";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.multi_wallet";
+                account: "transactionBuffer";
+              },
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: "account";
+                path: "creator";
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.buffer_index";
+                account: "transactionBuffer";
+              }
+            ];
+          };
+        },
+        {
+          name: "creator";
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "args";
+          type: {
+            defined: {
+              name: "transactionBufferExtendArgs";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "updateProtocolConfig";
+      docs: [
+        "Updates the protocol config.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context containing all relevant accounts required for updating the protocol config.",
+        "- `authority`: The new authority of the protocol config.",
+        "- `fee_recipient`: The account receiving the protocol fee on escrow settlements.",
+        "- `fee_bps`: The protocol fee in basis points.",
+        "",
+        "# Returns",
+        "- `Ok(())`: If the protocol config is successfully updated.",
+        "- `Err`: If the signer is not the protocol config authority or the fee is invalid.",
+        ""
+      ];
+      discriminator: [197, 97, 123, 54, 221, 168, 11, 135];
+      accounts: [
+        {
+          name: "protocolConfig";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "authority";
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: "authority";
+          type: "pubkey";
+        },
+        {
+          name: "feeRecipient";
+          type: "pubkey";
+        },
+        {
+          name: "feeBps";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "vaultTransactionExecute";
+      docs: [
+        "Executes a vault transaction.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction execution.",
+        "- `vault_index`: The index of the vault.",
+        "- `forward_return_data`: Whether to forward the return data of the last executed instruction.",
+        "",
+        "# Returns",
+        "- `Result<()>`: The result of the vault transaction execution."
+      ];
+      discriminator: [194, 8, 161, 87, 153, 164, 25, 171];
+      accounts: [
+        {
+          name: "transactionBuffer";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.multi_wallet";
+                account: "transactionBuffer";
+              },
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.creator";
+                account: "transactionBuffer";
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.buffer_index";
+                account: "transactionBuffer";
+              }
+            ];
+          };
+        },
+        {
+          name: "multiWallet";
+          writable: true;
+        },
+        {
+          name: "rentPayer";
+          writable: true;
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "vaultIndex";
+          type: "u16";
+        },
+        {
+          name: "forwardReturnData";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "vaultTransactionExecuteInline";
+      docs: [
+        "Executes a vault transaction whose message is passed directly as instruction data,",
+        "skipping the transaction buffer for messages small enough to fit in a single transaction.",
+        "Balance assertions are stored on transaction buffers, so inline execution does not check any;",
+        "use a transaction buffer when the vault balances must be asserted after execution.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction execution.",
+        "- `vault_index`: The index of the vault.",
+        "- `transaction_message`: The transaction message to be executed.",
+        "- `forward_return_data`: Whether to forward the return data of the last executed instruction.",
+        "",
+        "# Returns",
+        "- `Result<()>`: The result of the vault transaction execution."
+      ];
+      discriminator: [221, 143, 99, 229, 205, 205, 164, 198];
+      accounts: [
+        {
+          name: "multiWallet";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "multi_wallet.create_key";
+                account: "multiWallet";
+              }
+            ];
+          };
+        },
+        {
+          name: "instructionSysvar";
+          address: "Sysvar1nstructions1111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "vaultIndex";
+          type: "u16";
+        },
+        {
+          name: "transactionMessage";
+          type: {
+            defined: {
+              name: "transactionMessage";
+            };
+          };
+        },
+        {
+          name: "forwardReturnData";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "vaultTransactionValidate";
+      docs: [
+        "Validates a buffered vault transaction against the supplied accounts without executing it.",
+        "No member signatures are required, so backends can check a buffer before collecting them.",
+        "",
+        "# Parameters",
+        "- `ctx`: The context of the vault transaction validation.",
+        "- `vault_index`: The index of the vault.",
+        "",
+        "# Returns",
+        "- `Ok(VaultTransactionSummary)`: The accounts, programs and writable set of the transaction, set as return data.",
+        "- `Err`: If the buffer does not decode or the accounts do not match the message."
+      ];
+      discriminator: [173, 97, 248, 164, 41, 254, 227, 231];
+      accounts: [
+        {
+          name: "transactionBuffer";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109,
+                  117,
+                  108,
+                  116,
+                  105,
+                  95,
+                  119,
+                  97,
+                  108,
+                  108,
+                  101,
+                  116
+                ];
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.multi_wallet";
+                account: "transactionBuffer";
+              },
+              {
+                kind: "const";
+                value: [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  98,
+                  117,
+                  102,
+                  102,
+                  101,
+                  114
+                ];
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.creator";
+                account: "transactionBuffer";
+              },
+              {
+                kind: "account";
+                path: "transaction_buffer.buffer_index";
+                account: "transactionBuffer";
+              }
+            ];
+          };
+        },
+        {
+          name: "multiWallet";
+        }
+      ];
+      args: [
+        {
+          name: "vaultIndex";
+          type: "u16";
+        }
+      ];
+      returns: {
+        defined: {
+          name: "vaultTransactionSummary";
+        };
+      };
+    }
+  ];
+  accounts: [
+    {
+      name: "escrow";
+      discriminator: [31, 213, 123, 187, 186, 22, 218, 155];
+    },
+    {
+      name: "escrowReceipt";
+      discriminator: [5, 104, 57, 114, 177, 192, 135, 10];
+    },
+    {
+      name: "multiWallet";
+      discriminator: [100, 242, 252, 66, 54, 82, 77, 90];
+    },
+    {
+      name: "protocolConfig";
+      discriminator: [207, 91, 250, 28, 152, 179, 215, 209];
+    },
+    {
+      name: "transactionBuffer";
+      discriminator: [90, 36, 35, 219, 93, 225, 110, 96];
+    }
+  ];
+  events: [
+    {
+      name: "configEvent";
+      discriminator: [162, 6, 172, 68, 201, 128, 119, 230];
+    },
+    {
+      name: "escrowEvent";
+      discriminator: [241, 51, 61, 3, 5, 32, 113, 144];
+    },
+    {
+      name: "escrowVoteEvent";
+      discriminator: [253, 40, 255, 152, 159, 73, 11, 27];
+    },
+    {
+      name: "vaultTransactionEvent";
+      discriminator: [143, 5, 230, 245, 90, 196, 197, 129];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "durableNonceDetected";
+      msg: "Durable nonce detected. Durable nonce is not allowed for this transaction.";
+    },
+    {
+      code: 6001;
+      name: "duplicateMember";
+      msg: "Duplicate public keys found in the members array. Each member must have a unique public key.";
+    },
+    {
+      code: 6002;
+      name: "emptyMembers";
+      msg: "The members array cannot be empty. Add at least one member.";
+    },
+    {
+      code: 6003;
+      name: "tooManyMembers";
+      msg: "Too many members specified. A maximum of 65,535 members is allowed.";
+    },
+    {
+      code: 6004;
+      name: "invalidThreshold";
+      msg: "Invalid threshold specified. The threshold must be between 1 and the total number of members.";
+    },
+    {
+      code: 6005;
+      name: "thresholdTooHigh";
+      msg: "Threshold exceeds the maximum allowed limit of 2. Please choose a lower value.";
+    },
+    {
+      code: 6006;
+      name: "invalidTransactionMessage";
+      msg: "The provided TransactionMessage is malformed or improperly formatted.";
+    },
+    {
+      code: 6007;
+      name: "notEnoughSigners";
+      msg: "Insufficient signers. The number of signers must meet or exceed the minimum threshold.";
+    },
+    {
+      code: 6008;
+      name: "invalidNumberOfAccounts";
+      msg: "Incorrect number of accounts provided. Verify the account count matches the expected number.";
+    },
+    {
+      code: 6009;
+      name: "invalidAccount";
+      msg: "One or more accounts provided are invalid. Ensure all accounts meet the requirements.";
+    },
+    {
+      code: 6010;
+      name: "missingAccount";
+      msg: "Required account is missing. Ensure all necessary accounts are included.";
+    },
+    {
+      code: 6011;
+      name: "illegalAccountOwner";
+      msg: "Account is not owned by the Multisig program. Only accounts under the Multisig program can be used.";
+    },
+    {
+      code: 6012;
+      name: "multisigIsCurrentlyLocked";
+      msg: "The Multisig must be unlocked before performing this operation. Unlock it and try again.";
+    },
+    {
+      code: 6013;
+      name: "escrowDoesNotExist";
+      msg: "The escrow account doesn't exist.";
+    },
+    {
+      code: 6014;
+      name: "missingOwner";
+      msg: "The members array cannot have a length of one. Add an additional member.";
+    },
+    {
+      code: 6015;
+      name: "invalidEscrowProposer";
+      msg: "The proposer must match the account stated in the escrow.";
+    },
+    {
+      code: 6016;
+      name: "invalidEscrowRecipient";
+      msg: "The recipient nust match the account stated in the escrow.";
+    },
+    {
+      code: 6017;
+      name: "insufficientSignerWithExecutePermission";
+      msg: "Require at least one signer to have the execute permission.";
+    },
+    {
+      code: 6018;
+      name: "insufficientSignerWithInitiatePermission";
+      msg: "Require at least one signer to have the initiate permission.";
+    },
+    {
+      code: 6019;
+      name: "insufficientSignersWithVotePermission";
+      msg: "Require threshold to be lesser than or equal to the number of members with vote permission.";
+    },
+    {
+      code: 6020;
+      name: "unauthorisedToAcceptEscrowOffer";
+      msg: "You do not have permission to accept this offer.";
+    },
+    {
+      code: 6021;
+      name: "unauthorisedToModifyBuffer";
+      msg: "Only the creator of the transaction buffer have permission to modify the buffer.";
+    },
+    {
+      code: 6022;
+      name: "finalBufferHashMismatch";
+      msg: "Final message buffer hash doesnt match the expected hash";
+    },
+    {
+      code: 6023;
+      name: "finalBufferSizeExceeded";
+      msg: "Final buffer size cannot exceed 4000 bytes";
+    },
+    {
+      code: 6024;
+      name: "finalBufferSizeMismatch";
+      msg: "Final buffer size mismatch";
+    },
+    {
+      code: 6025;
+      name: "tooManyEphemeralSigners";
+      msg: "The transaction buffer declares too many ephemeral signers.";
+    },
+    {
+      code: 6026;
+      name: "transactionBufferAlreadyMigrated";
+      msg: "The transaction buffer already uses the current account layout.";
+    },
+    {
+      code: 6027;
+      name: "transactionBufferNotMigrated";
+      msg: "The transaction buffer uses an old account layout and must be migrated first.";
+    },
+    {
+      code: 6028;
+      name: "balanceAssertionFailed";
+      msg: "A balance assertion on the transaction buffer was violated after execution.";
+    },
+    {
+      code: 6029;
+      name: "tooManyBalanceAssertions";
+      msg: "The transaction buffer has too many balance assertions.";
+    },
+    {
+      code: 6030;
+      name: "invalidEscrowExpiry";
+      msg: "The escrow expiry must be in the future.";
+    },
+    {
+      code: 6031;
+      name: "escrowExpired";
+      msg: "The escrow has expired.";
+    },
+    {
+      code: 6032;
+      name: "escrowNotExpired";
+      msg: "The escrow has not expired yet.";
+    },
+    {
+      code: 6033;
+      name: "escrowAlreadyMigrated";
+      msg: "The escrow already uses the current account layout.";
+    },
+    {
+      code: 6034;
+      name: "escrowNotMigrated";
+      msg: "The escrow uses an old account layout and must be migrated first.";
+    },
+    {
+      code: 6035;
+      name: "tooManyPendingOffers";
+      msg: "Too many pending offers on the multi-wallet. Wait for existing offers to be settled or cancelled.";
+    },
+    {
+      code: 6036;
+      name: "invalidAuction";
+      msg: "Invalid auction parameters or the escrow is not an auction of the expected kind.";
+    },
+    {
+      code: 6037;
+      name: "auctionEnded";
+      msg: "The auction has already ended.";
+    },
+    {
+      code: 6038;
+      name: "auctionNotEnded";
+      msg: "The auction has not ended yet.";
+    },
+    {
+      code: 6039;
+      name: "auctionBidTooLow";
+      msg: "The bid must meet the reserve price and exceed the highest bid by the minimum increment.";
+    },
+    {
+      code: 6040;
+      name: "invalidFeeBasisPoints";
+      msg: "Fees cannot exceed 10,000 basis points in total.";
+    },
+    {
+      code: 6041;
+      name: "invalidFeeRecipient";
+      msg: "The fee recipient must match the account stated in the protocol config or the escrow.";
+    },
+    {
+      code: 6042;
+      name: "unauthorisedToUpdateProtocolConfig";
+      msg: "Only the protocol config authority can update the protocol config.";
+    },
+    {
+      code: 6043;
+      name: "marketplaceFeeNotApproved";
+      msg: "The owners did not approve the marketplace fee of the escrow.";
+    },
+    {
+      code: 6044;
+      name: "missingCounterOffer";
+      msg: "The escrow has no pending counter-offer.";
+    },
+    {
+      code: 6045;
+      name: "assetManifestTooLong";
+      msg: "The escrow asset manifest cannot have more than 16 entries.";
+    },
+    {
+      code: 6046;
+      name: "assetManifestMismatch";
+      msg: "The multi-wallet vaults no longer hold the assets listed in the escrow asset manifest.";
+    },
+    {
+      code: 6047;
+      name: "pendingOffersExist";
+      msg: "The multi-wallet cannot be listed while offers are pending. Cancel them first.";
+    },
+    {
+      code: 6048;
+      name: "unsupportedMintTransferHook";
+      msg: "Escrows do not support mints with a transfer hook.";
+    },
+    {
+      code: 6049;
+      name: "unsupportedMintNonTransferable";
+      msg: "Escrows do not support non-transferable mints.";
+    },
+    {
+      code: 6050;
+      name: "unsupportedMintConfidentialTransfer";
+      msg: "Escrows do not support mints with confidential transfers.";
+    },
+    {
+      code: 6051;
+      name: "transferFeeCalculationFailed";
+      msg: "Failed to calculate the transfer fee of the mint.";
+    },
+    {
+      code: 6052;
+      name: "unsupportedMintPermanentDelegate";
+      msg: "Escrows do not support mints with a permanent delegate.";
+    },
+    {
+      code: 6053;
+      name: "unsupportedMintCloseAuthority";
+      msg: "Escrows do not support mints with a close authority.";
+    },
+    {
+      code: 6054;
+      name: "unsupportedMintDefaultFrozen";
+      msg: "Escrows do not support mints whose token accounts are frozen by default.";
+    },
+    {
+      code: 6055;
+      name: "invalidPaymentLegs";
+      msg: "Escrow payment legs must be non-zero, pay in distinct assets and not exceed the maximum count.";
+    },
+    {
+      code: 6056;
+      name: "invalidSeat";
+      msg: "The seat must belong to a current member and be taken by a non-member.";
+    },
+    {
+      code: 6057;
+      name: "tooManyEscrowVotes";
+      msg: "The escrow has no room left to record another vote.";
+    },
+    {
+      code: 6058;
+      name: "tooManyAllowedBuyers";
+      msg: "Too many allowed buyers on the escrow listing.";
+    },
+    {
+      code: 6059;
+      name: "buyerNotAllowed";
+      msg: "The buyer is not allowed to execute this escrow listing.";
+    },
+    {
+      code: 6060;
+      name: "invalidOfferSignature";
+      msg: "No ed25519 instruction verifies the buyer's signature of the offer.";
+    },
+    {
+      code: 6061;
+      name: "signedOfferAlreadyAccepted";
+      msg: "The signed offer has already been accepted.";
+    },
+    {
+      code: 6062;
+      name: "escrowIdentifierInUse";
+      msg: "An escrow with this identifier already exists.";
+    },
+    {
+      code: 6063;
+      name: "invalidEscrowIdentifier";
+      msg: "The escrow identifier is outside of the allowed range.";
+    },
+    {
+      code: 6064;
+      name: "tooManyOpenBuffers";
+      msg: "The multi-wallet has too many open transaction buffers.";
+    },
+    {
+      code: 6065;
+      name: "openBuffersExist";
+      msg: "The multi-wallet still has open transaction buffers.";
+    },
+    {
+      code: 6066;
+      name: "vaultHoldsData";
+      msg: "A vault holds account data and cannot be swept.";
+    },
+    {
+      code: 6067;
+      name: "multiWalletAlreadyMigrated";
+      msg: "The multi-wallet already uses the current account layout.";
+    },
+    {
+      code: 6068;
+      name: "multiWalletNotMigrated";
+      msg: "The multi-wallet uses an old account layout and must be migrated first.";
+    },
+    {
+      code: 6069;
+      name: "walletMetadataTooLarge";
+      msg: "The wallet metadata exceeds its size limits.";
+    },
+    {
+      code: 6070;
+      name: "memberLabelTooLong";
+      msg: "A member label is longer than allowed.";
+    }
+  ];
+  types: [
+    {
+      name: "auction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "kind";
+            type: {
+              defined: {
+                name: "auctionKind";
+              };
+            };
+          },
+          {
+            name: "startsAt";
+            type: "i64";
+          },
+          {
+            name: "endsAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "auctionKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "english";
+            fields: [
+              {
+                name: "reservePrice";
+                type: "u64";
+              },
+              {
+                name: "minIncrement";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "dutch";
+            fields: [
+              {
+                name: "startPrice";
+                type: "u64";
+              },
+              {
+                name: "endPrice";
+                type: "u64";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "balanceAssertion";
+      docs: [
+        "A condition on an account balance that must hold after a vault transaction is executed."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "maxLamportsDecrease";
+            fields: [
+              {
+                name: "account";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "maxTokenDecrease";
+            fields: [
+              {
+                name: "tokenAccount";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "minTokenBalance";
+            fields: [
+              {
+                name: "tokenAccount";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "compiledInstruction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "programIdIndex";
+            type: "u8";
+          },
+          {
+            name: "accountIndexes";
+            docs: [
+              "Indices into the tx's `account_keys` list indicating which accounts to pass to the instruction."
+            ];
+            type: "bytes";
+          },
+          {
+            name: "data";
+            docs: ["Instruction data."];
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "configAction";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "setMembers";
+            fields: [
+              {
+                vec: {
+                  defined: {
+                    name: "member";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "addMembers";
+            fields: [
+              {
+                vec: {
+                  defined: {
+                    name: "member";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "removeMembers";
+            fields: [
+              {
+                vec: "pubkey";
+              }
+            ];
+          },
+          {
+            name: "setThreshold";
+            fields: ["u8"];
+          },
+          {
+            name: "setMetadata";
+            fields: [
+              {
+                option: "pubkey";
+              }
+            ];
+          },
+          {
+            name: "updateMetadata";
+            fields: [
+              {
+                defined: {
+                  name: "walletMetadataUpdate";
+                };
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "configEvent";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "members";
+            type: {
+              vec: {
+                defined: {
+                  name: "member";
+                };
+              };
+            };
+          },
+          {
+            name: "threshold";
+            type: "u8";
+          },
+          {
+            name: "metadata";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "walletMetadata";
+            docs: [
+              "Content written to the wallet metadata account by this instruction, if any."
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "walletMetadataUpdate";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "counterOffer";
+      docs: ["Terms proposed by the owners in response to a non-owner escrow."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "threshold";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "escrow";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "proposer";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "vaultBump";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "recipient";
+            type: {
+              defined: {
+                name: "recipient";
+              };
+            };
+          },
+          {
+            name: "newMembers";
+            type: {
+              option: {
+                vec: {
+                  defined: {
+                    name: "member";
+                  };
+                };
+              };
+            };
+          },
+          {
+            name: "threshold";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "auction";
+            type: {
+              option: {
+                defined: {
+                  name: "auction";
+                };
+              };
+            };
+          },
+          {
+            name: "marketplaceFee";
+            type: {
+              option: {
+                defined: {
+                  name: "marketplaceFee";
+                };
+              };
+            };
+          },
+          {
+            name: "counterOffer";
+            type: {
+              option: {
+                defined: {
+                  name: "counterOffer";
+                };
+              };
+            };
+          },
+          {
+            name: "assetManifest";
+            docs: [
+              "Vault balances the proposer expects to receive along with the multi-wallet."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "vaultAssetExpectation";
+                };
+              };
+            };
+          },
+          {
+            name: "additionalLegs";
+            docs: ["Assets paid in addition to the recipient amount."];
+            type: {
+              vec: {
+                defined: {
+                  name: "paymentLeg";
+                };
+              };
+            };
+          },
+          {
+            name: "seat";
+            docs: [
+              "Member whose seat is sold to the proposer. The other members and the threshold are kept."
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "votes";
+            docs: [
+              "Votes recorded by the owners ahead of executing or cancelling the escrow. Room for one vote per",
+              "member with `Permission::VoteEscrow` is reserved when the escrow is created."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "escrowVote";
+                };
+              };
+            };
+          },
+          {
+            name: "allowedBuyers";
+            docs: [
+              "Buyers allowed to execute an owner listing. Anyone can buy it if empty."
+            ];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "createdAt";
+            type: "i64";
+          },
+          {
+            name: "rentPayer";
+            docs: [
+              "Account that paid the escrow rent, refunded when an expired escrow is cancelled."
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "version";
+            docs: [
+              "Layout version of the account. Escrows created before versioning are upgraded by `migrate_escrow`",
+              "and cannot be used until then."
+            ];
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6024;
-      name: "finalBufferSizeMismatch";
-      msg: "Final buffer size mismatch";
-    }
-  ];
-  types: [
-    {
-      name: "configAction";
+      name: "escrowEvent";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "setMembers";
-            fields: [
-              {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "isPending";
+            type: "bool";
+          },
+          {
+            name: "isRejected";
+            type: "bool";
+          },
+          {
+            name: "proposer";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "approver";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "recipient";
+            type: {
+              defined: {
+                name: "recipient";
+              };
+            };
+          },
+          {
+            name: "newMembers";
+            type: {
+              option: {
                 vec: {
                   defined: {
                     name: "member";
                   };
                 };
-              }
-            ];
+              };
+            };
           },
           {
-            name: "addMembers";
-            fields: [
-              {
-                vec: {
-                  defined: {
-                    name: "member";
-                  };
+            name: "additionalLegs";
+            type: {
+              vec: {
+                defined: {
+                  name: "paymentLeg";
                 };
-              }
+              };
+            };
+          },
+          {
+            name: "seat";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "threshold";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "fees";
+            type: {
+              defined: {
+                name: "escrowFees";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "escrowFees";
+      docs: ["Fees deducted from the settled amount of an escrow."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "protocolFee";
+            type: "u64";
+          },
+          {
+            name: "marketplaceFee";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "escrowOutcome";
+      docs: ["How an escrow was settled."];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "executed";
+          },
+          {
+            name: "cancelled";
+          },
+          {
+            name: "rejected";
+          },
+          {
+            name: "expired";
+          }
+        ];
+      };
+    },
+    {
+      name: "escrowReceipt";
+      docs: [
+        "Compact on-chain record of a settled escrow, kept after the escrow account is closed."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "sellerMembersHash";
+            docs: [
+              "Hash of the members of the multi-wallet before settlement."
             ];
+            type: {
+              array: ["u8", 32];
+            };
           },
           {
-            name: "removeMembers";
-            fields: [
-              {
-                vec: "pubkey";
-              }
+            name: "buyerMembersHash";
+            docs: [
+              "Hash of the members after settlement, or of the members the buyer proposed if the escrow",
+              "was not executed. Zeroed if there are none."
             ];
+            type: {
+              array: ["u8", 32];
+            };
           },
           {
-            name: "setThreshold";
-            fields: ["u8"];
+            name: "createdAt";
+            type: "i64";
           },
           {
-            name: "setMetadata";
-            fields: [
-              {
-                option: "pubkey";
-              }
-            ];
+            name: "settledAt";
+            type: "i64";
+          },
+          {
+            name: "outcome";
+            type: {
+              defined: {
+                name: "escrowOutcome";
+              };
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
           }
         ];
       };
     },
     {
-      name: "configEvent";
+      name: "escrowVote";
+      docs: [
+        "Vote recorded on an escrow by a member with `Permission::VoteEscrow`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "approve";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "escrowVoteEvent";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "approve";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "initializeEscrowAsNonOwnerArgs";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "createKey";
-            type: "pubkey";
+            name: "newMembers";
+            docs: [
+              "The members of the multi-wallet after the escrow is executed."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "member";
+                };
+              };
+            };
+          },
+          {
+            name: "amount";
+            docs: ["The amount locked in the escrow vault."];
+            type: "u64";
+          },
+          {
+            name: "threshold";
+            docs: [
+              "The threshold of the multi-wallet after the escrow is executed."
+            ];
+            type: "u8";
+          },
+          {
+            name: "expiresAt";
+            docs: [
+              "Optional unix timestamp after which the escrow can no longer be executed."
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "marketplaceFee";
+            docs: [
+              "Optional fee paid to the marketplace the escrow was created through."
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "marketplaceFee";
+                };
+              };
+            };
           },
           {
-            name: "members";
+            name: "assetManifest";
+            docs: [
+              "Vault balances that must still be present when the owners execute the escrow."
+            ];
             type: {
               vec: {
                 defined: {
-                  name: "member";
+                  name: "vaultAssetExpectation";
                 };
               };
             };
           },
           {
-            name: "threshold";
-            type: "u8";
+            name: "additionalLegs";
+            docs: [
+              "Assets deposited in addition to `amount`. Their mints, token programs and token accounts",
+              "are passed as remaining accounts."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "paymentLeg";
+                };
+              };
+            };
           },
           {
-            name: "metadata";
+            name: "seat";
+            docs: [
+              "Member whose seat is bought instead of the whole multi-wallet. `new_members` must then be",
+              "empty and `threshold` is ignored."
+            ];
             type: {
               option: "pubkey";
             };
@@ -2371,143 +7495,180 @@ export type MultiWallet = {
       };
     },
     {
-      name: "escrow";
+      name: "initializeEscrowAsOwnerArgs";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "createKey";
+            name: "recipient";
+            docs: ["The account receiving the payment."];
             type: "pubkey";
           },
           {
-            name: "identifier";
+            name: "amount";
+            docs: ["The amount asked for the multi-wallet."];
             type: "u64";
           },
           {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "proposer";
+            name: "mint";
+            docs: ["Token mint the amount is denominated in (if any)."];
             type: {
               option: "pubkey";
             };
           },
           {
-            name: "vaultBump";
-            type: {
-              option: "u8";
-            };
-          },
-          {
-            name: "recipient";
+            name: "expiresAt";
+            docs: [
+              "Optional unix timestamp after which the escrow can no longer be executed."
+            ];
             type: {
-              defined: {
-                name: "recipient";
-              };
+              option: "i64";
             };
           },
           {
-            name: "newMembers";
+            name: "marketplaceFee";
+            docs: [
+              "Optional fee paid to the marketplace the escrow was created through."
+            ];
             type: {
               option: {
-                vec: {
-                  defined: {
-                    name: "member";
-                  };
+                defined: {
+                  name: "marketplaceFee";
                 };
               };
             };
           },
           {
-            name: "threshold";
+            name: "allowedBuyers";
+            docs: [
+              "Buyers allowed to execute the listing. Anyone can buy it if empty."
+            ];
             type: {
-              option: "u8";
+              vec: "pubkey";
             };
           }
         ];
       };
     },
     {
-      name: "escrowEvent";
+      name: "instructionReturnData";
+      docs: [
+        "Return data set by a single instruction of an executed vault transaction."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "createKey";
+            name: "programId";
             type: "pubkey";
           },
           {
-            name: "identifier";
-            type: "u64";
-          },
+            name: "data";
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "marketplaceFee";
+      docs: ["Fee charged by the marketplace an escrow was created through."];
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "isPending";
-            type: "bool";
+            name: "recipient";
+            type: "pubkey";
           },
           {
-            name: "isRejected";
-            type: "bool";
-          },
+            name: "feeBps";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "member";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "proposer";
-            type: {
-              option: "pubkey";
-            };
+            name: "pubkey";
+            type: "pubkey";
           },
           {
-            name: "approver";
+            name: "permissions";
             type: {
-              option: "pubkey";
+              option: {
+                defined: {
+                  name: "permissions";
+                };
+              };
             };
           },
           {
-            name: "recipient";
+            name: "label";
+            docs: ["Optional short label shown in place of the public key."];
             type: {
-              defined: {
-                name: "recipient";
-              };
+              option: "string";
             };
           },
           {
-            name: "newMembers";
-            type: {
-              option: {
-                vec: {
-                  defined: {
-                    name: "member";
-                  };
-                };
-              };
-            };
+            name: "addedAt";
+            docs: [
+              "Unix timestamp at which the member was added, set by the program. Zero if unknown."
+            ];
+            type: "i64";
           },
           {
-            name: "threshold";
+            name: "addedBy";
+            docs: ["Signer that added the member, set by the program."];
             type: {
-              option: "u8";
+              option: "pubkey";
             };
           }
         ];
       };
     },
     {
-      name: "member";
+      name: "messageAddressTableLookup";
+      docs: [
+        "Address table lookups describe an on-chain address lookup table to use",
+        "for loading more readonly and writable accounts in a single tx."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "pubkey";
+            name: "accountKey";
+            docs: ["Address lookup table account key"];
             type: "pubkey";
           },
           {
-            name: "permissions";
-            type: {
-              option: {
-                defined: {
-                  name: "permissions";
-                };
-              };
-            };
+            name: "writableIndexes";
+            docs: ["List of indexes used to load writable account addresses"];
+            type: "bytes";
+          },
+          {
+            name: "readonlyIndexes";
+            docs: ["List of indexes used to load readonly account addresses"];
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "metadataEntry";
+      docs: ["Free-form key/value pair of the wallet metadata."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "key";
+            type: "string";
+          },
+          {
+            name: "value";
+            type: "string";
           }
         ];
       };
@@ -2540,13 +7701,137 @@ export type MultiWallet = {
             };
           },
           {
-            name: "pendingOffers";
+            name: "pendingOffers";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "metadata";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "transactionCounter";
+            docs: [
+              "Nonce given to the next transaction buffer, so that buffers re-created at a closed buffer's",
+              "address derive new ephemeral signers."
+            ];
+            type: "u64";
+          },
+          {
+            name: "listing";
+            docs: [
+              "Owner-created sale listing, if any. The multi-wallet is locked while it is set."
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "escrowCounter";
+            docs: [
+              "Next escrow identifier allocated on-chain. Always greater than any identifier in use."
+            ];
+            type: "u64";
+          },
+          {
+            name: "openBuffers";
+            docs: [
+              "Number of transaction buffers of the multi-wallet that are not closed yet."
+            ];
+            type: "u16";
+          },
+          {
+            name: "version";
+            docs: [
+              "Layout version of the account. Accounts created before versioning are upgraded by",
+              "`migrate_multi_wallet`."
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "paymentLeg";
+      docs: [
+        "An asset paid into the escrow in addition to the recipient amount."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "permissions";
+      docs: ["Bitmask for permissions."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mask";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "protocolConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            docs: ["Authority allowed to update the protocol config."];
+            type: "pubkey";
+          },
+          {
+            name: "feeRecipient";
+            docs: ["Account receiving the protocol fee on escrow settlements."];
+            type: "pubkey";
+          },
+          {
+            name: "feeBps";
+            docs: ["Protocol fee in basis points of the settled amount."];
+            type: "u16";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "recipient";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "pubkey";
             type: {
-              vec: "pubkey";
+              option: "pubkey";
             };
           },
           {
-            name: "metadata";
+            name: "mint";
             type: {
               option: "pubkey";
             };
@@ -2555,37 +7840,65 @@ export type MultiWallet = {
       };
     },
     {
-      name: "permissions";
-      docs: ["Bitmask for permissions."];
+      name: "signedOffer";
+      docs: [
+        "Offer signed off-chain by a buyer, accepted by the owners through `accept_signed_offer`.",
+        "",
+        "The buyer approves the escrow vault PDA of `nonce` as delegate of `amount` on their token account,",
+        "which the program spends from when the owners accept the offer."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "mask";
-            type: "u8";
-          }
-        ];
-      };
-    },
-    {
-      name: "recipient";
-      type: {
-        kind: "struct";
-        fields: [
+            name: "multiWallet";
+            type: "pubkey";
+          },
+          {
+            name: "nonce";
+            docs: [
+              "Identifies the offer and its escrow vault. Must be at least `CLIENT_ESCROW_IDENTIFIER_START`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "buyer";
+            type: "pubkey";
+          },
           {
             name: "amount";
             type: "u64";
           },
           {
-            name: "pubkey";
+            name: "mint";
+            type: "pubkey";
+          },
+          {
+            name: "newMembers";
             type: {
-              option: "pubkey";
+              vec: {
+                defined: {
+                  name: "member";
+                };
+              };
             };
           },
           {
-            name: "mint";
+            name: "threshold";
+            type: "u8";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "marketplaceFee";
             type: {
-              option: "pubkey";
+              option: {
+                defined: {
+                  name: "marketplaceFee";
+                };
+              };
             };
           }
         ];
@@ -2626,9 +7939,26 @@ export type MultiWallet = {
             docs: ["Vault index of the transaction this buffer belongs to."];
             type: "u8";
           },
+          {
+            name: "numEphemeralSigners";
+            docs: [
+              "Number of ephemeral signer PDAs the transaction message expects the program to sign for."
+            ];
+            type: "u8";
+          },
+          {
+            name: "transactionNonce";
+            docs: [
+              "Nonce of the transaction, taken from the multi-wallet transaction counter. Mixed into the",
+              "ephemeral signer seeds, since the buffer address is reused once the buffer is closed."
+            ];
+            type: "u64";
+          },
           {
             name: "finalBufferHash";
-            docs: ["Hash of the final assembled transaction message."];
+            docs: [
+              "Hash of the final assembled transaction message, followed by the Borsh-encoded assertions if there are any."
+            ];
             type: {
               array: ["u8", 32];
             };
@@ -2638,10 +7968,31 @@ export type MultiWallet = {
             docs: ["The size of the final assembled transaction message."];
             type: "u16";
           },
+          {
+            name: "assertions";
+            docs: [
+              "Balance assertions that must hold after the transaction is executed."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "balanceAssertion";
+                };
+              };
+            };
+          },
           {
             name: "buffer";
             docs: ["The buffer of the transaction message."];
             type: "bytes";
+          },
+          {
+            name: "version";
+            docs: [
+              "Layout version of the account. Buffers created before versioning are upgraded by",
+              "`migrate_transaction_buffer` and cannot be used until then."
+            ];
+            type: "u8";
           }
         ];
       };
@@ -2663,9 +8014,19 @@ export type MultiWallet = {
             docs: ["Index of the vault this transaction belongs to."];
             type: "u8";
           },
+          {
+            name: "numEphemeralSigners";
+            docs: [
+              "Number of ephemeral signer PDAs, derived from the buffer key, the transaction nonce and an index,",
+              "the program signs with when executing the transaction. At most `MAX_EPHEMERAL_SIGNERS`."
+            ];
+            type: "u8";
+          },
           {
             name: "finalBufferHash";
-            docs: ["Hash of the final assembled transaction message."];
+            docs: [
+              "Hash of the final assembled transaction message, followed by the Borsh-encoded `assertions` if there are any."
+            ];
             type: {
               array: ["u8", 32];
             };
@@ -2675,6 +8036,19 @@ export type MultiWallet = {
             docs: ["Final size of the buffer."];
             type: "u16";
           },
+          {
+            name: "assertions";
+            docs: [
+              "Balance assertions checked after the transaction is executed, at most `MAX_BALANCE_ASSERTIONS`."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "balanceAssertion";
+                };
+              };
+            };
+          },
           {
             name: "buffer";
             docs: ["Initial slice of the buffer."];
@@ -2694,6 +8068,215 @@ export type MultiWallet = {
           }
         ];
       };
+    },
+    {
+      name: "transactionMessage";
+      docs: ["Unvalidated instruction data, must be treated as untrusted."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "numSigners";
+            docs: ["The number of signer pubkeys in the account_keys vec."];
+            type: "u8";
+          },
+          {
+            name: "numWritableSigners";
+            docs: [
+              "The number of writable signer pubkeys in the account_keys vec."
+            ];
+            type: "u8";
+          },
+          {
+            name: "numWritableNonSigners";
+            docs: [
+              "The number of writable non-signer pubkeys in the account_keys vec."
+            ];
+            type: "u8";
+          },
+          {
+            name: "accountKeys";
+            docs: [
+              "The list of unique account public keys (including program IDs) that will be used in the provided instructions."
+            ];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "instructions";
+            docs: ["The list of instructions to execute."];
+            type: {
+              vec: {
+                defined: {
+                  name: "compiledInstruction";
+                };
+              };
+            };
+          },
+          {
+            name: "addressTableLookups";
+            docs: [
+              "List of address table lookups used to load additional accounts",
+              "for this transaction."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "messageAddressTableLookup";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "vaultAssetExpectation";
+      docs: [
+        "A vault balance the buyer expects to still be there when the escrow is executed."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "lamports";
+            fields: [
+              {
+                name: "vaultIndex";
+                type: "u16";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "token";
+            fields: [
+              {
+                name: "vaultIndex";
+                type: "u16";
+              },
+              {
+                name: "mint";
+                type: "pubkey";
+              },
+              {
+                name: "amount";
+                type: "u64";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "vaultTransactionEvent";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "createKey";
+            type: "pubkey";
+          },
+          {
+            name: "vaultIndex";
+            type: "u16";
+          },
+          {
+            name: "returnData";
+            type: {
+              vec: {
+                option: {
+                  defined: {
+                    name: "instructionReturnData";
+                  };
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "vaultTransactionSummary";
+      docs: [
+        "Summary of a validated vault transaction, returned by `vault_transaction_validate`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "vault";
+            docs: ["The vault PDA that signs the transaction."];
+            type: "pubkey";
+          },
+          {
+            name: "accounts";
+            docs: [
+              "All accounts of the message, static keys first, then loaded writable and loaded readonly keys."
+            ];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "programIndexes";
+            docs: [
+              "Indexes into `accounts` of the programs invoked by the message."
+            ];
+            type: "bytes";
+          },
+          {
+            name: "writableIndexes";
+            docs: [
+              "Indexes into `accounts` of the accounts the message requests as writable."
+            ];
+            type: "bytes";
+          }
+        ];
+      };
+    },
+    {
+      name: "walletMetadataUpdate";
+      docs: [
+        "Content of the wallet metadata, replaced as a whole by `ConfigAction::UpdateMetadata`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "imageUri";
+            type: "string";
+          },
+          {
+            name: "tags";
+            type: {
+              vec: "string";
+            };
+          },
+          {
+            name: "entries";
+            type: {
+              vec: {
+                defined: {
+                  name: "metadataEntry";
+                };
+              };
+            };
+          }
+        ];
+      };
     }
   ];
 };
@@ -7,6 +7,7 @@ export type IPermissions = {
 export type Member = {
   pubkey: PublicKey;
   permissions: IPermissions | null;
+  label?: string | null;
 };
 
 export const Permission = {
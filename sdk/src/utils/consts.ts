@@ -1,5 +1,6 @@
 import { Program } from "@coral-xyz/anchor";
 import { Connection, PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import { MultiWalletIdl } from "../idl/multi_wallet.js";
 import { type MultiWallet } from "../types/index.js";
 
@@ -34,3 +35,6 @@ export const JITO_TIP_ACCOUNTS = [
 export const ADDRESS_LOOK_UP_TABLE = new PublicKey(
   "Hg5CGGARH2PSh7ceV8KVqZ6VqW5bnFqdFabziVddHsDZ"
 );
+
+/** Escrow identifiers below this value are allocated by the program. */
+export const CLIENT_ESCROW_IDENTIFIER_START = new BN(1).shln(63);
@@ -1,3 +1,4 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
   type AccountMeta,
   AddressLookupTableAccount,
@@ -13,6 +14,8 @@ import BN from "bn.js";
 import { Buffer } from "buffer";
 import invariant from "invariant";
 import {
+  type MarketplaceFee,
+  type Member,
   type TransactionMessage,
   transactionMessageBeet,
 } from "../types/index.js";
@@ -27,7 +30,7 @@ export function getMultiSigFromAddress(address: PublicKey) {
   return multisigPda;
 }
 
-export function getEscrow(walletAddress: PublicKey, identifier: number) {
+export function getEscrow(walletAddress: PublicKey, identifier: BN | number) {
   const [escrow] = PublicKey.findProgramAddressSync(
     [
       Buffer.from("escrow"),
@@ -41,7 +44,7 @@ export function getEscrow(walletAddress: PublicKey, identifier: number) {
 
 export function getEscrowNativeVault(
   walletAddress: PublicKey,
-  identifier: number
+  identifier: BN | number
 ) {
   const [escrow] = PublicKey.findProgramAddressSync(
     [
@@ -55,6 +58,72 @@ export function getEscrowNativeVault(
   return escrow;
 }
 
+export function getWalletMetadata(walletAddress: PublicKey) {
+  const multisigPda = getMultiSigFromAddress(walletAddress);
+  const [walletMetadata] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("multi_wallet"),
+      multisigPda.toBuffer(),
+      Buffer.from("wallet_metadata"),
+    ],
+    program().programId
+  );
+  return walletMetadata;
+}
+
+export function getProtocolConfig() {
+  const [protocolConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config")],
+    program().programId
+  );
+  return protocolConfig;
+}
+
+export function toMember(member: Member) {
+  return {
+    pubkey: member.pubkey,
+    permissions: member.permissions,
+    label: member.label ?? null,
+    // Set by the program.
+    addedAt: new BN(0),
+    addedBy: null,
+  };
+}
+
+/**
+ * Returns the recipients of the protocol and marketplace fees of an escrow settlement,
+ * with their token accounts when the escrow is settled in `mint`.
+ */
+export async function getEscrowFeeAccounts({
+  marketplaceFee,
+  mint,
+  tokenProgram,
+}: {
+  marketplaceFee: MarketplaceFee | null;
+  mint: PublicKey | null;
+  tokenProgram: PublicKey | null;
+}) {
+  const protocolConfig = await program().account.protocolConfig.fetchNullable(
+    getProtocolConfig()
+  );
+  const protocolFeeRecipient =
+    protocolConfig && protocolConfig.feeBps > 0
+      ? protocolConfig.feeRecipient
+      : null;
+  const marketplaceFeeRecipient = marketplaceFee?.recipient ?? null;
+  const tokenAccount = (owner: PublicKey | null) =>
+    owner && mint && tokenProgram
+      ? getAssociatedTokenAddressSync(mint, owner, true, tokenProgram)
+      : null;
+
+  return {
+    protocolFeeRecipient,
+    protocolFeeTokenAccount: tokenAccount(protocolFeeRecipient),
+    marketplaceFeeRecipient,
+    marketplaceFeeTokenAccount: tokenAccount(marketplaceFeeRecipient),
+  };
+}
+
 export function getVaultFromAddress(address: PublicKey, vault_index = 0) {
   const multisigPda = getMultiSigFromAddress(address);
   const [multisigVaultPda] = PublicKey.findProgramAddressSync(
@@ -7,6 +7,7 @@ import {
   SystemProgram,
   Transaction,
 } from "@solana/web3.js";
+import BN from "bn.js";
 import { expect } from "chai";
 import {
   acceptEscrowAsNonOwner,
@@ -14,6 +15,7 @@ import {
   cancelEscrowAsNonOwner,
   cancelEscrowAsOwner,
   changeConfig,
+  CLIENT_ESCROW_IDENTIFIER_START,
   createTransactionBundle,
   createWallet,
   fetchEscrowData,
@@ -26,6 +28,11 @@ import {
   Permissions,
 } from "../sdk";
 
+const getRandomId = () =>
+  CLIENT_ESCROW_IDENTIFIER_START.add(
+    new BN(Math.round(Math.random() * Number.MAX_SAFE_INTEGER))
+  );
+
 describe("multi_wallet", () => {
   const connection = new Connection("http://localhost:8899", "confirmed");
   initMultiWalletProgram(connection);
@@ -191,19 +198,18 @@ describe("multi_wallet", () => {
   });
 
   it("Initiates an escrow as proposer using native sol and closing the escrow as proposer", async () => {
-    const identifier = Math.round(Math.random() * Number.MAX_SAFE_INTEGER);
-    const ix = await initiateEscrowAsNonOwner({
-      identifier,
-      walletAddress: wallet.publicKey,
-      member: wallet.publicKey,
-      newOwners: [
-        { pubkey: payer.publicKey, permissions: Permissions.all() },
-        { pubkey: wallet.publicKey, permissions: Permissions.all() },
-      ],
-      proposer: payer.publicKey,
-      amount: LAMPORTS_PER_SOL * 0.001,
-      threshold: 2,
-    });
+    const { initiateEscrowAsNonOwnerIx: ix, identifier } =
+      await initiateEscrowAsNonOwner({
+        walletAddress: wallet.publicKey,
+        member: wallet.publicKey,
+        newOwners: [
+          { pubkey: payer.publicKey, permissions: Permissions.all() },
+          { pubkey: wallet.publicKey, permissions: Permissions.all() },
+        ],
+        proposer: payer.publicKey,
+        amount: LAMPORTS_PER_SOL * 0.001,
+        threshold: 2,
+      });
     const tx = new Transaction().add(ix);
     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
     tx.feePayer = payer.publicKey;
@@ -217,7 +223,7 @@ describe("multi_wallet", () => {
 
     const escrowData = await fetchEscrowData(wallet.publicKey, identifier);
 
-    expect(escrowData.identifier.toNumber()).equal(identifier);
+    expect(escrowData.identifier.eq(identifier)).equal(true);
     expect(escrowData.recipient.amount.toNumber()).equal(
       LAMPORTS_PER_SOL * 0.001
     );
@@ -238,19 +244,19 @@ describe("multi_wallet", () => {
   });
 
   it("Initiates an escrow as proposer using native sol and closing the escrow as owner", async () => {
-    const identifier = Math.round(Math.random() * Number.MAX_SAFE_INTEGER);
-    const ix = await initiateEscrowAsNonOwner({
-      identifier,
-      walletAddress: wallet.publicKey,
-      member: wallet.publicKey,
-      newOwners: [
-        { pubkey: payer.publicKey, permissions: Permissions.all() },
-        { pubkey: wallet.publicKey, permissions: Permissions.all() },
-      ],
-      proposer: payer.publicKey,
-      amount: LAMPORTS_PER_SOL * 0.001,
-      threshold: 2,
-    });
+    const { initiateEscrowAsNonOwnerIx: ix, identifier } =
+      await initiateEscrowAsNonOwner({
+        identifier: getRandomId(),
+        walletAddress: wallet.publicKey,
+        member: wallet.publicKey,
+        newOwners: [
+          { pubkey: payer.publicKey, permissions: Permissions.all() },
+          { pubkey: wallet.publicKey, permissions: Permissions.all() },
+        ],
+        proposer: payer.publicKey,
+        amount: LAMPORTS_PER_SOL * 0.001,
+        threshold: 2,
+      });
 
     const tx = new Transaction().add(ix);
     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
@@ -281,21 +287,20 @@ describe("multi_wallet", () => {
   });
 
   it("2 x Initiates an escrow as proposer using native sol and accepting escrow as owner then cancelling any pending escrow", async () => {
-    const getRandomId = () => Math.random() * 1000000;
-
-    const initiateEscrow = async (randomId: number) => {
-      const ix = await initiateEscrowAsNonOwner({
-        identifier: randomId,
-        walletAddress: wallet.publicKey,
-        member: wallet.publicKey,
-        newOwners: [
-          { pubkey: payer.publicKey, permissions: Permissions.all() },
-          { pubkey: wallet.publicKey, permissions: Permissions.all() },
-        ],
-        proposer: payer.publicKey,
-        amount: LAMPORTS_PER_SOL * 0.001,
-        threshold: 2,
-      });
+    const initiateEscrow = async (randomId: BN) => {
+      const { initiateEscrowAsNonOwnerIx: ix } =
+        await initiateEscrowAsNonOwner({
+          identifier: randomId,
+          walletAddress: wallet.publicKey,
+          member: wallet.publicKey,
+          newOwners: [
+            { pubkey: payer.publicKey, permissions: Permissions.all() },
+            { pubkey: wallet.publicKey, permissions: Permissions.all() },
+          ],
+          proposer: payer.publicKey,
+          amount: LAMPORTS_PER_SOL * 0.001,
+          threshold: 2,
+        });
 
       const tx = new Transaction().add(ix);
       tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
@@ -311,6 +316,13 @@ describe("multi_wallet", () => {
     let id2 = getRandomId();
     await initiateEscrow(id);
     await initiateEscrow(id2);
+
+    let reused = false;
+    try {
+      await initiateEscrow(id2);
+      reused = true;
+    } catch {}
+    expect(reused).equal(false);
     const ix = await acceptEscrowAsOwner({
       signers: [test.publicKey, wallet.publicKey],
       recipient: wallet.publicKey,
@@ -344,15 +356,14 @@ describe("multi_wallet", () => {
   });
 
   it("Initiates an escrow as owner using native sol and closing the escrow as owner", async () => {
-    const identifier = Math.round(Math.random() * Number.MAX_SAFE_INTEGER);
-    const ix = await initiateEscrowAsOwner({
-      identifier,
-      walletAddress: wallet.publicKey,
-      amount: LAMPORTS_PER_SOL * 0.001,
-      feePayer: payer.publicKey,
-      recipient: wallet.publicKey,
-      signers: [wallet.publicKey, payer.publicKey],
-    });
+    const { initiateEscrowAsOwnerIx: ix, identifier } =
+      await initiateEscrowAsOwner({
+        walletAddress: wallet.publicKey,
+        amount: LAMPORTS_PER_SOL * 0.001,
+        feePayer: payer.publicKey,
+        recipient: wallet.publicKey,
+        signers: [wallet.publicKey, payer.publicKey],
+      });
     const tx = new Transaction().add(ix);
     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
     tx.feePayer = payer.publicKey;
@@ -382,15 +393,14 @@ describe("multi_wallet", () => {
   });
 
   it("Initiates an escrow as owner using native sol and accepting the escrow as proposer", async () => {
-    const identifier = Math.round(Math.random() * Number.MAX_SAFE_INTEGER);
-    const ix = await initiateEscrowAsOwner({
-      identifier,
-      walletAddress: wallet.publicKey,
-      amount: LAMPORTS_PER_SOL * 0.001,
-      feePayer: payer.publicKey,
-      recipient: wallet.publicKey,
-      signers: [wallet.publicKey, payer.publicKey],
-    });
+    const { initiateEscrowAsOwnerIx: ix, identifier } =
+      await initiateEscrowAsOwner({
+        walletAddress: wallet.publicKey,
+        amount: LAMPORTS_PER_SOL * 0.001,
+        feePayer: payer.publicKey,
+        recipient: wallet.publicKey,
+        signers: [wallet.publicKey, payer.publicKey],
+      });
     const tx = new Transaction().add(ix);
     tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
     tx.feePayer = payer.publicKey;